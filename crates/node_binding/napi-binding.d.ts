@@ -446,6 +446,17 @@ export declare class ModuleGraphConnection {
   get originModule(): Module | null
 }
 
+/**
+ * The hash functions which are implemented in rust only, so that `compilation.createHash`
+ * produces the same result as the hashes calculated by the compiler.
+ */
+export declare class NativeHash {
+  constructor(function: string)
+  update(data: string | Buffer): void
+  /** Returns the digest in hex, the hash can't be updated anymore. */
+  digest(): string
+}
+
 export declare class NativeWatcher {
   constructor(options: NativeWatcherOptions)
   watch(files: [Array<string>, Array<string>], directories: [Array<string>, Array<string>], missing: [Array<string>, Array<string>], startTime: bigint, callback: (err: Error | null, result: NativeWatchResult) => void, callbackUndelayed: (path: string) => void): void
@@ -549,6 +560,7 @@ export declare enum BuiltinPluginName {
   NamedModuleIdsPlugin = 'NamedModuleIdsPlugin',
   NaturalModuleIdsPlugin = 'NaturalModuleIdsPlugin',
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
+  HashedModuleIdsPlugin = 'HashedModuleIdsPlugin',
   NaturalChunkIdsPlugin = 'NaturalChunkIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
@@ -564,6 +576,7 @@ export declare enum BuiltinPluginName {
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -1705,7 +1718,16 @@ export interface NapiResolveOptions {
 export interface NativeWatcherOptions {
   followSymlinks?: boolean
   pollInterval?: number
+  /** Whether to poll all watched paths instead of relying on native events. */
+  poll?: boolean
+  /**
+   * Only poll the watched paths under these roots, other paths still use native events.
+   * It is ignored when `poll` is true.
+   */
+  pollRoots?: Array<string>
   aggregateTimeout?: number
+  /** The file to record the events of the watcher, used to debug the watch mode. */
+  journal?: string
   /**
    * The ignored paths for the watcher.
    * It can be a single path, an array of paths, or a regular expression.
@@ -2146,6 +2168,8 @@ export interface RawExperimentSnapshotOptions {
   immutablePaths: Array<string|RegExp>
   unmanagedPaths: Array<string|RegExp>
   managedPaths: Array<string|RegExp>
+  contentHashPaths: Array<string|RegExp>
+  gitIndex: boolean
 }
 
 export interface RawExposeOptions {
@@ -2219,6 +2243,13 @@ export interface RawGeneratorOptions {
   json?: RawJsonGeneratorOptions
 }
 
+export interface RawHashedModuleIdsPluginOptions {
+  context?: string
+  hashFunction?: string
+  hashDigest?: string
+  hashDigestLength?: number
+}
+
 export interface RawHtmlRspackPluginBaseOptions {
   href?: string
   target?: "_self" | "_blank" | "_parent" | "_top"
@@ -2273,6 +2304,7 @@ export interface RawIgnorePluginOptions {
 
 export interface RawIncremental {
   silent: boolean
+  check: boolean
   make: boolean
   inferAsyncModules: boolean
   providedExports: boolean
@@ -2875,8 +2907,11 @@ export interface RawStatsOptions {
 }
 
 export interface RawStorageOptions {
-  type: "filesystem"
+  type: "filesystem" | "remote"
   directory: string
+  url?: string
+  maxSize?: number
+  compression?: "none" | "zstd" | "lz4"
 }
 
 export interface RawSubresourceIntegrityPluginOptions {
@@ -2900,6 +2935,8 @@ export interface RawSwcJsMinimizerRspackPluginOptions {
   exclude?: string | RegExp | (string | RegExp)[]
   extractComments?: RawExtractComments
   minimizerOptions: RawSwcJsMinimizerOptions
+  nameCache?: boolean | string
+  legalComments?: 'inline' | 'eof' | 'linked' | 'external'
 }
 
 export interface RawToOptions {
@@ -95,6 +95,7 @@ export const JsStats = __napiModule.exports.JsStats
 export const KnownBuildInfo = __napiModule.exports.KnownBuildInfo
 export const Module = __napiModule.exports.Module
 export const ModuleGraphConnection = __napiModule.exports.ModuleGraphConnection
+export const NativeHash = __napiModule.exports.NativeHash
 export const NativeWatcher = __napiModule.exports.NativeWatcher
 export const NativeWatchResult = __napiModule.exports.NativeWatchResult
 export const NormalModule = __napiModule.exports.NormalModule
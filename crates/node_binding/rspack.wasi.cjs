@@ -140,6 +140,7 @@ module.exports.JsStats = __napiModule.exports.JsStats
 module.exports.KnownBuildInfo = __napiModule.exports.KnownBuildInfo
 module.exports.Module = __napiModule.exports.Module
 module.exports.ModuleGraphConnection = __napiModule.exports.ModuleGraphConnection
+module.exports.NativeHash = __napiModule.exports.NativeHash
 module.exports.NativeWatcher = __napiModule.exports.NativeWatcher
 module.exports.NativeWatchResult = __napiModule.exports.NativeWatchResult
 module.exports.NormalModule = __napiModule.exports.NormalModule
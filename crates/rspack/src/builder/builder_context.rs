@@ -77,6 +77,7 @@ pub(super) enum BuiltinPluginOptions {
   NamedModuleIdsPlugin,
   NaturalModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
+  HashedModuleIdsPlugin(rspack_ids::HashedModuleIdsPluginOptions),
   NaturalChunkIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
@@ -152,9 +153,7 @@ impl BuilderContext {
         rspack_plugin_runtime::enable_chunk_loading_plugin(chunk_loading_type, &mut plugins);
       }
       BuiltinPluginOptions::EnableWasmLoadingPlugin(wasm_loading_type) => {
-        plugins.push(rspack_plugin_wasm::enable_wasm_loading_plugin(
-          wasm_loading_type,
-        ));
+        rspack_plugin_wasm::enable_wasm_loading_plugin(wasm_loading_type, &mut plugins);
       }
 
       // Runtime and error handling plugins
@@ -300,6 +299,9 @@ impl BuilderContext {
       BuiltinPluginOptions::DeterministicModuleIdsPlugin => {
         plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed())
       }
+      BuiltinPluginOptions::HashedModuleIdsPlugin(options) => {
+        plugins.push(rspack_ids::HashedModuleIdsPlugin::new(options).boxed())
+      }
       BuiltinPluginOptions::NaturalChunkIdsPlugin => {
         plugins.push(rspack_ids::NaturalChunkIdsPlugin::default().boxed())
       }
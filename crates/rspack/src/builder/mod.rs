@@ -54,7 +54,9 @@ use rspack_core::{
   incremental::{IncrementalOptions, IncrementalPasses},
 };
 use rspack_error::{Error, Result};
-use rspack_fs::{IntermediateFileSystem, ReadableFileSystem, WritableFileSystem};
+use rspack_fs::{
+  IntermediateFileSystem, OverlayFileSystem, ReadableFileSystem, WritableFileSystem,
+};
 use rspack_hash::{HashDigest, HashFunction, HashSalt};
 use rspack_paths::{AssertUtf8, Utf8PathBuf};
 use rspack_regex::RspackRegex;
@@ -106,6 +108,7 @@ pub struct CompilerBuilder {
   input_filesystem: Option<Arc<dyn ReadableFileSystem>>,
   intermediate_filesystem: Option<Arc<dyn IntermediateFileSystem>>,
   output_filesystem: Option<Arc<dyn WritableFileSystem>>,
+  overlay_filesystem: Option<OverlayFileSystem>,
 }
 
 impl CompilerBuilder {
@@ -120,6 +123,7 @@ impl CompilerBuilder {
       input_filesystem: None,
       intermediate_filesystem: None,
       output_filesystem: None,
+      overlay_filesystem: None,
     }
   }
 }
@@ -443,6 +447,15 @@ impl CompilerBuilder {
     self
   }
 
+  /// Set the overlay filesystem, which takes precedence over the input filesystem.
+  ///
+  /// Virtual files can be added or removed between rebuilds with [`Compiler::write_virtual_file`]
+  /// and [`Compiler::remove_virtual_file`].
+  pub fn overlay_filesystem(&mut self, overlay_filesystem: OverlayFileSystem) -> &mut Self {
+    self.overlay_filesystem = Some(overlay_filesystem);
+    self
+  }
+
   /// Build [`Compiler`] from options and plugins.
   pub fn build(&mut self) -> Result<Compiler> {
     let mut builder_context = BuilderContext::default();
@@ -451,11 +464,17 @@ impl CompilerBuilder {
     let platform = builder_context.take_platform();
     plugins.append(&mut self.plugins);
 
-    let input_filesystem = self.input_filesystem.take();
+    let overlay_filesystem = self.overlay_filesystem.take();
+    let input_filesystem = match &overlay_filesystem {
+      Some(overlay_filesystem) => {
+        Some(Arc::new(overlay_filesystem.clone()) as Arc<dyn ReadableFileSystem>)
+      }
+      None => self.input_filesystem.take(),
+    };
     let intermediate_filesystem = self.intermediate_filesystem.take();
     let output_filesystem = self.output_filesystem.take();
     let compiler_context = CURRENT_COMPILER_CONTEXT.try_with(|v| v.clone()).ok();
-    Ok(Compiler::new(
+    let mut compiler = Compiler::new(
       String::new(),
       compiler_options,
       plugins,
@@ -467,7 +486,9 @@ impl CompilerBuilder {
       None,
       compiler_context,
       Arc::new(platform),
-    ))
+    );
+    compiler.overlay_filesystem = overlay_filesystem;
+    Ok(compiler)
   }
 }
 
@@ -2084,6 +2105,18 @@ fn default_rules(async_web_assembly: bool, css: bool) -> Vec<ModuleRule> {
       },
       ModuleRule {
         mimetype: Some(RuleSetCondition::String("text/css".into()).into()),
+        effect: ModuleRuleEffect {
+          r#type: Some(ModuleType::Css),
+          resolve: Some(resolve.clone()),
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      ModuleRule {
+        with: Some(HashMap::from_iter([(
+          "type".into(),
+          RuleSetCondition::String("css".into()).into(),
+        )])),
         effect: ModuleRuleEffect {
           r#type: Some(ModuleType::Css),
           resolve: Some(resolve),
@@ -2128,6 +2161,28 @@ fn default_rules(async_web_assembly: bool, css: bool) -> Vec<ModuleRule> {
       },
       ..Default::default()
     },
+    ModuleRule {
+      with: Some(HashMap::from_iter([(
+        "type".into(),
+        RuleSetCondition::String("text".into()).into(),
+      )])),
+      effect: ModuleRuleEffect {
+        r#type: Some(ModuleType::AssetSource),
+        ..Default::default()
+      },
+      ..Default::default()
+    },
+    ModuleRule {
+      with: Some(HashMap::from_iter([(
+        "type".into(),
+        RuleSetCondition::String("bytes".into()).into(),
+      )])),
+      effect: ModuleRuleEffect {
+        r#type: Some(ModuleType::AssetBytes),
+        ..Default::default()
+      },
+      ..Default::default()
+    },
   ]);
 
   rules
@@ -3486,6 +3541,13 @@ impl OptimizationOptionsBuilder {
           .plugins
           .push(BuiltinPluginOptions::NaturalModuleIdsPlugin);
       }
+      "hashed" => {
+        builder_context
+          .plugins
+          .push(BuiltinPluginOptions::HashedModuleIdsPlugin(
+            Default::default(),
+          ));
+      }
       _ => {
         return Err(
           BuilderError::Option(
@@ -3634,6 +3696,8 @@ impl OptimizationOptionsBuilder {
                 exclude: None,
                 extract_comments: None,
                 minimizer_options: Default::default(),
+                name_cache: None,
+                legal_comments: None,
               },
             ),
           )),
@@ -3787,6 +3851,7 @@ impl ExperimentsBuilder {
       IncrementalOptions {
         silent: true,
         passes,
+        check: false,
       }
     });
     let top_level_await = d!(self.top_level_await, true);
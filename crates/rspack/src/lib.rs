@@ -51,7 +51,8 @@
 //! - [x] `CompilerBuilder` API
 //! - [ ] `SplitChunksPlugin` API
 //! - [ ] `BundlerInfoPlugin` API
-//! - [ ] `StatsPrinter` API
+//! - [x] `StatsPrinter` API
+//! - [x] Virtual files API
 //! - [ ] Stable `Compiler` API
 //! - [ ] Stable `Compilation` API
 //! - [ ] Rust Plugin for Rspack
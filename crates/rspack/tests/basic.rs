@@ -52,4 +52,123 @@ mod tests {
     })
     .await;
   }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn basic_css_import_attribute() {
+    use rspack_core::Experiments;
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let mut compiler = Compiler::builder()
+        .context(
+          Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/css-import-attribute"),
+        )
+        .entry("main", "./src/index.js")
+        .experiments(Experiments::builder().css(true))
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+
+      let errors: Vec<_> = compiler.compilation.get_errors().collect();
+      assert!(errors.is_empty());
+
+      // `with { type: "css" }` makes the module css regardless of its extension
+      let asset = &compiler.compilation.assets().get("main.css").unwrap();
+      assert!(
+        asset
+          .source
+          .as_ref()
+          .unwrap()
+          .source()
+          .into_string_lossy()
+          .contains("color: red")
+      );
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn basic_virtual_files() {
+    use rspack_fs::OverlayFileSystem;
+    use rspack_tasks::within_compiler_context_for_testing;
+    within_compiler_context_for_testing(async {
+      let context = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic");
+      let virtual_file = context.join("src/virtual.js");
+      let mut compiler = Compiler::builder()
+        .context(context.clone())
+        .entry("main", "./src/virtual.js")
+        .overlay_filesystem(OverlayFileSystem::default())
+        .build()
+        .unwrap();
+
+      compiler
+        .write_virtual_file(&virtual_file, b"console.log(456);".to_vec())
+        .unwrap();
+      compiler.build().await.unwrap();
+
+      let errors: Vec<_> = compiler.compilation.get_errors().collect();
+      assert!(errors.is_empty());
+      let asset = &compiler.compilation.assets().get("main.js").unwrap();
+      assert_eq!(
+        asset.source.as_ref().unwrap().source().into_string_lossy(),
+        "console.log(456);"
+      );
+
+      compiler
+        .write_virtual_file(&virtual_file, b"console.log(789);".to_vec())
+        .unwrap();
+      compiler
+        .rebuild(Default::default(), Default::default())
+        .await
+        .unwrap();
+
+      let asset = &compiler.compilation.assets().get("main.js").unwrap();
+      assert_eq!(
+        asset.source.as_ref().unwrap().source().into_string_lossy(),
+        "console.log(789);"
+      );
+
+      assert!(compiler.remove_virtual_file(&virtual_file).unwrap());
+      compiler
+        .rebuild(Default::default(), Default::default())
+        .await
+        .unwrap();
+      assert!(compiler.compilation.get_errors().next().is_some());
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn basic_archive_output() {
+    use std::sync::Arc;
+
+    use rspack_fs::{ArchiveFileSystem, ArchiveFormat};
+    use rspack_tasks::within_compiler_context_for_testing;
+    async fn build_archive(context: &Utf8Path) -> Vec<u8> {
+      let archive = ArchiveFileSystem::new(ArchiveFormat::TarGz, context.join("dist"));
+      let mut compiler = Compiler::builder()
+        .context(context)
+        .entry("main", "./src/index.js")
+        .output_filesystem(Arc::new(archive.clone()))
+        .build()
+        .unwrap();
+
+      compiler.build().await.unwrap();
+
+      let errors: Vec<_> = compiler.compilation.get_errors().collect();
+      assert!(errors.is_empty());
+      // the assets are emitted concurrently
+      assert!(compiler.compilation.assets().len() > 1);
+      archive.finish().await.unwrap()
+    }
+
+    within_compiler_context_for_testing(async {
+      let context = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/archive");
+      let first = build_archive(&context).await;
+      for _ in 0..5 {
+        assert_eq!(first, build_archive(&context).await);
+      }
+    })
+    .await;
+  }
 }
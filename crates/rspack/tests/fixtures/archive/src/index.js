@@ -0,0 +1,3 @@
+Promise.all([import("./a"), import("./b"), import("./c")]).then(modules => {
+	console.log(modules.map(module => module.default));
+});
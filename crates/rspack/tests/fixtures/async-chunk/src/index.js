@@ -0,0 +1,3 @@
+import("./async").then(({ default: value }) => {
+	console.log(value);
+});
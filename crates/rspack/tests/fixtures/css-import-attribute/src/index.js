@@ -0,0 +1 @@
+import "./style.txt" with { type: "css" };
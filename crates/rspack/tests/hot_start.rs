@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{
+    Compiler, ExperimentCacheOptions, Experiments,
+    cache::persistent::{
+      PersistentCacheOptions,
+      snapshot::{PathMatcher, SnapshotOptions},
+      storage::StorageOptions,
+    },
+    incremental::{IncrementalOptions, IncrementalPasses},
+  };
+  use rspack_fs::{MemoryFileSystem, OverlayFileSystem};
+  use rspack_paths::{Utf8Path, Utf8PathBuf};
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  fn context() -> Utf8PathBuf {
+    Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/hot-start")
+  }
+
+  async fn build(input: &OverlayFileSystem, intermediate: &MemoryFileSystem) -> Compiler {
+    let mut compiler = Compiler::builder()
+      .context(context())
+      .entry("main", "./src/index.js")
+      .experiments(
+        Experiments::builder()
+          .cache(ExperimentCacheOptions::Persistent(PersistentCacheOptions {
+            build_dependencies: vec![],
+            version: String::new(),
+            // the snapshot skips `message.js`, so its changes are invisible to the hot start
+            snapshot: SnapshotOptions::new(
+              vec![PathMatcher::String("message.js".into())],
+              vec![],
+              vec![],
+              vec![],
+              false,
+            ),
+            storage: StorageOptions::FileSystem {
+              directory: "/cache".into(),
+              max_size: None,
+              compression: Default::default(),
+            },
+          }))
+          .incremental(IncrementalOptions {
+            silent: true,
+            passes: IncrementalPasses::MAKE | IncrementalPasses::EMIT_ASSETS,
+            check: true,
+          }),
+      )
+      .overlay_filesystem(input.clone())
+      .intermediate_filesystem(Arc::new(intermediate.clone()))
+      .output_filesystem(Arc::new(MemoryFileSystem::default()))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+  }
+
+  fn hot_start_mismatches(compiler: &Compiler) -> Vec<String> {
+    compiler
+      .compilation
+      .get_warnings()
+      .filter(|warning| warning.code.as_deref() == Some("HotStartMismatch"))
+      .map(|warning| warning.message.clone())
+      .collect()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn hot_start() {
+    within_compiler_context_for_testing(async {
+      let input = OverlayFileSystem::default();
+      let intermediate = MemoryFileSystem::default();
+
+      let compiler = build(&input, &intermediate).await;
+      assert!(
+        !compiler
+          .compilation
+          .incremental
+          .mutations_readable(IncrementalPasses::MAKE)
+      );
+      drop(compiler);
+
+      // a new compiler starts with the artifacts restored from storage,
+      // and its result is the same as the full build of the check
+      let compiler = build(&input, &intermediate).await;
+      assert!(
+        compiler
+          .compilation
+          .incremental
+          .mutations_readable(IncrementalPasses::MAKE)
+      );
+      assert!(hot_start_mismatches(&compiler).is_empty());
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn hot_start_mismatch() {
+    within_compiler_context_for_testing(async {
+      let input = OverlayFileSystem::default();
+      let intermediate = MemoryFileSystem::default();
+
+      drop(build(&input, &intermediate).await);
+
+      // the hot start keeps the stale `message.js` restored from storage,
+      // while the full build of the check reads the new one
+      input.write_file(
+        &context().join("src/message.js"),
+        br#"export const message = "world";"#.to_vec(),
+      );
+      let compiler = build(&input, &intermediate).await;
+      let mismatches = hot_start_mismatches(&compiler);
+      assert_eq!(mismatches.len(), 1);
+      assert!(mismatches[0].contains("`main.js` differs from"));
+    })
+    .await;
+  }
+}
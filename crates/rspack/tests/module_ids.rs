@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+  use rspack::builder::Builder as _;
+  use rspack_core::{ChunkGraph, Compiler, Optimization};
+  use rspack_paths::Utf8Path;
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn hashed_module_ids() {
+    within_compiler_context_for_testing(async {
+      let mut compiler = Compiler::builder()
+        .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+        .entry("main", "./src/index.js")
+        .optimization(Optimization::builder().module_ids("hashed".to_string()))
+        .build()
+        .unwrap();
+      compiler.build().await.unwrap();
+
+      let compilation = &compiler.compilation;
+      assert!(compilation.get_errors().next().is_none());
+      let module = compilation
+        .get_module_graph()
+        .modules()
+        .keys()
+        .find(|identifier| identifier.ends_with("index.js"))
+        .copied()
+        .unwrap();
+      // the first 4 characters of the base64 encoded md4 hash of "./src/index.js", the same as webpack
+      assert_eq!(
+        ChunkGraph::get_module_id(&compilation.module_ids_artifact, module)
+          .unwrap()
+          .as_str(),
+        "tjUo"
+      );
+    })
+    .await;
+  }
+}
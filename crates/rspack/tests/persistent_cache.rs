@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{
+    CacheOptions, Compiler, ExperimentCacheOptions, Experiments, LogType,
+    cache::persistent::{
+      PersistentCacheOptions, snapshot::SnapshotOptions, storage::StorageOptions,
+    },
+  };
+  use rspack_fs::{MemoryFileSystem, OverlayFileSystem};
+  use rspack_paths::{Utf8Path, Utf8PathBuf};
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  fn context() -> Utf8PathBuf {
+    Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/async-chunk")
+  }
+
+  async fn build(input: &OverlayFileSystem, intermediate: &MemoryFileSystem) -> Compiler {
+    let mut compiler = Compiler::builder()
+      .context(context())
+      .entry("main", "./src/index.js")
+      .cache(CacheOptions::Memory {
+        max_generations: None,
+      })
+      .experiments(
+        Experiments::builder().cache(ExperimentCacheOptions::Persistent(PersistentCacheOptions {
+          build_dependencies: vec![],
+          version: String::new(),
+          snapshot: SnapshotOptions::default(),
+          storage: StorageOptions::FileSystem {
+            directory: "/cache".into(),
+            max_size: None,
+            compression: Default::default(),
+          },
+        })),
+      )
+      .overlay_filesystem(input.clone())
+      .intermediate_filesystem(Arc::new(intermediate.clone()))
+      .output_filesystem(Arc::new(MemoryFileSystem::default()))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+  }
+
+  /// The hits and total of the cache logs of the compilation.
+  fn cache_log(compiler: &Compiler, label: &str) -> Option<(u32, u32)> {
+    let logging = compiler.compilation.get_logging();
+    let logs = logging.get("rspack.Compilation")?;
+    logs.iter().find_map(|log| match log {
+      LogType::Cache {
+        label: l,
+        hit,
+        total,
+      } if *l == label => Some((*hit, *total)),
+      _ => None,
+    })
+  }
+
+  fn chunk_hashes(compiler: &Compiler) -> Vec<String> {
+    let compilation = &compiler.compilation;
+    let mut hashes = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk.hash(&compilation.chunk_hashes_artifact))
+      .map(|hash| hash.encoded().to_string())
+      .collect::<Vec<_>>();
+    hashes.sort();
+    hashes
+  }
+
+  fn content_hashes(compiler: &Compiler) -> Vec<String> {
+    let compilation = &compiler.compilation;
+    let mut hashes = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk.content_hash(&compilation.chunk_hashes_artifact))
+      .flat_map(|content_hash| content_hash.values())
+      .map(|hash| hash.encoded().to_string())
+      .collect::<Vec<_>>();
+    hashes.sort();
+    hashes
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn restore_across_compilers() {
+    within_compiler_context_for_testing(async {
+      let input = OverlayFileSystem::default();
+      let intermediate = MemoryFileSystem::default();
+
+      let compiler = build(&input, &intermediate).await;
+      let (hit, _) = cache_log(&compiler, "chunk hash cache").unwrap();
+      assert_eq!(hit, 0);
+      let hashes = chunk_hashes(&compiler);
+      drop(compiler);
+
+      // a new compiler restores the code generation results and chunk hashes from storage
+      let compiler = build(&input, &intermediate).await;
+      let (hit, _) = cache_log(&compiler, "module code generation cache").unwrap();
+      assert!(hit > 0);
+      let (hit, total) = cache_log(&compiler, "chunk hash cache").unwrap();
+      assert!(hit > 0 && hit == total);
+      assert_eq!(chunk_hashes(&compiler), hashes);
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn restore_with_changed_module() {
+    within_compiler_context_for_testing(async {
+      let input = OverlayFileSystem::default();
+      let intermediate = MemoryFileSystem::default();
+
+      let compiler = build(&input, &intermediate).await;
+      let hashes = chunk_hashes(&compiler);
+      let contents = content_hashes(&compiler);
+      drop(compiler);
+
+      // the chunk of the changed module must not restore its stale hashes
+      input.write_file(
+        &context().join("src/async.js"),
+        b"export default 24;".to_vec(),
+      );
+      let compiler = build(&input, &intermediate).await;
+      let (hit, total) = cache_log(&compiler, "chunk hash cache").unwrap();
+      assert!(hit < total);
+      assert_ne!(chunk_hashes(&compiler), hashes);
+      let new_contents = content_hashes(&compiler);
+      assert_eq!(new_contents.len(), contents.len());
+      assert!(new_contents.iter().any(|hash| !contents.contains(hash)));
+    })
+    .await;
+  }
+}
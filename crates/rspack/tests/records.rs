@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{ChunkGraph, Compilation, Compiler, Optimization};
+  use rspack_fs::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+  use rspack_ids::{RecordIdsPlugin, RecordIdsPluginOptions};
+  use rspack_paths::{Utf8Path, Utf8PathBuf};
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  fn context() -> Utf8PathBuf {
+    Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic")
+  }
+
+  fn records_path() -> Utf8PathBuf {
+    Utf8PathBuf::from("/records/records.json")
+  }
+
+  async fn build(intermediate: &MemoryFileSystem) -> Compiler {
+    let mut compiler = Compiler::builder()
+      .context(context())
+      .entry("main", "./src/index.js")
+      .optimization(Optimization::builder().chunk_ids("named".to_string()))
+      .intermediate_filesystem(Arc::new(intermediate.clone()))
+      .output_filesystem(Arc::new(MemoryFileSystem::default()))
+      .plugin(Box::new(RecordIdsPlugin::new(RecordIdsPluginOptions {
+        input_path: Some(records_path()),
+        output_path: Some(records_path()),
+      })))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+  }
+
+  fn module_id(compilation: &Compilation) -> String {
+    let module = compilation
+      .get_module_graph()
+      .modules()
+      .keys()
+      .find(|identifier| identifier.ends_with("index.js"))
+      .copied()
+      .unwrap();
+    ChunkGraph::get_module_id(&compilation.module_ids_artifact, module)
+      .unwrap()
+      .to_string()
+  }
+
+  fn chunk_id(compilation: &Compilation) -> String {
+    let chunk = compilation.chunk_by_ukey.values().next().unwrap();
+    chunk.id().unwrap().to_string()
+  }
+
+  async fn read_records(intermediate: &MemoryFileSystem) -> serde_json::Value {
+    serde_json::from_slice(&intermediate.read(&records_path()).await.unwrap()).unwrap()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn records() {
+    within_compiler_context_for_testing(async {
+      let intermediate = MemoryFileSystem::default();
+
+      // write the records of the first build
+      let compiler = build(&intermediate).await;
+      let (module, chunk) = (
+        module_id(&compiler.compilation),
+        chunk_id(&compiler.compilation),
+      );
+      drop(compiler);
+      let records = read_records(&intermediate).await;
+      assert!(records["modules"]["byIdentifier"]["./src/index.js"].is_number());
+      assert_eq!(records["chunks"]["byName"]["main"], "main");
+
+      // the second compilation reads the records written by the first one
+      let compiler = build(&intermediate).await;
+      assert_eq!(module_id(&compiler.compilation), module);
+      assert_eq!(chunk_id(&compiler.compilation), chunk);
+      drop(compiler);
+
+      // reuse the ids in the records, even if the chunk is named
+      intermediate
+        .write(
+          &records_path(),
+          br#"{ "modules": { "byIdentifier": { "./src/index.js": 42 } }, "chunks": { "byName": { "main": "entry" } } }"#,
+        )
+        .await
+        .unwrap();
+      let compiler = build(&intermediate).await;
+      assert_eq!(module_id(&compiler.compilation), "42");
+      assert_eq!(chunk_id(&compiler.compilation), "entry");
+
+      let records = read_records(&intermediate).await;
+      assert_eq!(records["modules"]["byIdentifier"]["./src/index.js"], 42);
+      assert_eq!(records["chunks"]["byName"]["main"], "entry");
+    })
+    .await;
+  }
+}
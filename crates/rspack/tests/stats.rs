@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+  use rspack::builder::Builder as _;
+  use rspack_core::{Compiler, ExtendedStatsOptions, StatsPreset, StatsPrinter};
+  use rspack_paths::Utf8Path;
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  async fn build() -> Compiler {
+    let mut compiler = Compiler::builder()
+      .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+      .entry("main", "./src/index.js")
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    compiler
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn stats_json() {
+    within_compiler_context_for_testing(async move {
+      let compiler = build().await;
+      let stats = compiler.compilation.get_stats();
+
+      let json = stats
+        .to_json(&ExtendedStatsOptions::from_preset(StatsPreset::Normal))
+        .unwrap();
+      assert_eq!(json["errorsCount"], 0);
+      assert_eq!(json["warningsCount"], 0);
+      assert_eq!(json["assets"][0]["name"], "main.js");
+      assert_eq!(json["assetsByChunkName"]["main"][0], "main.js");
+      assert!(
+        json["modules"]
+          .as_array()
+          .unwrap()
+          .iter()
+          .any(|module| module["name"] == "./src/index.js"
+            && module["moduleType"] == "javascript/auto")
+      );
+      assert!(json["entrypoints"]["main"]["assets"].is_array());
+
+      let json = stats
+        .to_json(&ExtendedStatsOptions::from_preset(StatsPreset::ErrorsOnly))
+        .unwrap();
+      assert!(json.get("assets").is_none());
+      assert!(json.get("modules").is_none());
+      assert!(json["errors"].as_array().unwrap().is_empty());
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn stats_printer() {
+    within_compiler_context_for_testing(async move {
+      let compiler = build().await;
+      let stats = compiler.compilation.get_stats();
+
+      let normal = StatsPrinter::new(StatsPreset::Normal, false)
+        .print(&stats)
+        .unwrap();
+      assert!(normal.contains("asset main.js"));
+      assert!(normal.contains("./src/index.js"));
+      assert!(normal.contains("Rspack compiled successfully"));
+
+      let minimal = StatsPrinter::new(StatsPreset::Minimal, false)
+        .print(&stats)
+        .unwrap();
+      assert!(!minimal.contains("asset main.js"));
+      assert!(minimal.contains("modules\n"));
+
+      let verbose = StatsPrinter::new(StatsPreset::Verbose, false)
+        .print(&stats)
+        .unwrap();
+      assert!(verbose.contains("Entrypoint main"));
+      assert!(verbose.contains("chunk (runtime: main) main.js (main)"));
+
+      let errors_only = StatsPrinter::new(StatsPreset::ErrorsOnly, false)
+        .print(&stats)
+        .unwrap();
+      assert!(errors_only.is_empty());
+
+      assert!("errors-only".parse::<StatsPreset>().is_ok());
+      assert!("detailed".parse::<StatsPreset>().is_err());
+    })
+    .await;
+  }
+}
@@ -28,6 +28,21 @@ impl NodeFileSystem {
     Ok(Self(Arc::new(tsfs)))
   }
 }
+
+/// The errors of the node filesystem only keep their messages, which contain the error code,
+/// so `ENOENT` is mapped back to `ErrorKind::NotFound` for the callers checking missing files.
+fn to_read_result<T, E: ToString>(res: std::result::Result<T, E>) -> Result<T> {
+  res.map_err(|e| {
+    let message = e.to_string();
+    let kind = if message.contains("ENOENT") {
+      std::io::ErrorKind::NotFound
+    } else {
+      std::io::ErrorKind::Other
+    };
+    Error::new(kind, &message)
+  })
+}
+
 #[async_trait]
 impl WritableFileSystem for NodeFileSystem {
   async fn create_dir(&self, dir: &Utf8Path) -> Result<()> {
@@ -100,12 +115,7 @@ impl WritableFileSystem for NodeFileSystem {
   // TODO: support read_file options
   async fn read_file(&self, file: &Utf8Path) -> Result<Vec<u8>> {
     let file = file.as_str().to_string();
-    let res = self
-      .0
-      .read_file
-      .call_with_promise(file)
-      .await
-      .to_fs_result()?;
+    let res = to_read_result(self.0.read_file.call_with_promise(file).await)?;
 
     match res {
       Either3::A(data) => Ok(data.to_vec()),
@@ -173,18 +183,19 @@ impl IntermediateFileSystem for NodeFileSystem {}
 impl ReadableFileSystem for NodeFileSystem {
   #[instrument(skip(self), level = "debug")]
   async fn read(&self, path: &Utf8Path) -> Result<Vec<u8>> {
-    self
-      .0
-      .read_file
-      .call_with_promise(path.as_str().to_string())
-      .await
-      .to_fs_result()
-      // TODO: simplify the return value?
-      .map(|result| match result {
-        Either3::A(buf) => buf.into(),
-        Either3::B(str) => str.into(),
-        Either3::C(_) => vec![],
-      })
+    to_read_result(
+      self
+        .0
+        .read_file
+        .call_with_promise(path.as_str().to_string())
+        .await,
+    )
+    // TODO: simplify the return value?
+    .map(|result| match result {
+      Either3::A(buf) => buf.into(),
+      Either3::B(str) => str.into(),
+      Either3::C(_) => vec![],
+    })
   }
   #[instrument(skip(self), level = "debug")]
   fn read_sync(&self, path: &Utf8Path) -> Result<Vec<u8>> {
@@ -0,0 +1,47 @@
+use std::hash::Hasher;
+
+use napi::{Either, bindgen_prelude::Buffer};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+use crate::error::RspackResultToNapiResultExt;
+
+/// The hash functions which are implemented in rust only, so that `compilation.createHash`
+/// produces the same result as the hashes calculated by the compiler.
+#[napi]
+pub struct NativeHash {
+  hash: Option<RspackHash>,
+}
+
+#[napi]
+impl NativeHash {
+  #[napi(constructor)]
+  pub fn new(function: String) -> napi::Result<Self> {
+    let function = HashFunction::try_from(function.as_str()).to_napi_result()?;
+    Ok(Self {
+      hash: Some(RspackHash::new(&function)),
+    })
+  }
+
+  #[napi]
+  pub fn update(&mut self, data: Either<String, Buffer>) -> napi::Result<()> {
+    let hash = self
+      .hash
+      .as_mut()
+      .ok_or_else(|| napi::Error::from_reason("Hash has been digested"))?;
+    match data {
+      Either::A(data) => hash.write(data.as_bytes()),
+      Either::B(data) => hash.write(&data),
+    }
+    Ok(())
+  }
+
+  /// Returns the digest in hex, the hash can't be updated anymore.
+  #[napi]
+  pub fn digest(&mut self) -> napi::Result<String> {
+    let hash = self
+      .hash
+      .take()
+      .ok_or_else(|| napi::Error::from_reason("Hash has been digested"))?;
+    Ok(hash.digest(&HashDigest::Hex).encoded().to_string())
+  }
+}
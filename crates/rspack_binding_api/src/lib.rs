@@ -70,6 +70,7 @@ mod error;
 mod exports_info;
 mod filename;
 mod fs_node;
+mod hash;
 mod html;
 mod identifier;
 mod location;
@@ -8,7 +8,9 @@ use napi::bindgen_prelude::*;
 use napi_derive::*;
 use rspack_paths::ArcPath;
 use rspack_regex::RspackRegex;
-use rspack_watcher::{FsEventKind, FsWatcher, FsWatcherIgnored, FsWatcherOptions};
+use rspack_watcher::{
+  FsEventKind, FsWatcher, FsWatcherIgnored, FsWatcherOptions, FsWatcherPolling,
+};
 
 type JsWatcherIgnored = Either3<String, Vec<String>, RspackRegex>;
 
@@ -24,14 +26,36 @@ fn to_fs_watcher_ignored(ignored: Option<JsWatcherIgnored>) -> FsWatcherIgnored
   }
 }
 
+fn to_fs_watcher_polling(poll: Option<bool>, poll_roots: Option<Vec<String>>) -> FsWatcherPolling {
+  if poll.unwrap_or(false) {
+    return FsWatcherPolling::All;
+  }
+  match poll_roots {
+    Some(roots) if !roots.is_empty() => {
+      FsWatcherPolling::Roots(roots.into_iter().map(ArcPath::from).collect())
+    }
+    _ => FsWatcherPolling::Disabled,
+  }
+}
+
 #[napi(object, object_to_js = false)]
 pub struct NativeWatcherOptions {
   pub follow_symlinks: Option<bool>,
 
   pub poll_interval: Option<u32>,
 
+  /// Whether to poll all watched paths instead of relying on native events.
+  pub poll: Option<bool>,
+
+  /// Only poll the watched paths under these roots, other paths still use native events.
+  /// It is ignored when `poll` is true.
+  pub poll_roots: Option<Vec<String>>,
+
   pub aggregate_timeout: Option<u32>,
 
+  /// The file to record the events of the watcher, used to debug the watch mode.
+  pub journal: Option<String>,
+
   #[napi(ts_type = "string | string[] | RegExp")]
   /// The ignored paths for the watcher.
   /// It can be a single path, an array of paths, or a regular expression.
@@ -62,7 +86,9 @@ impl NativeWatcher {
       FsWatcherOptions {
         follow_symlinks: options.follow_symlinks.unwrap_or(false),
         poll_interval: options.poll_interval,
+        polling: to_fs_watcher_polling(options.poll, options.poll_roots),
         aggregate_timeout: options.aggregate_timeout,
+        journal: options.journal.map(PathBuf::from),
       },
       to_fs_watcher_ignored(options.ignored.clone()),
     );
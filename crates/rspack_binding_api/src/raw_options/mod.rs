@@ -77,7 +77,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
     let mode = value.mode.unwrap_or_default().into();
     let module: ModuleOptions = value.module.try_into()?;
     let cache = value.cache.into();
-    let mut experiments: Experiments = value.experiments.into();
+    let mut experiments: Experiments = value.experiments.try_into()?;
     if let CacheOptions::Disabled = cache {
       experiments.incremental.passes = IncrementalPasses::empty();
     }
@@ -1,8 +1,8 @@
 use napi::Either;
 use napi_derive::napi;
-use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_error::{Result, ToStringResultToRspackResultExt, error};
 use rspack_plugin_swc_js_minimizer::{
-  ExtractComments, MinimizerOptions, OptionWrapper, PluginOptions,
+  ExtractComments, LegalComments, MinimizerOptions, NameCacheOptions, OptionWrapper, PluginOptions,
 };
 use serde::de::DeserializeOwned;
 use swc_core::base::BoolOrDataConfig;
@@ -27,6 +27,10 @@ pub struct RawSwcJsMinimizerRspackPluginOptions {
   pub exclude: Option<RawAssetConditions>,
   pub extract_comments: Option<RawExtractComments>,
   pub minimizer_options: RawSwcJsMinimizerOptions,
+  #[napi(ts_type = "boolean | string")]
+  pub name_cache: Option<Either<bool, String>>,
+  #[napi(ts_type = "'inline' | 'eof' | 'linked' | 'external'")]
+  pub legal_comments: Option<String>,
 }
 
 #[derive(Debug)]
@@ -67,6 +71,30 @@ fn into_extract_comments(c: Option<RawExtractComments>) -> Option<ExtractComment
   Some(ExtractComments { condition, banner })
 }
 
+fn into_name_cache(c: Option<Either<bool, String>>) -> Option<NameCacheOptions> {
+  match c? {
+    Either::A(true) => Some(NameCacheOptions { path: None }),
+    Either::A(false) => None,
+    Either::B(path) => Some(NameCacheOptions {
+      path: Some(path.into()),
+    }),
+  }
+}
+
+fn into_legal_comments(c: Option<String>) -> Result<Option<LegalComments>> {
+  let Some(c) = c else {
+    return Ok(None);
+  };
+  let legal_comments = match c.as_str() {
+    "inline" => LegalComments::Inline,
+    "eof" => LegalComments::Eof,
+    "linked" => LegalComments::Linked,
+    "external" => LegalComments::External,
+    _ => return Err(error!("Invalid legalComments option: {c}")),
+  };
+  Ok(Some(legal_comments))
+}
+
 impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for PluginOptions {
   type Error = rspack_error::Error;
 
@@ -84,8 +112,16 @@ impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for PluginOptions {
       value.minimizer_options.ecma,
     )?;
 
+    let extract_comments = into_extract_comments(value.extract_comments);
+    let legal_comments = into_legal_comments(value.legal_comments)?;
+    if extract_comments.is_some() && legal_comments.is_some() {
+      return Err(error!(
+        "`extractComments` and `legalComments` can not be used at the same time"
+      ));
+    }
+
     Ok(Self {
-      extract_comments: into_extract_comments(value.extract_comments),
+      extract_comments,
       test: value.test.map(into_asset_conditions),
       include: value.include.map(into_asset_conditions),
       exclude: value.exclude.map(into_asset_conditions),
@@ -98,6 +134,8 @@ impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for PluginOptions {
         minify: value.minimizer_options.minify,
         ..Default::default()
       },
+      name_cache: into_name_cache(value.name_cache),
+      legal_comments,
     })
   }
 }
@@ -29,9 +29,11 @@ pub struct RawExperiments {
   pub defer_import: bool,
 }
 
-impl From<RawExperiments> for Experiments {
-  fn from(value: RawExperiments) -> Self {
-    Self {
+impl TryFrom<RawExperiments> for Experiments {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExperiments) -> rspack_error::Result<Self> {
+    Ok(Self {
       incremental: match value.incremental {
         Some(value) => match value {
           WithFalse::True(value) => value.into(),
@@ -41,10 +43,10 @@ impl From<RawExperiments> for Experiments {
       },
       top_level_await: value.top_level_await,
       rspack_future: value.rspack_future.unwrap_or_default().into(),
-      cache: normalize_raw_experiment_cache_options(value.cache),
+      cache: normalize_raw_experiment_cache_options(value.cache)?,
       css: value.css.unwrap_or(false),
       lazy_barrel: value.lazy_barrel,
       defer_import: value.defer_import,
-    }
+    })
   }
 }
@@ -12,6 +12,9 @@ pub struct RawExperimentSnapshotOptions {
   pub unmanaged_paths: Vec<RawPathMatcher>,
   #[napi(ts_type = r#"Array<string|RegExp>"#)]
   pub managed_paths: Vec<RawPathMatcher>,
+  #[napi(ts_type = r#"Array<string|RegExp>"#)]
+  pub content_hash_paths: Vec<RawPathMatcher>,
+  pub git_index: bool,
 }
 
 type RawPathMatcher = Either<String, RspackRegex>;
@@ -34,6 +37,12 @@ impl From<RawExperimentSnapshotOptions> for SnapshotOptions {
         .into_iter()
         .map(normalize_raw_path_matcher)
         .collect(),
+      value
+        .content_hash_paths
+        .into_iter()
+        .map(normalize_raw_path_matcher)
+        .collect(),
+      value.git_index,
     )
   }
 }
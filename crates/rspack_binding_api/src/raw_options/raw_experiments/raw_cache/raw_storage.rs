@@ -1,21 +1,44 @@
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{PackCompression, StorageOptions};
+use rspack_error::error;
 
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawStorageOptions {
-  #[napi(ts_type = r#""filesystem""#)]
+  #[napi(ts_type = r#""filesystem" | "remote""#)]
   pub r#type: String,
   pub directory: String,
+  pub url: Option<String>,
+  pub max_size: Option<f64>,
+  #[napi(ts_type = r#""none" | "zstd" | "lz4""#)]
+  pub compression: Option<String>,
 }
 
-impl From<RawStorageOptions> for StorageOptions {
-  fn from(value: RawStorageOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawStorageOptions> for StorageOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStorageOptions) -> Result<Self, Self::Error> {
+    let max_size = value.max_size.map(|size| size as usize);
+    let compression = match value.compression.as_deref() {
+      None => PackCompression::None,
+      Some(name) => PackCompression::from_name(name)
+        .ok_or_else(|| error!("unsupported experiments.cache.storage.compression \"{name}\""))?,
+    };
+    Ok(match value.r#type.as_str() {
       "filesystem" => StorageOptions::FileSystem {
         directory: value.directory.into(),
+        max_size,
+        compression,
+      },
+      "remote" => StorageOptions::Remote {
+        directory: value.directory.into(),
+        url: value.url.ok_or_else(|| {
+          error!("experiments.cache.storage.url is required when the storage type is \"remote\"")
+        })?,
+        max_size,
+        compression,
       },
-      s => panic!("unsupported storage type {s}"),
-    }
+      s => return Err(error!("unsupported experiments.cache.storage.type \"{s}\"")),
+    })
   }
 }
@@ -5,6 +5,7 @@ use rspack_core::incremental::{IncrementalOptions, IncrementalPasses};
 #[napi(object)]
 pub struct RawIncremental {
   pub silent: bool,
+  pub check: bool,
   // passes
   pub make: bool,
   pub infer_async_modules: bool,
@@ -74,6 +75,7 @@ impl From<RawIncremental> for IncrementalOptions {
     Self {
       silent: value.silent,
       passes,
+      check: value.check,
     }
   }
 }
@@ -9,7 +9,7 @@ use std::{
 use dashmap::{DashMap, DashSet};
 use hashlink::{LinkedHashMap, LinkedHashSet};
 use indexmap::{IndexMap, IndexSet};
-use rspack_cacheable::{cacheable, with, with::AsPreset};
+use rspack_cacheable::{cacheable, utils::PortableString, with, with::As};
 use serde::Serialize;
 use ustr::Ustr;
 
@@ -34,10 +34,12 @@ pub type IdentifierIndexSet = IndexSet<Identifier, BuildHasherDefault<Identifier
 pub type IdentifierDashSet = DashSet<Identifier, BuildHasherDefault<IdentifierHasher>>;
 pub type IdentifierLinkedSet = LinkedHashSet<Identifier, BuildHasherDefault<IdentifierHasher>>;
 
-#[cacheable(hashable)]
+/// Absolute paths in identifiers are serialized relative to the project root,
+/// so that the persistent cache can be reused after moving the project.
+#[cacheable(with=As<PortableString>, hashable)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
 #[cfg_attr(allocative, derive(allocative::Allocative))]
-pub struct Identifier(#[cacheable(with=AsPreset)] Ustr);
+pub struct Identifier(Ustr);
 
 impl Deref for Identifier {
   type Target = Ustr;
@@ -98,6 +100,12 @@ impl fmt::Display for Identifier {
   }
 }
 
+impl AsRef<str> for Identifier {
+  fn as_ref(&self) -> &str {
+    self.0.as_str()
+  }
+}
+
 // for Identifier
 impl with::AsRefStrConverter for Identifier {
   fn as_str(&self) -> &str {
@@ -1,10 +1,17 @@
-use rspack_collections::UkeyMap;
+use rspack_collections::{Identifier, IdentifierMap, UkeyMap};
+use rspack_hash::RspackHashDigest;
 
 use crate::{ChunkHashesResult, ChunkUkey};
 
 #[derive(Debug, Default)]
 pub struct ChunkHashesArtifact {
   chunk_to_hashes: UkeyMap<ChunkUkey, ChunkHashesResult>,
+  /// The digest of the inputs of the chunks which are hashed independently of other chunks,
+  /// it keys the chunk hashes in persistent cache since chunk ukey is not stable.
+  chunk_to_input_hash: UkeyMap<ChunkUkey, RspackHashDigest>,
+  /// The chunk hashes of the last compilation keyed by the input hash,
+  /// which are restored by persistent cache.
+  restored: IdentifierMap<ChunkHashesResult>,
 }
 
 impl ChunkHashesArtifact {
@@ -28,14 +35,48 @@ impl ChunkHashesArtifact {
   }
 
   pub fn remove(&mut self, chunk: &ChunkUkey) -> Option<ChunkHashesResult> {
+    self.chunk_to_input_hash.remove(chunk);
     self.chunk_to_hashes.remove(chunk)
   }
 
-  pub fn retain(&mut self, f: impl FnMut(&ChunkUkey, &mut ChunkHashesResult) -> bool) {
-    self.chunk_to_hashes.retain(f)
+  pub fn retain(&mut self, mut f: impl FnMut(&ChunkUkey, &mut ChunkHashesResult) -> bool) {
+    self
+      .chunk_to_hashes
+      .retain(|chunk, hashes| f(chunk, hashes));
+    let chunk_to_hashes = &self.chunk_to_hashes;
+    self
+      .chunk_to_input_hash
+      .retain(|chunk, _| chunk_to_hashes.contains_key(chunk));
   }
 
+  /// Clear the hashes of the chunks, the restored hashes are kept.
   pub fn clear(&mut self) {
     self.chunk_to_hashes.clear();
+    self.chunk_to_input_hash.clear();
+  }
+
+  pub fn get_input_hash(&self, chunk: &ChunkUkey) -> Option<&RspackHashDigest> {
+    self.chunk_to_input_hash.get(chunk)
+  }
+
+  pub fn set_input_hash(&mut self, chunk: ChunkUkey, input_hash: Option<RspackHashDigest>) {
+    match input_hash {
+      Some(input_hash) => self.chunk_to_input_hash.insert(chunk, input_hash),
+      None => self.chunk_to_input_hash.remove(&chunk),
+    };
+  }
+
+  /// Get the restored hashes of the last compilation by the input hash.
+  pub(crate) fn get_restored(&self, input_hash: &RspackHashDigest) -> Option<&ChunkHashesResult> {
+    self.restored.get(&Identifier::from(input_hash.encoded()))
+  }
+
+  /// Restore the hashes of the last compilation by the input hash.
+  ///
+  /// It's used by persistent cache.
+  pub(crate) fn restore(&mut self, input_hash: &RspackHashDigest, hashes: ChunkHashesResult) {
+    self
+      .restored
+      .insert(Identifier::from(input_hash.encoded()), hashes);
   }
 }
@@ -1,6 +1,7 @@
 use futures::Future;
 use rspack_collections::Identifier;
 use rspack_error::{Diagnostic, Result};
+use rspack_hash::RspackHashDigest;
 use rspack_sources::BoxSource;
 
 use crate::{Chunk, Compilation, MemoryGCStorage, SourceType};
@@ -21,6 +22,19 @@ impl ChunkRenderCacheArtifact {
       storage.start_next_generation();
     }
   }
+  /// Get the cached render result by the chunk content hash.
+  pub(crate) fn get(&self, content_hash: &RspackHashDigest) -> Option<BoxSource> {
+    let storage = self.storage.as_ref()?;
+    storage.get(&Identifier::from(content_hash.encoded()))
+  }
+  /// Set the cached render result by the chunk content hash.
+  ///
+  /// It's used by persistent cache to restore the results of the last compilation.
+  pub(crate) fn set(&self, content_hash: &RspackHashDigest, source: BoxSource) {
+    if let Some(storage) = &self.storage {
+      storage.set(Identifier::from(content_hash.encoded()), source);
+    }
+  }
   pub async fn use_cache<G, F>(
     &self,
     compilation: &Compilation,
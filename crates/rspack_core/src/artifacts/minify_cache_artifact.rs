@@ -0,0 +1,75 @@
+use rspack_collections::{Identifier, IdentifierDashMap};
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+
+use crate::MemoryGCStorage;
+
+/// The result of minifying an asset.
+#[derive(Debug, Clone)]
+pub struct MinifyResult {
+  pub source: BoxSource,
+  /// The comments extracted from the asset, e.g. the license comments.
+  pub extracted_comments: Option<String>,
+}
+
+/// Minify cache keyed by the hash of the asset content and the minimizer options,
+/// so that the unchanged assets can skip minification.
+#[derive(Debug, Default)]
+pub struct MinifyCacheArtifact {
+  storage: Option<MemoryGCStorage<MinifyResult>>,
+  /// The keys used by the current compilation, only these results are persisted.
+  used_keys: IdentifierDashMap<RspackHashDigest>,
+}
+
+impl MinifyCacheArtifact {
+  pub fn new(storage: MemoryGCStorage<MinifyResult>) -> Self {
+    Self {
+      storage: Some(storage),
+      used_keys: Default::default(),
+    }
+  }
+  pub fn start_next_generation(&self) {
+    if let Some(storage) = &self.storage {
+      storage.start_next_generation();
+    }
+    self.used_keys.clear();
+  }
+  /// Get the cached minify result by the hash of the asset content and the minimizer options.
+  pub fn get(&self, key: &RspackHashDigest) -> Option<MinifyResult> {
+    let storage = self.storage.as_ref()?;
+    let id = Identifier::from(key.encoded());
+    let result = storage.get(&id)?;
+    self.used_keys.insert(id, key.clone());
+    Some(result)
+  }
+  /// Set the minify result by the hash of the asset content and the minimizer options.
+  pub fn set(&self, key: &RspackHashDigest, result: MinifyResult) {
+    if let Some(storage) = &self.storage {
+      let id = Identifier::from(key.encoded());
+      storage.set(id, result);
+      self.used_keys.insert(id, key.clone());
+    }
+  }
+  /// Restore the minify result of the last compilation.
+  ///
+  /// It's used by persistent cache, the restored results are not persisted again until they are used.
+  pub(crate) fn restore(&self, key: &RspackHashDigest, result: MinifyResult) {
+    if let Some(storage) = &self.storage {
+      storage.set(Identifier::from(key.encoded()), result);
+    }
+  }
+  /// The minify results used by the current compilation.
+  pub(crate) fn used_results(&self) -> Vec<(RspackHashDigest, MinifyResult)> {
+    let Some(storage) = &self.storage else {
+      return vec![];
+    };
+    self
+      .used_keys
+      .iter()
+      .filter_map(|item| {
+        let result = storage.get(item.key())?;
+        Some((item.value().clone(), result))
+      })
+      .collect()
+  }
+}
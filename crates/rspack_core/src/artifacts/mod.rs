@@ -9,6 +9,7 @@ mod chunk_hashes_artifact;
 mod chunk_ids_artifact;
 mod chunk_render_cache_artifact;
 mod code_generation_results;
+mod minify_cache_artifact;
 mod module_graph_cache_artifact;
 mod module_static_cache_artifact;
 mod side_effects_do_optimize_artifact;
@@ -19,6 +20,7 @@ pub use chunk_hashes_artifact::*;
 pub use chunk_ids_artifact::*;
 pub use chunk_render_cache_artifact::ChunkRenderCacheArtifact;
 pub use code_generation_results::*;
+pub use minify_cache_artifact::{MinifyCacheArtifact, MinifyResult};
 pub use module_graph_cache_artifact::*;
 pub use module_static_cache_artifact::*;
 pub use side_effects_do_optimize_artifact::*;
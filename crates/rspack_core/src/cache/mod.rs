@@ -6,7 +6,8 @@ use std::{fmt::Debug, sync::Arc};
 
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 
-use self::{disable::DisableCache, memory::MemoryCache, persistent::PersistentCache};
+pub(crate) use self::disable::DisableCache;
+use self::{memory::MemoryCache, persistent::PersistentCache};
 use crate::{
   Compilation, CompilerOptions, ExperimentCacheOptions,
   compilation::build_module_graph::BuildModuleGraphArtifact,
@@ -1,8 +1,110 @@
-#[derive(Debug)]
-pub struct CacheableContext;
+use std::path::{Path, PathBuf};
+
+use rspack_cacheable::utils::PortableString;
+use sugar_path::SugarPath;
+
+/// The prefix of the paths made relative to `project_root`, same as the one of `PortableString`.
+const PROJECT_ROOT_PLACEHOLDER: &str = "<project_root>";
+
+/// The context used by persistent cache to serialize data.
+///
+/// Absolute paths are stored relative to `project_root`, so that the cache
+/// is still valid after the project directory is moved.
+#[derive(Debug, Default)]
+pub struct CacheableContext {
+  project_root: Option<PathBuf>,
+}
+
+impl CacheableContext {
+  pub fn new(project_root: Option<PathBuf>) -> Self {
+    Self { project_root }
+  }
+
+  /// Convert a path to the portable string used as storage key.
+  ///
+  /// Absolute paths are made relative to `project_root` and prefixed with `<project_root>`,
+  /// so they can be told apart from the paths kept as they are.
+  pub fn portable_path(&self, path: &Path) -> String {
+    match &self.project_root {
+      Some(project_root) if path.is_absolute() => format!(
+        "{PROJECT_ROOT_PLACEHOLDER}/{}",
+        path.relative(project_root).to_slash_lossy()
+      ),
+      _ => path.to_slash_lossy().into_owned(),
+    }
+  }
+
+  /// Restore a path from the storage key generated by [`Self::portable_path`].
+  pub fn resolve_portable_path(&self, path: &str) -> PathBuf {
+    if let Some(project_root) = &self.project_root
+      && let Some(relative) = path.strip_prefix(PROJECT_ROOT_PLACEHOLDER)
+    {
+      return Path::new(relative.trim_start_matches('/')).absolutize_with(project_root.as_path());
+    }
+    PathBuf::from(path)
+  }
+
+  /// Convert a string which may contain absolute paths to the portable string used as storage key.
+  pub fn portable_string(&self, content: &str) -> String {
+    PortableString::new(content, self.project_root.as_deref()).into_path_string(None)
+  }
+}
 
 impl rspack_cacheable::CacheableContext for CacheableContext {
-  fn project_root(&self) -> Option<&std::path::Path> {
-    None
+  fn project_root(&self) -> Option<&Path> {
+    self.project_root.as_deref()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::{Path, PathBuf};
+
+  use super::CacheableContext;
+
+  #[test]
+  fn should_portable_path_round_trip() {
+    let context = CacheableContext::new(Some(PathBuf::from("/home/user/project")));
+    let round_trip =
+      |path: &str| context.resolve_portable_path(&context.portable_path(Path::new(path)));
+
+    assert_eq!(
+      context.portable_path(Path::new("/home/user/project/src/index.js")),
+      "<project_root>/src/index.js"
+    );
+    assert_eq!(
+      round_trip("/home/user/project/src/index.js"),
+      PathBuf::from("/home/user/project/src/index.js")
+    );
+    assert_eq!(
+      round_trip("/home/user/project"),
+      PathBuf::from("/home/user/project")
+    );
+
+    // the paths outside the project root are still resolved to themselves
+    assert_eq!(
+      context.portable_path(Path::new("/home/user/other/index.js")),
+      "<project_root>/../other/index.js"
+    );
+    assert_eq!(
+      round_trip("/home/user/other/index.js"),
+      PathBuf::from("/home/user/other/index.js")
+    );
+
+    // the relative paths are kept relative
+    assert_eq!(
+      context.portable_path(Path::new("src/index.js")),
+      "src/index.js"
+    );
+    assert_eq!(round_trip("src/index.js"), PathBuf::from("src/index.js"));
+
+    // the restored paths follow the moved project root
+    let moved = CacheableContext::new(Some(PathBuf::from("/workspace/project")));
+    assert_eq!(
+      moved.resolve_portable_path(
+        &context.portable_path(Path::new("/home/user/project/src/index.js"))
+      ),
+      PathBuf::from("/workspace/project/src/index.js")
+    );
   }
 }
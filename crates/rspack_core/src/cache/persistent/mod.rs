@@ -6,17 +6,21 @@ pub mod storage;
 
 use std::{
   hash::{DefaultHasher, Hash, Hasher},
-  sync::Arc,
+  sync::{Arc, Mutex},
 };
 
 pub use cacheable_context::CacheableContext;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 use rspack_paths::ArcPathSet;
+use rspack_storage::SaveReport;
 use rspack_workspace::rspack_pkg_version;
 
 use self::{
   build_dependencies::{BuildDeps, BuildDepsOptions},
-  occasion::{MakeOccasion, MetaOccasion},
+  occasion::{
+    ChunkHashOccasion, ChunkRenderOccasion, CodeGenerateOccasion, MakeOccasion, MetaOccasion,
+    MinifyOccasion,
+  },
   snapshot::{Snapshot, SnapshotOptions},
   storage::{Storage, StorageOptions, create_storage},
 };
@@ -43,9 +47,15 @@ pub struct PersistentCache {
   storage: Arc<dyn Storage>,
   make_occasion: MakeOccasion,
   meta_occasion: MetaOccasion,
+  code_generate_occasion: CodeGenerateOccasion,
+  chunk_hash_occasion: ChunkHashOccasion,
+  chunk_render_occasion: ChunkRenderOccasion,
+  minify_occasion: MinifyOccasion,
   async_mode: bool,
   // TODO replace to logger and output warnings directly.
   warnings: Vec<String>,
+  /// Reports of finished saves, which are output by the logger of next compilation in async mode.
+  save_reports: Arc<Mutex<Vec<SaveReport>>>,
 }
 
 impl PersistentCache {
@@ -57,19 +67,36 @@ impl PersistentCache {
     intermediate_filesystem: Arc<dyn IntermediateFileSystem>,
   ) -> Self {
     let async_mode = compiler_options.mode.is_development();
+    let context = Arc::new(CacheableContext::new(Some(
+      compiler_options
+        .context
+        .as_path()
+        .as_std_path()
+        .to_path_buf(),
+    )));
     let version = {
       let mut hasher = DefaultHasher::new();
       compiler_path.hash(&mut hasher);
-      option.hash(&mut hasher);
+      // storage location is not part of the version,
+      // so that the version can be used as the namespace of a shared remote storage.
+      // build dependencies are hashed relative to the context to keep the version portable.
+      for path in &option.build_dependencies {
+        context.portable_path(path).hash(&mut hasher);
+      }
+      option.version.hash(&mut hasher);
+      option.snapshot.hash(&mut hasher);
       rspack_pkg_version!().hash(&mut hasher);
       compiler_options.name.hash(&mut hasher);
       compiler_options.mode.hash(&mut hasher);
       hex::encode(hasher.finish().to_ne_bytes())
     };
     let storage = create_storage(option.storage.clone(), version, intermediate_filesystem);
-    let context = Arc::new(CacheableContext);
-    let make_occasion = MakeOccasion::new(storage.clone(), context);
+    let make_occasion = MakeOccasion::new(storage.clone(), context.clone());
     let meta_occasion = MetaOccasion::new(storage.clone());
+    let code_generate_occasion = CodeGenerateOccasion::new(storage.clone(), context.clone());
+    let chunk_hash_occasion = ChunkHashOccasion::new(storage.clone(), context.clone());
+    let chunk_render_occasion = ChunkRenderOccasion::new(storage.clone(), context.clone());
+    let minify_occasion = MinifyOccasion::new(storage.clone(), context.clone());
     Self {
       initialized: false,
       build_deps: BuildDeps::new(
@@ -77,13 +104,24 @@ impl PersistentCache {
         &option.snapshot,
         input_filesystem.clone(),
         storage.clone(),
+        context.clone(),
+      ),
+      snapshot: Snapshot::new(
+        option.snapshot.clone(),
+        input_filesystem,
+        storage.clone(),
+        context,
       ),
-      snapshot: Snapshot::new(option.snapshot.clone(), input_filesystem, storage.clone()),
       storage,
       make_occasion,
       meta_occasion,
+      code_generate_occasion,
+      chunk_hash_occasion,
+      chunk_render_occasion,
+      minify_occasion,
       async_mode,
       warnings: Default::default(),
+      save_reports: Default::default(),
     }
   }
 
@@ -110,14 +148,23 @@ impl PersistentCache {
       }
     };
     if self.async_mode {
-      tokio::spawn(async {
-        if let Err(err) = rx.await.expect("should receive message") {
+      let save_reports = self.save_reports.clone();
+      tokio::spawn(async move {
+        match rx.await.expect("should receive message") {
+          Ok(report) => save_reports.lock().expect("should get lock").push(report),
           // TODO use infra structure logger to println
-          println!("persistent cache save failed. {err}");
+          Err(err) => println!("persistent cache save failed. {err}"),
         }
       });
-    } else if let Err(err) = rx.await.expect("should receive message") {
-      self.warnings.push(err.to_string());
+    } else {
+      match rx.await.expect("should receive message") {
+        Ok(report) => self
+          .save_reports
+          .lock()
+          .expect("should get lock")
+          .push(report),
+        Err(err) => self.warnings.push(err.to_string()),
+      }
     }
   }
 }
@@ -130,6 +177,21 @@ impl Cache for PersistentCache {
     // rebuild will pass modified_files and removed_files from js side,
     // so only calculate them when build.
     if !compilation.is_rebuild {
+      // recovery the code generation, chunk hash, chunk render and minify results to memory cache,
+      // they are keyed by the hash of their inputs, so the outdated results will never be hit.
+      if let Err(err) = self.code_generate_occasion.recovery(compilation).await {
+        self.warnings.push(err.to_string());
+      }
+      if let Err(err) = self.chunk_hash_occasion.recovery(compilation).await {
+        self.warnings.push(err.to_string());
+      }
+      if let Err(err) = self.chunk_render_occasion.recovery(compilation).await {
+        self.warnings.push(err.to_string());
+      }
+      if let Err(err) = self.minify_occasion.recovery(compilation).await {
+        self.warnings.push(err.to_string());
+      }
+
       let (is_hot_start, modified_paths, removed_paths, _) =
         match self.snapshot.calc_modified_paths().await {
           Ok(res) => res,
@@ -150,6 +212,12 @@ impl Cache for PersistentCache {
     // save meta
     self.meta_occasion.save();
 
+    // save code generation, chunk hash, chunk render and minify results
+    self.code_generate_occasion.save(compilation);
+    self.chunk_hash_occasion.save(compilation);
+    self.chunk_render_occasion.save(compilation);
+    self.minify_occasion.save(compilation);
+
     // save snapshot
     // TODO add a all_dependencies to collect dependencies
     let (_, file_added, file_removed) = compilation.file_dependencies();
@@ -184,6 +252,22 @@ impl Cache for PersistentCache {
     for msg in std::mem::take(&mut self.warnings) {
       logger.warn(msg);
     }
+    let save_reports = std::mem::take(&mut *self.save_reports.lock().expect("should get lock"));
+    for report in save_reports {
+      for version in &report.evicted {
+        logger.info(format!(
+          "evicted cache version {} ({} bytes) to fit the storage size budget",
+          version.version, version.size
+        ));
+      }
+      if report.is_over_budget() {
+        logger.warn(format!(
+          "cache directory size {} bytes still exceeds the storage size budget {} bytes",
+          report.total_size.unwrap_or_default(),
+          report.max_size.unwrap_or_default()
+        ));
+      }
+    }
   }
 
   async fn before_build_module_graph(&mut self, make_artifact: &mut BuildModuleGraphArtifact) {
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{
+  cacheable, from_bytes, to_bytes,
+  with::{AsCacheable, AsMap},
+};
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::{ChunkHashesResult, Compilation, SourceType};
+
+pub const SCOPE: &str = "occasion_chunk_hash";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  /// The digest of the chunk inputs which the hashes calculated with.
+  input_hash: RspackHashDigest,
+  hash: RspackHashDigest,
+  #[cacheable(with=AsMap<AsCacheable, AsCacheable>)]
+  content_hash: HashMap<SourceType, RspackHashDigest>,
+}
+
+/// Chunk Hash Occasion is used to save the hashes of ChunkHashesArtifact
+///
+/// The hashes are keyed by the digest of the chunk inputs, so they can be reused
+/// even if the chunk ukey changes between compilations.
+#[derive(Debug)]
+pub struct ChunkHashOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// The keys which has been written to storage.
+  saved_keys: HashSet<Vec<u8>>,
+}
+
+impl ChunkHashOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      storage,
+      context,
+      saved_keys: Default::default(),
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkHash::save", skip_all)]
+  pub fn save(&mut self, compilation: &Compilation) {
+    let artifact = &compilation.chunk_hashes_artifact;
+    let mut current_keys = HashSet::default();
+    let mut need_save = HashMap::default();
+    for chunk in compilation.chunk_by_ukey.keys() {
+      let (Some(input_hash), Some(hashes)) = (artifact.get_input_hash(chunk), artifact.get(chunk))
+      else {
+        continue;
+      };
+      let key = input_hash.encoded().as_bytes().to_vec();
+      if self.saved_keys.contains(&key) {
+        current_keys.insert(key);
+        continue;
+      }
+      need_save.insert(
+        key,
+        Node {
+          input_hash: input_hash.clone(),
+          hash: hashes.hash().clone(),
+          content_hash: hashes.content_hash().clone(),
+        },
+      );
+    }
+
+    let items = need_save
+      .into_par_iter()
+      .filter_map(|(key, node)| match to_bytes(&node, self.context.as_ref()) {
+        Ok(bytes) => Some((key, bytes)),
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+
+    for key in self.saved_keys.difference(&current_keys) {
+      self.storage.remove(SCOPE, key);
+    }
+    for (key, bytes) in items {
+      current_keys.insert(key.clone());
+      self.storage.set(SCOPE, key, bytes);
+    }
+    self.saved_keys = current_keys;
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkHash::recovery", skip_all)]
+  pub async fn recovery(&mut self, compilation: &mut Compilation) -> Result<()> {
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let node: Node = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected chunk hash deserialize failed");
+      compilation.chunk_hashes_artifact.restore(
+        &node.input_hash,
+        ChunkHashesResult::new(node.hash, node.content_hash),
+      );
+      self.saved_keys.insert(key.as_ref().clone());
+    }
+    Ok(())
+  }
+}
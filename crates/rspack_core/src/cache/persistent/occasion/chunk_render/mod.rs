@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes, with::AsPreset};
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::Compilation;
+
+pub const SCOPE: &str = "occasion_chunk_render";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  /// The chunk content hash which the source rendered with.
+  content_hash: RspackHashDigest,
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+}
+
+/// Chunk Render Occasion is used to save ChunkRenderCacheArtifact
+///
+/// The rendered sources are keyed by chunk content hash, so they can be reused
+/// even if the chunk ukey changes between compilations.
+#[derive(Debug)]
+pub struct ChunkRenderOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// The keys which has been written to storage.
+  saved_keys: HashSet<Vec<u8>>,
+}
+
+impl ChunkRenderOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      storage,
+      context,
+      saved_keys: Default::default(),
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::save", skip_all)]
+  pub fn save(&mut self, compilation: &Compilation) {
+    let artifact = &compilation.chunk_render_cache_artifact;
+    let mut current_keys = HashSet::default();
+    let mut need_save = HashMap::default();
+    for chunk in compilation.chunk_by_ukey.values() {
+      let Some(content_hash) = chunk.content_hash(&compilation.chunk_hashes_artifact) else {
+        continue;
+      };
+      for hash in content_hash.values() {
+        let key = hash.encoded().as_bytes().to_vec();
+        if self.saved_keys.contains(&key) {
+          current_keys.insert(key);
+          continue;
+        }
+        if need_save.contains_key(&key) {
+          continue;
+        }
+        let Some(source) = artifact.get(hash) else {
+          continue;
+        };
+        need_save.insert(
+          key,
+          Node {
+            content_hash: hash.clone(),
+            source,
+          },
+        );
+      }
+    }
+
+    let items = need_save
+      .into_par_iter()
+      .filter_map(|(key, node)| match to_bytes(&node, self.context.as_ref()) {
+        Ok(bytes) => Some((key, bytes)),
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+
+    for key in self.saved_keys.difference(&current_keys) {
+      self.storage.remove(SCOPE, key);
+    }
+    for (key, bytes) in items {
+      current_keys.insert(key.clone());
+      self.storage.set(SCOPE, key, bytes);
+    }
+    self.saved_keys = current_keys;
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::ChunkRender::recovery", skip_all)]
+  pub async fn recovery(&mut self, compilation: &Compilation) -> Result<()> {
+    let artifact = &compilation.chunk_render_cache_artifact;
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let node: Node = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected chunk render result deserialize failed");
+      artifact.set(&node.content_hash, node.source);
+      self.saved_keys.insert(key.as_ref().clone());
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{
+  cacheable, from_bytes, to_bytes,
+  with::{AsCacheable, AsMap, AsPreset},
+};
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::{
+  BindingCell, CodeGenerationResult, Compilation, ModuleIdentifier, RuntimeGlobals, SourceType,
+};
+
+pub const SCOPE: &str = "occasion_code_generate";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  module: ModuleIdentifier,
+  /// The chunk graph module hash which the result generated with.
+  hash: RspackHashDigest,
+  #[cacheable(with=AsMap<AsCacheable, AsPreset>)]
+  sources: HashMap<SourceType, BoxSource>,
+  runtime_requirements: RuntimeGlobals,
+  result_hash: Option<RspackHashDigest>,
+}
+
+/// Only the result without extra data can be saved,
+/// the data, chunk init fragments and concatenation scope are not serializable.
+fn is_persistable(result: &CodeGenerationResult) -> bool {
+  result.data.is_empty()
+    && result.chunk_init_fragments.is_empty()
+    && result.concatenation_scope.is_none()
+}
+
+/// Code Generate Occasion is used to save the code generation results
+///
+/// The results are keyed by module identifier and chunk graph module hash,
+/// which is the same as the memory cache of code generation.
+#[derive(Debug)]
+pub struct CodeGenerateOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// The keys which has been written to storage.
+  saved_keys: HashSet<Vec<u8>>,
+}
+
+impl CodeGenerateOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      storage,
+      context,
+      saved_keys: Default::default(),
+    }
+  }
+
+  fn storage_key(&self, module: &ModuleIdentifier, hash: &RspackHashDigest) -> Vec<u8> {
+    self
+      .context
+      .portable_string(&format!("{}|{}", module, hash.encoded()))
+      .into_bytes()
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::save", skip_all)]
+  pub fn save(&mut self, compilation: &Compilation) {
+    let occasion = &compilation.old_cache.code_generate_occasion;
+    let mut current_keys = HashSet::default();
+    let mut need_save = HashMap::default();
+    for module in compilation.code_generation_results.inner().0.keys() {
+      let Some(hashes) = compilation.cgm_hash_artifact.get_runtime_map(module) else {
+        continue;
+      };
+      for hash in hashes.values() {
+        let key = self.storage_key(module, hash);
+        if self.saved_keys.contains(&key) {
+          current_keys.insert(key);
+          continue;
+        }
+        if need_save.contains_key(&key) {
+          continue;
+        }
+        let Some(result) = occasion.get(module, hash) else {
+          continue;
+        };
+        if !is_persistable(&result) {
+          continue;
+        }
+        need_save.insert(
+          key,
+          Node {
+            module: *module,
+            hash: hash.clone(),
+            sources: result.inner().clone(),
+            runtime_requirements: result.runtime_requirements,
+            result_hash: result.hash,
+          },
+        );
+      }
+    }
+
+    let items = need_save
+      .into_par_iter()
+      .filter_map(|(key, node)| match to_bytes(&node, self.context.as_ref()) {
+        Ok(bytes) => Some((key, bytes)),
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+
+    for key in self.saved_keys.difference(&current_keys) {
+      self.storage.remove(SCOPE, key);
+    }
+    for (key, bytes) in items {
+      current_keys.insert(key.clone());
+      self.storage.set(SCOPE, key, bytes);
+    }
+    self.saved_keys = current_keys;
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::CodeGenerate::recovery", skip_all)]
+  pub async fn recovery(&mut self, compilation: &Compilation) -> Result<()> {
+    let occasion = &compilation.old_cache.code_generate_occasion;
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let node: Node = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected code generation result deserialize failed");
+      let result = CodeGenerationResult {
+        inner: BindingCell::from(node.sources),
+        runtime_requirements: node.runtime_requirements,
+        hash: node.result_hash,
+        ..Default::default()
+      };
+      occasion.set(&node.module, &node.hash, result);
+      self.saved_keys.insert(key.as_ref().clone());
+    }
+    Ok(())
+  }
+}
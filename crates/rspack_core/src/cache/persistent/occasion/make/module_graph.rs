@@ -46,7 +46,7 @@ pub fn save_module_graph(
   context: &CacheableContext,
 ) {
   for identifier in removed_modules {
-    storage.remove(SCOPE, identifier.as_bytes());
+    storage.remove(SCOPE, context.portable_string(identifier).as_bytes());
   }
 
   // save module_graph
@@ -95,7 +95,7 @@ pub fn save_module_graph(
         lazy_info,
       };
       match to_bytes(&node, context) {
-        Ok(bytes) => (identifier.as_bytes().to_vec(), bytes),
+        Ok(bytes) => (context.portable_string(identifier).into_bytes(), bytes),
         Err(err @ CacheableError::UnsupportedField) => {
           tracing::warn!("to bytes failed {:?}", err);
           // try use alternatives
@@ -107,7 +107,7 @@ pub fn save_module_graph(
             .collect();
           node.blocks = vec![];
           if let Ok(bytes) = to_bytes(&node, context) {
-            (identifier.as_bytes().to_vec(), bytes)
+            (context.portable_string(identifier).into_bytes(), bytes)
           } else {
             panic!("alternatives serialize failed")
           }
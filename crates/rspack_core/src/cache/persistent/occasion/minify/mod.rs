@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes, with::AsPreset};
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::BoxSource;
+use rustc_hash::FxHashSet as HashSet;
+
+use super::super::{Storage, cacheable_context::CacheableContext};
+use crate::{Compilation, MinifyResult};
+
+pub const SCOPE: &str = "occasion_minify";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  /// The hash of the asset content and the minimizer options.
+  key: RspackHashDigest,
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+  extracted_comments: Option<String>,
+}
+
+/// Minify Occasion is used to save MinifyCacheArtifact
+///
+/// Only the results used by the current compilation are saved,
+/// so the results of the removed or changed assets are cleaned up.
+#[derive(Debug)]
+pub struct MinifyOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+  /// The keys which has been written to storage.
+  saved_keys: HashSet<Vec<u8>>,
+}
+
+impl MinifyOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self {
+      storage,
+      context,
+      saved_keys: Default::default(),
+    }
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::Minify::save", skip_all)]
+  pub fn save(&mut self, compilation: &Compilation) {
+    let mut current_keys = HashSet::default();
+    let mut need_save = vec![];
+    for (key, result) in compilation.minify_cache_artifact.used_results() {
+      let storage_key = key.encoded().as_bytes().to_vec();
+      if self.saved_keys.contains(&storage_key) {
+        current_keys.insert(storage_key);
+        continue;
+      }
+      need_save.push((
+        storage_key,
+        Node {
+          key,
+          source: result.source,
+          extracted_comments: result.extracted_comments,
+        },
+      ));
+    }
+
+    let items = need_save
+      .into_par_iter()
+      .filter_map(|(key, node)| match to_bytes(&node, self.context.as_ref()) {
+        Ok(bytes) => Some((key, bytes)),
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+
+    for key in self.saved_keys.difference(&current_keys) {
+      self.storage.remove(SCOPE, key);
+    }
+    for (key, bytes) in items {
+      current_keys.insert(key.clone());
+      self.storage.set(SCOPE, key, bytes);
+    }
+    self.saved_keys = current_keys;
+  }
+
+  #[tracing::instrument(name = "Cache::Occasion::Minify::recovery", skip_all)]
+  pub async fn recovery(&mut self, compilation: &Compilation) -> Result<()> {
+    let artifact = &compilation.minify_cache_artifact;
+    for (key, value) in self.storage.load(SCOPE).await? {
+      let node: Node = from_bytes(&value, self.context.as_ref())
+        .expect("unexpected minify result deserialize failed");
+      artifact.restore(
+        &node.key,
+        MinifyResult {
+          source: node.source,
+          extracted_comments: node.extracted_comments,
+        },
+      );
+      self.saved_keys.insert(key.as_ref().clone());
+    }
+    Ok(())
+  }
+}
@@ -1,5 +1,13 @@
+pub mod chunk_hash;
+pub mod chunk_render;
+pub mod code_generate;
 pub mod make;
 pub mod meta;
+pub mod minify;
 
+pub use chunk_hash::ChunkHashOccasion;
+pub use chunk_render::ChunkRenderOccasion;
+pub use code_generate::CodeGenerateOccasion;
 pub use make::MakeOccasion;
 pub use meta::MetaOccasion;
+pub use minify::MinifyOccasion;
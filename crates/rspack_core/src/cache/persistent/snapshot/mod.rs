@@ -1,7 +1,7 @@
 mod option;
 mod strategy;
 
-use std::{path::Path, sync::Arc};
+use std::sync::Arc;
 
 use rspack_cacheable::{from_bytes, to_bytes};
 use rspack_error::Result;
@@ -13,7 +13,7 @@ pub use self::{
   option::{PathMatcher, SnapshotOptions},
   strategy::Strategy,
 };
-use super::storage::Storage;
+use super::{cacheable_context::CacheableContext, storage::Storage};
 use crate::FutureConsumer;
 
 pub const SCOPE: &str = "snapshot";
@@ -28,6 +28,8 @@ pub struct Snapshot {
   options: Arc<SnapshotOptions>,
   fs: Arc<dyn ReadableFileSystem>,
   storage: Arc<dyn Storage>,
+  /// Used to store paths relative to the project root
+  context: Arc<CacheableContext>,
 }
 
 impl Snapshot {
@@ -35,12 +37,14 @@ impl Snapshot {
     options: SnapshotOptions,
     fs: Arc<dyn ReadableFileSystem>,
     storage: Arc<dyn Storage>,
+    context: Arc<CacheableContext>,
   ) -> Self {
     Self {
       scope: SCOPE,
       options: Arc::new(options),
       fs,
       storage,
+      context,
     }
   }
 
@@ -49,12 +53,14 @@ impl Snapshot {
     options: SnapshotOptions,
     fs: Arc<dyn ReadableFileSystem>,
     storage: Arc<dyn Storage>,
+    context: Arc<CacheableContext>,
   ) -> Self {
     Self {
       scope,
       options: Arc::new(options),
       fs,
       storage,
+      context,
     }
   }
 
@@ -72,6 +78,17 @@ impl Snapshot {
     {
       return Some(v);
     }
+    if options.is_content_hash_path(&path_str) {
+      if options.git_index()
+        && let Some(o) = helper.git_object(path).await
+      {
+        return Some(o);
+      }
+      if let Some(h) = helper.content_hash(path).await {
+        return Some(h);
+      }
+      return Some(Strategy::Missing);
+    }
     if let Some(h) = helper.path_hash(path).await {
       return Some(h);
     }
@@ -89,7 +106,7 @@ impl Snapshot {
         async move {
           let strategy = Self::calc_strategy(&options, &helper, &path).await?;
           Some((
-            path.as_os_str().as_encoded_bytes().to_vec(),
+            self.context.portable_path(&path).into_bytes(),
             to_bytes::<_, ()>(&strategy, &()).expect("should to bytes success"),
           ))
         }
@@ -106,7 +123,7 @@ impl Snapshot {
     for item in paths {
       self
         .storage
-        .remove(self.scope, item.as_os_str().as_encoded_bytes())
+        .remove(self.scope, self.context.portable_path(&item).as_bytes())
     }
   }
 
@@ -125,7 +142,10 @@ impl Snapshot {
       .map(|(key, value)| {
         let helper = helper.clone();
         async move {
-          let path: ArcPath = Path::new(&*String::from_utf8_lossy(&key)).into();
+          let path: ArcPath = self
+            .context
+            .resolve_portable_path(&String::from_utf8_lossy(&key))
+            .into();
           let strategy: Strategy =
             from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
           let validate = helper.validate(&path, &strategy).await;
@@ -156,7 +176,10 @@ mod tests {
   use rspack_fs::{MemoryFileSystem, WritableFileSystem};
   use rspack_paths::ArcPath;
 
-  use super::{super::storage::MemoryStorage, PathMatcher, Snapshot, SnapshotOptions};
+  use super::{
+    super::{cacheable_context::CacheableContext, storage::MemoryStorage},
+    PathMatcher, Snapshot, SnapshotOptions,
+  };
 
   macro_rules! p {
     ($tt:tt) => {
@@ -172,6 +195,8 @@ mod tests {
       vec![PathMatcher::String("constant".into())],
       vec![PathMatcher::String("node_modules/project".into())],
       vec![PathMatcher::String("node_modules".into())],
+      vec![],
+      false,
     );
 
     fs.create_dir_all("/node_modules/project".into())
@@ -201,7 +226,7 @@ mod tests {
       .await
       .unwrap();
 
-    let snapshot = Snapshot::new(options, fs.clone(), storage);
+    let snapshot = Snapshot::new(options, fs.clone(), storage, Default::default());
 
     snapshot
       .add(
@@ -253,4 +278,40 @@ mod tests {
     assert!(modified_paths.contains(&p!("/node_modules/lib/file1")));
     assert_eq!(no_change_paths.len(), 1);
   }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn should_snapshot_survive_moving_project() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+
+    fs.create_dir_all("/a/project/src".into()).await.unwrap();
+    fs.create_dir_all("/b/project/src".into()).await.unwrap();
+    fs.write("/a/project/src/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/b/project/src/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let snapshot = Snapshot::new(
+      Default::default(),
+      fs.clone(),
+      storage.clone(),
+      Arc::new(CacheableContext::new(Some("/a/project".into()))),
+    );
+    snapshot.add([p!("/a/project/src/file1")].into_iter()).await;
+
+    let snapshot = Snapshot::new(
+      Default::default(),
+      fs.clone(),
+      storage,
+      Arc::new(CacheableContext::new(Some("/b/project".into()))),
+    );
+    let (is_hot_start, modified_paths, deleted_paths, no_change_paths) =
+      snapshot.calc_modified_paths().await.unwrap();
+    assert!(is_hot_start);
+    assert!(modified_paths.is_empty());
+    assert!(deleted_paths.is_empty());
+    assert!(no_change_paths.contains(&p!("/b/project/src/file1")));
+  }
 }
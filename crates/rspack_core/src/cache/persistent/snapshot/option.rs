@@ -26,6 +26,11 @@ pub struct SnapshotOptions {
   unmanaged_paths: Vec<PathMatcher>,
   /// managed_paths, snapshot will use lib version strategy
   managed_paths: Vec<PathMatcher>,
+  /// content hash paths, snapshot will only compare the content hash of them
+  /// and ignore the modified time
+  content_hash_paths: Vec<PathMatcher>,
+  /// whether to trust the object ids in git index for tracked files in content_hash_paths
+  git_index: bool,
 }
 
 impl SnapshotOptions {
@@ -33,11 +38,15 @@ impl SnapshotOptions {
     immutable_paths: Vec<PathMatcher>,
     unmanaged_paths: Vec<PathMatcher>,
     managed_paths: Vec<PathMatcher>,
+    content_hash_paths: Vec<PathMatcher>,
+    git_index: bool,
   ) -> Self {
     Self {
       immutable_paths,
       unmanaged_paths,
       managed_paths,
+      content_hash_paths,
+      git_index,
     }
   }
 
@@ -64,6 +73,17 @@ impl SnapshotOptions {
     }
     false
   }
+
+  pub fn is_content_hash_path(&self, path_str: &str) -> bool {
+    self
+      .content_hash_paths
+      .iter()
+      .any(|item| item.try_match(path_str))
+  }
+
+  pub fn git_index(&self) -> bool {
+    self.git_index
+  }
 }
 
 #[cfg(test)]
@@ -101,6 +121,8 @@ mod tests {
         PathMatcher::String("node_modules".into()),
         PathMatcher::Regexp(RspackRegex::new("test_modules/.+").unwrap()),
       ],
+      vec![PathMatcher::String("/src/".into())],
+      true,
     );
 
     assert!(options.is_immutable_path("/root/project/constant/var.js"));
@@ -116,5 +138,9 @@ mod tests {
 
     assert!(options.is_managed_path("/root/project/test_modules/var.js"));
     assert!(!options.is_managed_path("/root/project/test_modules/test1/var.js"));
+
+    assert!(options.is_content_hash_path("/root/project/src/index.js"));
+    assert!(!options.is_content_hash_path("/root/project/lib/index.js"));
+    assert!(options.git_index());
   }
 }
@@ -0,0 +1,440 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rspack_fs::{FileMetadata, ReadableFileSystem};
+use rspack_paths::{ArcPath, ArcPathDashMap, AssertUtf8};
+use rustc_hash::FxHashMap as HashMap;
+use sha1::{Digest, Sha1};
+
+/// An entry of the git index.
+#[derive(Debug)]
+struct IndexEntry {
+  mtime_ms: u64,
+  /// The file size truncated to 32 bits, as git does.
+  size: u32,
+  oid: String,
+}
+
+/// A git repository with its parsed index.
+#[derive(Debug)]
+struct GitRepo {
+  root: ArcPath,
+  /// The modified time of the index file, entries modified at or after it may be racily clean.
+  index_mtime_ms: u64,
+  /// Map from the path relative to the repository root to the index entry.
+  entries: HashMap<String, IndexEntry>,
+}
+
+/// A helper for reading git object ids of tracked files.
+#[derive(Debug)]
+pub struct GitHelper {
+  /// File system abstraction for reading the git directory.
+  fs: Arc<dyn ReadableFileSystem>,
+
+  /// Cache mapping directory paths to the git repository which contains them.
+  repo_cache: ArcPathDashMap<Option<Arc<GitRepo>>>,
+}
+
+impl GitHelper {
+  /// Creates a new GitHelper instance with the given file system.
+  pub fn new(fs: Arc<dyn ReadableFileSystem>) -> Self {
+    Self {
+      fs,
+      repo_cache: Default::default(),
+    }
+  }
+
+  /// Returns the git object id of a file tracked by git.
+  ///
+  /// The object id recorded in the git index is trusted when the file stat matches
+  /// the index entry, otherwise it is computed from the file content.
+  pub async fn tracked_object_id(&self, path: &ArcPath) -> Option<String> {
+    let metadata = self.fs.metadata(path.assert_utf8()).await.ok()?;
+    if !metadata.is_file {
+      return None;
+    }
+    let repo = self.repo(&ArcPath::from(path.parent()?)).await?;
+    let entry = repo.entries.get(&relative_path(&repo.root, path)?)?;
+    if is_clean(&repo, entry, &metadata) {
+      return Some(entry.oid.clone());
+    }
+    self.hash_file(path).await
+  }
+
+  /// Returns the git object id of a file, no matter whether it is tracked by git.
+  pub async fn object_id(&self, path: &ArcPath) -> Option<String> {
+    if let Some(oid) = self.tracked_object_id(path).await {
+      return Some(oid);
+    }
+    let metadata = self.fs.metadata(path.assert_utf8()).await.ok()?;
+    if !metadata.is_file {
+      return None;
+    }
+    self.hash_file(path).await
+  }
+
+  async fn hash_file(&self, path: &ArcPath) -> Option<String> {
+    let content = self.fs.read(path.assert_utf8()).await.ok()?;
+    Some(hash_object(&content))
+  }
+
+  /// Finds the git repository for the given directory by traversing up the directory tree.
+  #[async_recursion::async_recursion]
+  async fn repo(&self, dir: &ArcPath) -> Option<Arc<GitRepo>> {
+    if let Some(repo) = self.repo_cache.get(dir) {
+      return repo.clone();
+    }
+
+    let dot_git = dir.join(".git");
+    let res = if let Ok(metadata) = self.fs.metadata(dot_git.assert_utf8()).await {
+      // the nearest `.git` is the boundary of repository even if it can not be read
+      self.read_repo(dir, &dot_git, metadata.is_file).await
+    } else if let Some(p) = dir.parent() {
+      self.repo(&ArcPath::from(p)).await
+    } else {
+      None
+    };
+
+    self.repo_cache.insert(dir.clone(), res.clone());
+    res
+  }
+
+  async fn read_repo(
+    &self,
+    root: &ArcPath,
+    dot_git: &std::path::Path,
+    is_file: bool,
+  ) -> Option<Arc<GitRepo>> {
+    // worktrees and submodules use a `.git` file pointing to the real git directory
+    let git_dir = if is_file {
+      let content = self.fs.read(dot_git.assert_utf8()).await.ok()?;
+      let content = String::from_utf8(content).ok()?;
+      let git_dir = content.trim().strip_prefix("gitdir:")?.trim();
+      root.join(git_dir)
+    } else {
+      dot_git.to_path_buf()
+    };
+
+    // only sha1 object ids are supported
+    if let Ok(config) = self.fs.read(&git_dir.join("config").assert_utf8()).await
+      && parse_object_format(&String::from_utf8_lossy(&config))
+        .is_some_and(|format| format != "sha1")
+    {
+      return None;
+    }
+
+    let index_path = git_dir.join("index").assert_utf8();
+    let index_metadata = self.fs.metadata(&index_path).await.ok()?;
+    let content = self.fs.read(&index_path).await.ok()?;
+    Some(Arc::new(GitRepo {
+      root: root.clone(),
+      index_mtime_ms: index_metadata.mtime_ms,
+      entries: parse_index(&content)?,
+    }))
+  }
+}
+
+fn relative_path(root: &ArcPath, path: &ArcPath) -> Option<String> {
+  let components = path
+    .strip_prefix(root)
+    .ok()?
+    .components()
+    .map(|c| c.as_os_str().to_string_lossy())
+    .collect::<Vec<Cow<str>>>();
+  Some(components.join("/"))
+}
+
+/// Check whether the file is unchanged since it was added to the index, the same way as git.
+fn is_clean(repo: &GitRepo, entry: &IndexEntry, metadata: &FileMetadata) -> bool {
+  entry.size == metadata.size as u32
+    && entry.mtime_ms == metadata.mtime_ms
+    && entry.mtime_ms < repo.index_mtime_ms
+}
+
+/// Compute the object id of a blob, which is the same as `git hash-object`.
+fn hash_object(content: &[u8]) -> String {
+  let mut hasher = Sha1::new();
+  hasher.update(format!("blob {}\0", content.len()).as_bytes());
+  hasher.update(content);
+  hex::encode(hasher.finalize())
+}
+
+struct IndexReader<'a> {
+  data: &'a [u8],
+  offset: usize,
+}
+
+impl<'a> IndexReader<'a> {
+  fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+    let res = self.data.get(self.offset..self.offset.checked_add(len)?)?;
+    self.offset += len;
+    Some(res)
+  }
+
+  fn u8(&mut self) -> Option<u8> {
+    Some(self.bytes(1)?[0])
+  }
+
+  fn u16(&mut self) -> Option<u16> {
+    Some(u16::from_be_bytes(self.bytes(2)?.try_into().ok()?))
+  }
+
+  fn u32(&mut self) -> Option<u32> {
+    Some(u32::from_be_bytes(self.bytes(4)?.try_into().ok()?))
+  }
+
+  /// Read the offset encoded integer used by index version 4.
+  fn varint(&mut self) -> Option<usize> {
+    let mut byte = self.u8()?;
+    let mut value = (byte & 0x7f) as usize;
+    while byte & 0x80 != 0 {
+      if value > usize::MAX >> 8 {
+        return None;
+      }
+      byte = self.u8()?;
+      value = ((value + 1) << 7) | (byte & 0x7f) as usize;
+    }
+    Some(value)
+  }
+
+  /// Read bytes until nul, the nul byte is consumed but not returned.
+  fn until_nul(&mut self) -> Option<&'a [u8]> {
+    let len = self.data.get(self.offset..)?.iter().position(|b| *b == 0)?;
+    let res = self.bytes(len)?;
+    self.offset += 1;
+    Some(res)
+  }
+}
+
+/// Parse the `extensions.objectFormat` of git config file, the object format is sha1 if it's not set.
+///
+/// See https://git-scm.com/docs/git-config#_syntax
+fn parse_object_format(config: &str) -> Option<String> {
+  let mut in_extensions = false;
+  let mut object_format = None;
+  for line in config.lines() {
+    let mut line = line.trim();
+    // section header like `[extensions]`, the rest of line can be a variable
+    if let Some(header) = line.strip_prefix('[') {
+      let Some((name, rest)) = header.split_once(']') else {
+        continue;
+      };
+      in_extensions = name.trim().eq_ignore_ascii_case("extensions");
+      line = rest.trim();
+    }
+    if !in_extensions {
+      continue;
+    }
+    let line = line.split(['#', ';']).next().unwrap_or_default();
+    if let Some((key, value)) = line.split_once('=')
+      && key.trim().eq_ignore_ascii_case("objectformat")
+    {
+      // the last one wins if the variable is set multiple times
+      object_format = Some(value.trim().trim_matches('"').to_ascii_lowercase());
+    }
+  }
+  object_format
+}
+
+/// Parse the entries of git index file.
+///
+/// See https://git-scm.com/docs/index-format
+fn parse_index(data: &[u8]) -> Option<HashMap<String, IndexEntry>> {
+  let mut reader = IndexReader { data, offset: 0 };
+  if reader.bytes(4)? != b"DIRC" {
+    return None;
+  }
+  let version = reader.u32()?;
+  if !(2..=4).contains(&version) {
+    return None;
+  }
+  let count = reader.u32()?;
+
+  let mut entries = HashMap::default();
+  let mut prev_name: Vec<u8> = vec![];
+  for _ in 0..count {
+    let start = reader.offset;
+    // ctime
+    reader.bytes(8)?;
+    let mtime_s = reader.u32()? as u64;
+    let mtime_ns = reader.u32()? as u64;
+    // dev, ino, mode, uid, gid
+    reader.bytes(20)?;
+    let size = reader.u32()?;
+    let oid = hex::encode(reader.bytes(20)?);
+    let flags = reader.u16()?;
+    if version >= 3 && flags & 0x4000 != 0 {
+      // extended flags
+      reader.u16()?;
+    }
+
+    let name = if version == 4 {
+      // the name is prefix compressed with the previous entry
+      let strip_len = reader.varint()?;
+      let suffix = reader.until_nul()?;
+      let keep_len = prev_name.len().checked_sub(strip_len)?;
+      let mut name = prev_name[..keep_len].to_vec();
+      name.extend_from_slice(suffix);
+      name
+    } else {
+      let name = reader.until_nul()?.to_vec();
+      // entries are padded with nul bytes to a multiple of eight bytes
+      let len = reader.offset - start;
+      reader.bytes((8 - len % 8) % 8)?;
+      name
+    };
+
+    // ignore the unmerged entries
+    let stage = (flags >> 12) & 0x3;
+    if stage == 0
+      && let Ok(name_str) = std::str::from_utf8(&name)
+    {
+      entries.insert(
+        name_str.to_string(),
+        IndexEntry {
+          mtime_ms: mtime_s * 1000 + mtime_ns / 1_000_000,
+          size,
+          oid,
+        },
+      );
+    }
+    prev_name = name;
+  }
+  Some(entries)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_paths::ArcPath;
+
+  use super::{GitHelper, hash_object, parse_index, parse_object_format};
+
+  /// Build a version 2 git index with the given (name, mtime_ms, size, oid) entries.
+  fn build_index(entries: &[(&str, u64, u32, &str)]) -> Vec<u8> {
+    let mut data = b"DIRC".to_vec();
+    data.extend_from_slice(&2u32.to_be_bytes());
+    data.extend_from_slice(&(entries.len() as u32).to_be_bytes());
+    for (name, mtime_ms, size, oid) in entries {
+      let start = data.len();
+      data.extend_from_slice(&[0; 8]);
+      data.extend_from_slice(&((mtime_ms / 1000) as u32).to_be_bytes());
+      data.extend_from_slice(&(((mtime_ms % 1000) * 1_000_000) as u32).to_be_bytes());
+      data.extend_from_slice(&[0; 20]);
+      data.extend_from_slice(&size.to_be_bytes());
+      data.extend_from_slice(&hex::decode(oid).unwrap());
+      data.extend_from_slice(&(name.len() as u16).to_be_bytes());
+      data.extend_from_slice(name.as_bytes());
+      let len = data.len() - start;
+      data.extend(std::iter::repeat_n(0, 8 - len % 8));
+    }
+    data
+  }
+
+  #[test]
+  fn should_hash_object_works() {
+    // same as `echo -n abc | git hash-object --stdin`
+    assert_eq!(
+      hash_object(b"abc"),
+      "f2ba8f84ab5c1bce84a7b441cb1959cfc7093b7f"
+    );
+  }
+
+  #[test]
+  fn should_parse_index_works() {
+    let oid = hash_object(b"abc");
+    let data = build_index(&[("a.js", 1000, 3, &oid), ("src/b.js", 2500, 3, &oid)]);
+    let entries = parse_index(&data).unwrap();
+    assert_eq!(entries.len(), 2);
+    let entry = entries.get("src/b.js").unwrap();
+    assert_eq!(entry.mtime_ms, 2500);
+    assert_eq!(entry.size, 3);
+    assert_eq!(entry.oid, oid);
+
+    assert!(parse_index(b"XXXX").is_none());
+    assert!(parse_index(&data[..data.len() - 10]).is_none());
+  }
+
+  #[test]
+  fn should_parse_object_format_works() {
+    assert_eq!(parse_object_format("[core]\n\tbare = false\n"), None);
+    assert_eq!(
+      parse_object_format(
+        "[core]\n\trepositoryformatversion = 1\n[extensions]\n\tobjectformat = sha256\n"
+      )
+      .as_deref(),
+      Some("sha256")
+    );
+    assert_eq!(
+      parse_object_format("[Extensions] objectFormat = \"SHA1\" ; comment\n").as_deref(),
+      Some("sha1")
+    );
+    // not in the extensions section
+    assert_eq!(
+      parse_object_format(
+        "[remote \"origin\"]\n\turl = https://example.com/sha256.git\n[core]\n\tobjectformat = sha256\n"
+      ),
+      None
+    );
+  }
+
+  #[tokio::test]
+  async fn tracked_object_id() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/project/.git".into()).await.unwrap();
+    fs.create_dir_all("/project/src".into()).await.unwrap();
+    fs.write("/project/src/a.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/project/src/b.js".into(), "b".as_bytes())
+      .await
+      .unwrap();
+    let mtime = fs.stat("/project/src/a.js".into()).await.unwrap().mtime_ms;
+
+    // the index stores a fake oid to check that it is trusted
+    let fake_oid = "0123456789012345678901234567890123456789";
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write(
+      "/project/.git/index".into(),
+      &build_index(&[("src/a.js", mtime, 3, fake_oid)]),
+    )
+    .await
+    .unwrap();
+
+    let helper = GitHelper::new(fs.clone());
+    assert_eq!(
+      helper
+        .tracked_object_id(&ArcPath::from("/project/src/a.js"))
+        .await,
+      Some(fake_oid.to_string())
+    );
+    assert_eq!(
+      helper
+        .tracked_object_id(&ArcPath::from("/project/src/b.js"))
+        .await,
+      None
+    );
+    assert_eq!(
+      helper.object_id(&ArcPath::from("/project/src/b.js")).await,
+      Some(hash_object(b"b"))
+    );
+
+    // the file stat no longer matches the index entry
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/project/src/a.js".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    let helper = GitHelper::new(fs.clone());
+    assert_eq!(
+      helper
+        .tracked_object_id(&ArcPath::from("/project/src/a.js"))
+        .await,
+      Some(hash_object(b"abcd"))
+    );
+    assert_eq!(
+      helper.object_id(&ArcPath::from("/project/src/c.js")).await,
+      None
+    );
+  }
+}
@@ -1,3 +1,4 @@
+mod git_helper;
 mod hash_helper;
 mod package_helper;
 
@@ -8,6 +9,7 @@ use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, AssertUtf8};
 
 use self::{
+  git_helper::GitHelper,
   hash_helper::{ContentHash, HashHelper},
   package_helper::PackageHelper,
 };
@@ -28,6 +30,19 @@ pub enum Strategy {
   /// and then compare the file hash if the file has been updated.
   PathHash { mtime: u64, hash: u64 },
 
+  /// Check by content hash
+  ///
+  /// This strategy ignores the modified time and always compares the file hash,
+  /// so it still works after the timestamps are reset, e.g. by `git clone`.
+  ContentHash(u64),
+
+  /// Check by git object id
+  ///
+  /// This strategy compares the git object id of a tracked file. The object id in git
+  /// index is trusted when the file stat matches the index entry, otherwise the object
+  /// id is computed from the file content.
+  GitObject(String),
+
   /// Check missing file
   ///
   /// This strategy indicates that the current file is in a missing state,
@@ -40,6 +55,8 @@ impl PartialEq for Strategy {
     match (self, other) {
       (Self::PackageVersion(v1), Self::PackageVersion(v2)) => v1 == v2,
       (Self::PathHash { hash: h1, .. }, Self::PathHash { hash: h2, .. }) => h1 == h2,
+      (Self::ContentHash(h1), Self::ContentHash(h2)) => h1 == h2,
+      (Self::GitObject(o1), Self::GitObject(o2)) => o1 == o2,
       (Self::Missing, Self::Missing) => true,
       _ => false,
     }
@@ -61,6 +78,7 @@ pub struct StrategyHelper {
   fs: Arc<dyn ReadableFileSystem>,
   package_helper: PackageHelper,
   hash_helper: HashHelper,
+  git_helper: GitHelper,
 }
 
 impl StrategyHelper {
@@ -68,7 +86,8 @@ impl StrategyHelper {
     Self {
       fs: fs.clone(),
       package_helper: PackageHelper::new(fs.clone()),
-      hash_helper: HashHelper::new(fs),
+      hash_helper: HashHelper::new(fs.clone()),
+      git_helper: GitHelper::new(fs),
     }
   }
 
@@ -101,6 +120,21 @@ impl StrategyHelper {
     Some(Strategy::PathHash { mtime, hash })
   }
 
+  /// get path content hash strategy
+  pub async fn content_hash(&self, path: &ArcPath) -> Option<Strategy> {
+    let ContentHash { hash, .. } = self.hash_helper.content_hash(path).await?;
+    Some(Strategy::ContentHash(hash))
+  }
+
+  /// get path git object strategy, only files tracked by git have it
+  pub async fn git_object(&self, path: &ArcPath) -> Option<Strategy> {
+    self
+      .git_helper
+      .tracked_object_id(path)
+      .await
+      .map(Strategy::GitObject)
+  }
+
   /// validate path file by target strategy
   pub async fn validate(&self, path: &ArcPath, strategy: &Strategy) -> ValidateResult {
     match strategy {
@@ -131,6 +165,27 @@ impl StrategyHelper {
           ValidateResult::Modified
         }
       }
+      Strategy::ContentHash(hash) => {
+        let Some(ContentHash { hash: cur_hash, .. }) = self.hash_helper.content_hash(path).await
+        else {
+          return ValidateResult::Deleted;
+        };
+        if &cur_hash == hash {
+          ValidateResult::NoChanged
+        } else {
+          ValidateResult::Modified
+        }
+      }
+      Strategy::GitObject(oid) => {
+        let Some(ref cur_oid) = self.git_helper.object_id(path).await else {
+          return ValidateResult::Deleted;
+        };
+        if cur_oid == oid {
+          ValidateResult::NoChanged
+        } else {
+          ValidateResult::Modified
+        }
+      }
       Strategy::Missing => {
         if self.modified_time(path).await.is_some() {
           ValidateResult::Modified
@@ -253,6 +308,101 @@ mod tests {
     ));
   }
 
+  #[tokio::test]
+  async fn validate_content_hash() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let helper = StrategyHelper::new(fs.clone());
+    let strategy = helper
+      .content_hash(&ArcPath::from("/file1.js"))
+      .await
+      .unwrap();
+
+    // a new mtime with the same content, e.g. after `git clone`
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let helper = StrategyHelper::new(fs.clone());
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::NoChanged
+    ));
+
+    let helper = StrategyHelper::new(fs.clone());
+    fs.write("/file1.js".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Modified
+    ));
+
+    let helper = StrategyHelper::new(fs.clone());
+    fs.remove_file("/file1.js".into()).await.unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Deleted
+    ));
+  }
+
+  #[tokio::test]
+  async fn validate_git_object() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    // untracked files have no git object strategy
+    let helper = StrategyHelper::new(fs.clone());
+    assert!(
+      helper
+        .git_object(&ArcPath::from("/file1.js"))
+        .await
+        .is_none()
+    );
+
+    // same as `echo -n abc | git hash-object --stdin`
+    let strategy = Strategy::GitObject("f2ba8f84ab5c1bce84a7b441cb1959cfc7093b7f".into());
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::NoChanged
+    ));
+
+    let helper = StrategyHelper::new(fs.clone());
+    fs.write("/file1.js".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Modified
+    ));
+
+    let helper = StrategyHelper::new(fs.clone());
+    fs.remove_file("/file1.js".into()).await.unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Deleted
+    ));
+  }
+
   #[tokio::test]
   async fn validate_missing() {
     let fs = Arc::new(MemoryFileSystem::default());
@@ -1,6 +1,6 @@
 use std::sync::{Arc, Mutex};
 
-use rspack_storage::{Result, Storage};
+use rspack_storage::{Result, SaveReport, Storage};
 use rustc_hash::FxHashMap as HashMap;
 use tokio::sync::oneshot::{Receiver, channel};
 
@@ -37,9 +37,9 @@ impl Storage for MemoryStorage {
     let mut map = self.inner.lock().expect("should get lock");
     map.get_mut(scope).map(|map| map.remove(key));
   }
-  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
-    let (rs, rx) = channel::<Result<()>>();
-    let _ = rs.send(Ok(()));
+  fn trigger_save(&self) -> Result<Receiver<Result<SaveReport>>> {
+    let (rs, rx) = channel::<Result<SaveReport>>();
+    let _ = rs.send(Ok(SaveReport::default()));
     Ok(rx)
   }
   async fn reset(&self) {
@@ -5,8 +5,8 @@ use std::{path::PathBuf, sync::Arc};
 
 pub use memory::MemoryStorage;
 use rspack_fs::IntermediateFileSystem;
-pub use rspack_storage::Storage;
 use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+pub use rspack_storage::{PackCompression, Storage};
 
 /// Storage Options
 ///
@@ -14,7 +14,47 @@ use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
 /// Since MemoryStorage is only used in unit test, there is no need to add it here.
 #[derive(Debug, Clone, Hash)]
 pub enum StorageOptions {
-  FileSystem { directory: PathBuf },
+  FileSystem {
+    directory: PathBuf,
+    /// The maximum size in bytes of the cache directory,
+    /// the least recently used cache versions will be evicted when exceeded.
+    max_size: Option<usize>,
+    /// The codec used to compress pack files.
+    compression: PackCompression,
+  },
+  /// Local file system storage which is shared with other machines through a remote blob server.
+  ///
+  /// The cache version is used as the remote namespace.
+  Remote {
+    directory: PathBuf,
+    url: String,
+    max_size: Option<usize>,
+    compression: PackCompression,
+  },
+}
+
+/// Create the options of local pack storage which is used by all storage types.
+pub fn create_pack_options(
+  directory: PathBuf,
+  version: String,
+  max_size: Option<usize>,
+  compression: PackCompression,
+  fs: Arc<dyn IntermediateFileSystem>,
+) -> PackStorageOptions {
+  PackStorageOptions {
+    temp_root: directory.join(".temp"),
+    root: directory,
+    clean: true,
+    bucket_size: 20,
+    pack_size: 500 * 1024,
+    compression,
+    expire: 7 * 24 * 60 * 60 * 1000,
+    max_size,
+    fs: Arc::new(BridgeFileSystem(fs)),
+    fresh_generation: Some(1),
+    release_generation: Some(2),
+    version,
+  }
 }
 
 pub fn create_storage(
@@ -23,20 +63,43 @@ pub fn create_storage(
   fs: Arc<dyn IntermediateFileSystem>,
 ) -> Arc<dyn Storage> {
   match options {
-    StorageOptions::FileSystem { directory } => {
-      let option = PackStorageOptions {
-        temp_root: directory.join(".temp"),
-        root: directory,
-        clean: true,
-        bucket_size: 20,
-        pack_size: 500 * 1024,
-        expire: 7 * 24 * 60 * 60 * 1000,
-        fs: Arc::new(BridgeFileSystem(fs)),
-        fresh_generation: Some(1),
-        release_generation: Some(2),
-        version,
-      };
-      Arc::new(PackStorage::new(option))
+    StorageOptions::FileSystem {
+      directory,
+      max_size,
+      compression,
+    } => Arc::new(PackStorage::new(create_pack_options(
+      directory,
+      version,
+      max_size,
+      compression,
+      fs,
+    ))),
+    #[cfg(not(target_family = "wasm"))]
+    StorageOptions::Remote {
+      directory,
+      url,
+      max_size,
+      compression,
+    } => {
+      use rspack_storage::{HttpRemoteClient, RemoteStorage, RemoteStorageOptions};
+      Arc::new(RemoteStorage::new(RemoteStorageOptions {
+        local: create_pack_options(directory, version, max_size, compression, fs),
+        client: Arc::new(HttpRemoteClient::new(url)),
+      }))
     }
+    // there is no network access in wasm, only the local tier is available
+    #[cfg(target_family = "wasm")]
+    StorageOptions::Remote {
+      directory,
+      max_size,
+      compression,
+      ..
+    } => Arc::new(PackStorage::new(create_pack_options(
+      directory,
+      version,
+      max_size,
+      compression,
+      fs,
+    ))),
   }
 }
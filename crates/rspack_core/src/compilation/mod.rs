@@ -48,12 +48,12 @@ use crate::{
   CompilationLogger, CompilationLogging, CompilerOptions, CompilerPlatform, ConcatenationScope,
   DependenciesDiagnosticsArtifact, DependencyCodeGeneration, DependencyTemplate,
   DependencyTemplateType, DependencyType, DerefOption, Entry, EntryData, EntryOptions,
-  EntryRuntime, Entrypoint, ExecuteModuleId, Filename, ImportPhase, ImportVarMap,
-  ImportedByDeferModulesArtifact, Logger, MemoryGCStorage, ModuleFactory, ModuleGraph,
-  ModuleGraphCacheArtifact, ModuleIdentifier, ModuleIdsArtifact, ModuleStaticCacheArtifact,
-  PathData, ResolverFactory, RuntimeGlobals, RuntimeKeyMap, RuntimeMode, RuntimeModule,
-  RuntimeSpec, RuntimeSpecMap, RuntimeTemplate, SharedPluginDriver, SideEffectsOptimizeArtifact,
-  SourceType, Stats, ValueCacheVersions,
+  EntryRuntime, Entrypoint, ExecuteModuleId, ExperimentCacheOptions, Filename, ImportPhase,
+  ImportVarMap, ImportedByDeferModulesArtifact, Logger, MemoryGCStorage, MinifyCacheArtifact,
+  ModuleFactory, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier, ModuleIdsArtifact,
+  ModuleStaticCacheArtifact, PathData, ResolverFactory, RuntimeGlobals, RuntimeKeyMap, RuntimeMode,
+  RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate, SharedPluginDriver,
+  SideEffectsOptimizeArtifact, SourceType, Stats, ValueCacheVersions,
   build_chunk_graph::artifact::BuildChunkGraphArtifact,
   compilation::build_module_graph::{
     BuildModuleGraphArtifact, ModuleExecutor, UpdateParam, build_module_graph,
@@ -273,6 +273,8 @@ pub struct Compilation {
   pub module_static_cache_artifact: ModuleStaticCacheArtifact,
   // artifact for chunk render cache
   pub chunk_render_cache_artifact: ChunkRenderCacheArtifact,
+  // artifact for minify cache
+  pub minify_cache_artifact: MinifyCacheArtifact,
   pub imported_by_defer_modules_artifact: ImportedByDeferModulesArtifact,
 
   pub code_generated_modules: IdentifierSet,
@@ -404,11 +406,17 @@ impl Compilation {
       module_static_cache_artifact: Default::default(),
       code_generated_modules: Default::default(),
       chunk_render_cache_artifact: ChunkRenderCacheArtifact::new(MemoryGCStorage::new(
-        match &options.cache {
+        match options.memory_cache_options() {
           CacheOptions::Memory { max_generations } => max_generations.unwrap_or(1),
           CacheOptions::Disabled => 0, // FIXME: this should be removed in future
         },
       )),
+      minify_cache_artifact: MinifyCacheArtifact::new(MemoryGCStorage::new(
+        match options.memory_cache_options() {
+          CacheOptions::Memory { max_generations } => max_generations.unwrap_or(1),
+          CacheOptions::Disabled => 0,
+        },
+      )),
       build_time_executed_modules: Default::default(),
       old_cache,
       incremental,
@@ -1063,7 +1071,7 @@ impl Compilation {
   #[instrument("Compilation:code_generation",target=TRACING_BENCH_TARGET, skip_all)]
   async fn code_generation(&mut self, modules: IdentifierSet) -> Result<()> {
     let logger = self.get_logger("rspack.Compilation");
-    let mut codegen_cache_counter = match self.options.cache {
+    let mut codegen_cache_counter = match self.options.memory_cache_options() {
       CacheOptions::Disabled => None,
       _ => Some(logger.cache("module code generation cache")),
     };
@@ -2442,6 +2450,9 @@ impl Compilation {
       for hash_result in chunk_hash_results {
         let (chunk_ukey, chunk_hash_result) = hash_result?;
         let chunk = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+        compilation
+          .chunk_hashes_artifact
+          .set_input_hash(chunk_ukey, chunk_hash_result.input_hash);
         let chunk_hashes_changed = chunk.set_hashes(
           &mut compilation.chunk_hashes_artifact,
           chunk_hash_result.hash,
@@ -2495,7 +2506,7 @@ impl Compilation {
         let s = unsafe { token.used((&self, chunk, &plugin_driver)) };
         s.spawn(|(compilation, chunk, plugin_driver)| async {
           let hash_result = compilation
-            .process_chunk_hash(*chunk, plugin_driver)
+            .process_chunk_hash(*chunk, plugin_driver, true)
             .await?;
           Ok((*chunk, hash_result))
         });
@@ -2506,6 +2517,27 @@ impl Compilation {
     .map(|res| res.to_rspack_result())
     .collect::<Result<Vec<_>>>()?;
 
+    if !self.is_rebuild
+      && matches!(
+        self.options.experiments.cache,
+        ExperimentCacheOptions::Persistent(_)
+      )
+    {
+      let mut chunk_hash_cache_counter = logger.cache("chunk hash cache");
+      for (_, result) in other_chunks_hash_results.iter().flatten() {
+        if result.input_hash.as_ref().is_some_and(|input_hash| {
+          self
+            .chunk_hashes_artifact
+            .get_restored(input_hash)
+            .is_some()
+        }) {
+          chunk_hash_cache_counter.hit();
+        } else {
+          chunk_hash_cache_counter.miss();
+        }
+      }
+      logger.cache_end(chunk_hash_cache_counter);
+    }
     try_process_chunk_hash_results(self, other_chunks_hash_results)?;
     logger.time_end(start);
 
@@ -2640,7 +2672,7 @@ impl Compilation {
       }
 
       let chunk_hash_result = self
-        .process_chunk_hash(runtime_chunk_ukey, &plugin_driver)
+        .process_chunk_hash(runtime_chunk_ukey, &plugin_driver, false)
         .await?;
       let chunk = self.chunk_by_ukey.expect_get(&runtime_chunk_ukey);
       let chunk_hashes_changed = chunk.set_hashes(
@@ -2762,15 +2794,38 @@ impl Compilation {
     Ok(())
   }
 
+  /// `restorable` means the hashes of the chunk don't depend on the hashes of other chunks,
+  /// so they can be keyed by the chunk inputs and restored from persistent cache.
   async fn process_chunk_hash(
     &self,
     chunk_ukey: ChunkUkey,
     plugin_driver: &SharedPluginDriver,
+    restorable: bool,
   ) -> Result<ChunkHashResult> {
     let mut hasher = RspackHash::from(&self.options.output);
-    if let Some(chunk) = self.chunk_by_ukey.get(&chunk_ukey) {
+    let chunk = self.chunk_by_ukey.get(&chunk_ukey);
+    if let Some(chunk) = chunk {
       chunk.update_hash(&mut hasher, self);
     }
+    let input_hash = restorable.then(|| {
+      let mut input_hasher = hasher.clone();
+      if let Some(chunk) = chunk {
+        self.update_chunk_hash_hooks_input(&chunk_ukey, chunk.runtime(), &mut input_hasher);
+      }
+      input_hasher.digest(&self.options.output.hash_digest)
+    });
+    // the restored hashes are only used by the first compilation,
+    // rebuilds rely on the incremental chunk hashes.
+    if !self.is_rebuild
+      && let Some(input_hash) = &input_hash
+      && let Some(restored) = self.chunk_hashes_artifact.get_restored(input_hash)
+    {
+      return Ok(ChunkHashResult {
+        hash: restored.hash().clone(),
+        content_hash: restored.content_hash().clone(),
+        input_hash: Some(input_hash.clone()),
+      });
+    }
     plugin_driver
       .compilation_hooks
       .chunk_hash
@@ -2796,9 +2851,39 @@ impl Compilation {
     Ok(ChunkHashResult {
       hash: chunk_hash,
       content_hash: content_hashes,
+      input_hash,
     })
   }
 
+  /// Hashes the inputs of the `chunk_hash` and `content_hash` hooks that are not covered by
+  /// `Chunk::update_hash`, so a restored chunk hash is never reused for changed modules.
+  fn update_chunk_hash_hooks_input(
+    &self,
+    chunk_ukey: &ChunkUkey,
+    runtime: &RuntimeSpec,
+    hasher: &mut RspackHash,
+  ) {
+    for module_identifier in self
+      .chunk_graph
+      .get_ordered_chunk_modules_identifier(chunk_ukey)
+    {
+      module_identifier.hash(hasher);
+      ChunkGraph::get_module_id(&self.module_ids_artifact, module_identifier).hash(hasher);
+      ChunkGraph::get_module_hash(self, module_identifier, runtime).hash(hasher);
+    }
+    for runtime_module_identifier in self
+      .chunk_graph
+      .get_chunk_runtime_modules_iterable(chunk_ukey)
+      .sorted_unstable()
+    {
+      runtime_module_identifier.hash(hasher);
+      self
+        .runtime_modules_hash
+        .get(runtime_module_identifier)
+        .hash(hasher);
+    }
+  }
+
   #[instrument("Compilation:create_module_hashes", skip_all)]
   pub async fn create_module_hashes(&mut self, modules: IdentifierSet) -> Result<()> {
     let mg = self.get_module_graph();
@@ -3209,6 +3294,8 @@ pub struct RenderManifestEntry {
 pub struct ChunkHashResult {
   pub hash: RspackHashDigest,
   pub content_hash: ChunkContentHash,
+  /// The digest of the chunk inputs, which keys the hashes in persistent cache.
+  pub input_hash: Option<RspackHashDigest>,
 }
 
 #[cacheable]
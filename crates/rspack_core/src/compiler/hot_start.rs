@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use rspack_error::Result;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{
+  Compilation, Compiler,
+  cache::{Cache, DisableCache},
+  compilation::build_module_graph::ModuleExecutor,
+  fast_set,
+  incremental::{HotStartMismatch, Incremental, IncrementalOptions},
+  old_cache::Cache as OldCache,
+};
+
+impl Compiler {
+  /// Runs a full build on a side compilation without the persistent cache and incremental,
+  /// and returns the content of its assets as the expected result of the hot start.
+  ///
+  /// The side compilation has its own memory caches, so it neither reads the results restored
+  /// from the persistent cache nor leaves its results to the hot start compilation. The states
+  /// of plugins and resolvers for it are cleared after the full build.
+  ///
+  /// The hot start compilation with the restored artifacts is put back after the full build,
+  /// so the compilation hooks are called with it last.
+  pub(super) async fn build_for_hot_start_check(&mut self) -> Result<HashMap<String, Vec<u8>>> {
+    let side_compilation = Compilation::new(
+      self.id,
+      self.options.clone(),
+      self.platform.clone(),
+      self.plugin_driver.clone(),
+      self.buildtime_plugin_driver.clone(),
+      self.resolver_factory.clone(),
+      self.loader_resolver_factory.clone(),
+      None,
+      Arc::new(OldCache::new(self.options.clone())),
+      Incremental::new_cold(IncrementalOptions::empty_passes()),
+      Some(ModuleExecutor::default()),
+      Default::default(),
+      Default::default(),
+      self.input_filesystem.clone(),
+      self.intermediate_filesystem.clone(),
+      self.output_filesystem.clone(),
+      false,
+      self.compiler_context.clone(),
+    );
+    let hot_compilation = std::mem::replace(&mut self.compilation, side_compilation);
+    let cache = std::mem::replace(&mut self.cache, Box::new(DisableCache) as Box<dyn Cache>);
+
+    let res = self.compile().await;
+    self.cache = cache;
+    let assets = res.map(|_| {
+      self
+        .compilation
+        .assets()
+        .iter()
+        .filter_map(|(name, asset)| {
+          asset
+            .get_source()
+            .map(|source| (name.clone(), source.buffer().to_vec()))
+        })
+        .collect()
+    });
+    self.plugin_driver.clear_cache(self.compilation.id());
+    fast_set(&mut self.compilation, hot_compilation);
+    assets
+  }
+
+  /// Compares the assets of the hot start compilation with the full build,
+  /// and reports the differences as warnings.
+  pub(super) fn check_hot_start(&mut self, mut expected: HashMap<String, Vec<u8>>) {
+    let mut mismatches = vec![];
+    for (name, asset) in self.compilation.assets() {
+      let Some(source) = asset.get_source() else {
+        continue;
+      };
+      match expected.remove(name) {
+        Some(content) if content == source.buffer().as_ref() => {}
+        Some(_) => mismatches.push(HotStartMismatch {
+          asset: name.clone(),
+          reason: "differs from",
+        }),
+        None => mismatches.push(HotStartMismatch {
+          asset: name.clone(),
+          reason: "is not emitted by",
+        }),
+      }
+    }
+    mismatches.extend(expected.into_keys().map(|name| HotStartMismatch {
+      asset: name,
+      reason: "is missing, but emitted by",
+    }));
+
+    mismatches.sort_by(|a, b| a.asset.cmp(&b.asset));
+    for mismatch in mismatches {
+      self
+        .compilation
+        .push_diagnostic(rspack_error::Error::from(mismatch).into());
+    }
+  }
+}
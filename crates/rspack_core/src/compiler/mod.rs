@@ -1,9 +1,14 @@
+mod hot_start;
 mod rebuild;
+mod virtual_files;
 use std::sync::{Arc, atomic::AtomicU32};
 
 use futures::future::join_all;
 use rspack_error::Result;
-use rspack_fs::{IntermediateFileSystem, NativeFileSystem, ReadableFileSystem, WritableFileSystem};
+use rspack_fs::{
+  IntermediateFileSystem, NativeFileSystem, OverlayFileSystem, ReadableFileSystem,
+  WritableFileSystem,
+};
 use rspack_hook::define_hook;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rspack_sources::BoxSource;
@@ -82,6 +87,8 @@ pub struct Compiler {
   pub output_filesystem: Arc<dyn WritableFileSystem>,
   pub intermediate_filesystem: Arc<dyn IntermediateFileSystem>,
   pub input_filesystem: Arc<dyn ReadableFileSystem>,
+  /// The overlay of `input_filesystem` which holds the virtual files, see [`Compiler::write_virtual_file`].
+  pub overlay_filesystem: Option<OverlayFileSystem>,
   pub compilation: Compilation,
   pub plugin_driver: SharedPluginDriver,
   pub buildtime_plugin_driver: SharedPluginDriver,
@@ -191,6 +198,7 @@ impl Compiler {
       old_cache,
       emitted_asset_versions: Default::default(),
       input_filesystem,
+      overlay_filesystem: None,
       platform,
       compiler_context,
     }
@@ -217,6 +225,10 @@ impl Compiler {
     let plugin_driver_clone = self.plugin_driver.clone();
     let compilation_id = self.compilation.id();
     let _guard = scopeguard::guard((), move |_| plugin_driver_clone.clear_cache(compilation_id));
+    // a full build reads all the virtual files, so the pending changes of them can be dropped
+    if let Some(overlay_filesystem) = &self.overlay_filesystem {
+      overlay_filesystem.take_changes();
+    }
 
     fast_set(
       &mut self.compilation,
@@ -241,14 +253,31 @@ impl Compiler {
         self.compiler_context.clone(),
       ),
     );
-    let _is_hot = self.cache.before_compile(&mut self.compilation).await;
-    // TODO: disable it for now, enable it once persistent cache is added to all artifacts
-    // if is_hot {
-    //   // If it's a hot start, we can use incremental
-    //   self.compilation.incremental = Incremental::new_hot(self.options.experiments.incremental);
-    // }
+    let is_hot = self.cache.before_compile(&mut self.compilation).await;
+    let mut hot_start_check = None;
+    if is_hot {
+      // If it's a hot start, we can use incremental
+      self.compilation.incremental = Incremental::new_hot(self.options.experiments.incremental);
+      // The module graph is restored with fresh exports info, other artifacts are empty and
+      // will be fully recomputed by their passes, only provided exports can not be recovered
+      // from the mutations of make.
+      if let Some(diagnostic) = self.compilation.incremental.disable_passes(
+        IncrementalPasses::PROVIDED_EXPORTS,
+        "Persistent cache hot start",
+        "the exports info of modules are not persisted",
+      ) && let Some(diagnostic) = diagnostic
+      {
+        self.compilation.push_diagnostic(diagnostic);
+      }
+      if self.options.experiments.incremental.check {
+        hot_start_check = Some(self.build_for_hot_start_check().await?);
+      }
+    }
 
     self.compile().await?;
+    if let Some(expected) = hot_start_check {
+      self.check_hot_start(expected);
+    }
     self.old_cache.begin_idle();
     self.compile_done().await?;
     self.cache.after_compile(&self.compilation).await;
@@ -35,9 +35,10 @@ impl Compiler {
   ))]
   async fn rebuild_inner(
     &mut self,
-    changed_files: std::collections::HashSet<String>,
-    deleted_files: std::collections::HashSet<String>,
+    mut changed_files: std::collections::HashSet<String>,
+    mut deleted_files: std::collections::HashSet<String>,
   ) -> Result<()> {
+    self.merge_virtual_file_changes(&mut changed_files, &mut deleted_files);
     let records = CompilationRecords::record(&self.compilation);
 
     // build without stats
@@ -176,6 +177,11 @@ impl Compiler {
       new_compilation
         .chunk_render_cache_artifact
         .start_next_generation();
+      new_compilation.minify_cache_artifact =
+        std::mem::take(&mut self.compilation.minify_cache_artifact);
+      new_compilation
+        .minify_cache_artifact
+        .start_next_generation();
 
       // FOR BINDING SAFETY:
       // Update `compilation` for each rebuild.
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use rspack_error::Result;
+use rspack_fs::OverlayFileSystem;
+use rspack_paths::Utf8Path;
+
+use crate::Compiler;
+
+impl Compiler {
+  fn overlay_filesystem(&self) -> Result<&OverlayFileSystem> {
+    self.overlay_filesystem.as_ref().ok_or_else(|| {
+      rspack_error::error!(
+        "Virtual files require an `OverlayFileSystem` as the input file system of the compiler"
+      )
+    })
+  }
+
+  /// Add or update a virtual file, the change is picked up by the next rebuild.
+  pub fn write_virtual_file(&self, path: &Utf8Path, content: Vec<u8>) -> Result<()> {
+    self.overlay_filesystem()?.write_file(path, content);
+    Ok(())
+  }
+
+  /// Remove a virtual file, the removal is picked up by the next rebuild.
+  ///
+  /// Returns false if the virtual file does not exist.
+  pub fn remove_virtual_file(&self, path: &Utf8Path) -> Result<bool> {
+    Ok(self.overlay_filesystem()?.remove_file(path))
+  }
+
+  /// Merges the virtual files changed since the last build into the changed and removed files of the watcher.
+  pub(super) fn merge_virtual_file_changes(
+    &self,
+    changed_files: &mut HashSet<String>,
+    deleted_files: &mut HashSet<String>,
+  ) {
+    let Some(overlay_filesystem) = &self.overlay_filesystem else {
+      return;
+    };
+    let changes = overlay_filesystem.take_changes();
+    for path in changes.changed {
+      let path = path.into_string();
+      deleted_files.remove(&path);
+      changed_files.insert(path);
+    }
+    for path in changes.removed {
+      let path = path.into_string();
+      changed_files.remove(&path);
+      deleted_files.insert(path);
+    }
+  }
+}
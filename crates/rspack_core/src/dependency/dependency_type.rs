@@ -93,6 +93,10 @@ pub enum DependencyType {
   WasmImport,
   /// wasm export import
   WasmExportImported,
+  /// import source from wasm, or import.source() of wasm
+  WasmSourcePhaseImport,
+  /// typed exports of wasm
+  WasmExports,
   /// static exports
   StaticExports,
   /// container exposed
@@ -177,6 +181,8 @@ impl DependencyType {
       DependencyType::RequireEnsureItem => "require.ensure item",
       DependencyType::WasmImport => "wasm import",
       DependencyType::WasmExportImported => "wasm export imported",
+      DependencyType::WasmSourcePhaseImport => "wasm source phase import",
+      DependencyType::WasmExports => "wasm exports",
       DependencyType::StaticExports => "static exports",
       DependencyType::LoaderImport => "loader import",
       DependencyType::ExportInfoApi => "export info api",
@@ -88,6 +88,9 @@ impl IncrementalPasses {
 pub struct IncrementalOptions {
   pub silent: bool,
   pub passes: IncrementalPasses,
+  /// Compare the result of a persistent cache hot start with a full rebuild,
+  /// the differences are reported as warnings.
+  pub check: bool,
 }
 
 impl IncrementalOptions {
@@ -95,6 +98,7 @@ impl IncrementalOptions {
     Self {
       silent: true,
       passes: IncrementalPasses::empty(),
+      check: false,
     }
   }
 }
@@ -241,6 +245,23 @@ impl Incremental {
   }
 }
 
+#[derive(Debug)]
+pub struct HotStartMismatch {
+  pub asset: String,
+  pub reason: &'static str,
+}
+
+impl From<HotStartMismatch> for rspack_error::Error {
+  fn from(value: HotStartMismatch) -> rspack_error::Error {
+    let mut error = rspack_error::Error::warning(format!(
+      "Asset `{}` {} a full rebuild after the persistent cache hot start. This is likely a bug of incremental, please report it to Rspack.",
+      value.asset, value.reason
+    ));
+    error.code = Some("HotStartMismatch".into());
+    error
+  }
+}
+
 #[derive(Debug)]
 pub struct NotFriendlyForIncremental {
   pub thing: &'static str,
@@ -185,6 +185,9 @@ impl From<&ModuleType> for SourceType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
+  Json5,
+  Yaml,
+  Toml,
   Css,
   CssModule,
   CssAuto,
@@ -222,6 +225,14 @@ impl ModuleType {
     )
   }
 
+  /// The data modules which are lowered into json, and share the json parser and generator options.
+  pub fn is_json_like(&self) -> bool {
+    matches!(
+      self,
+      ModuleType::Json | ModuleType::Json5 | ModuleType::Yaml | ModuleType::Toml
+    )
+  }
+
   pub fn is_wasm_like(&self) -> bool {
     matches!(self, ModuleType::WasmSync | ModuleType::WasmAsync)
   }
@@ -254,6 +265,9 @@ impl ModuleType {
       ModuleType::CssAuto => "css/auto",
 
       ModuleType::Json => "json",
+      ModuleType::Json5 => "json5",
+      ModuleType::Yaml => "yaml",
+      ModuleType::Toml => "toml",
 
       ModuleType::WasmSync => "webassembly/sync",
       ModuleType::WasmAsync => "webassembly/async",
@@ -295,6 +309,9 @@ impl From<&str> for ModuleType {
       "css/auto" => Self::CssAuto,
 
       "json" => Self::Json,
+      "json5" => Self::Json5,
+      "yaml" => Self::Yaml,
+      "toml" => Self::Toml,
 
       "webassembly/sync" => Self::WasmSync,
       "webassembly/async" => Self::WasmAsync,
@@ -12,7 +12,8 @@ use derive_more::Debug;
 use futures::future::BoxFuture;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsMap, AsOption, AsPreset},
+  utils::PortableString,
+  with::{As, AsMap, AsOption, AsPreset},
 };
 use rspack_collections::{Identifiable, IdentifierMap, IdentifierSet};
 use rspack_error::{Diagnosable, Diagnostic, Result, error};
@@ -104,10 +105,13 @@ pub struct NormalModuleInner {
   /// Context of this module
   context: Box<Context>,
   /// Request with loaders from config
+  #[cacheable(with=As<PortableString>)]
   request: String,
   /// Request intended by user (without loaders from config)
+  #[cacheable(with=As<PortableString>)]
   user_request: String,
   /// Request without resolving
+  #[cacheable(with=As<PortableString>)]
   raw_request: String,
   /// The resolved module type of a module
   module_type: ModuleType,
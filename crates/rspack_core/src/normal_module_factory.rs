@@ -498,6 +498,17 @@ module.exports = "data:,";
 
     let resolved_module_type =
       self.calculate_module_type(match_module_type, &resolved_module_rules);
+    if let Some(attribute_type) = data.dependencies[0]
+      .get_attributes()
+      .and_then(|attributes| attributes.get("type"))
+      && is_module_type_compatible_with_import_attribute(&resolved_module_type, attribute_type)
+        == Some(false)
+    {
+      return Err(error!(
+        "Import attribute `type: \"{attribute_type}\"` conflicts with the module type '{}' of '{}' resolved from module rules, remove the `type` of the matched rules or the import attribute.",
+        resolved_module_type, data.request
+      ));
+    }
     let resolved_module_layer =
       self.calculate_module_layer(data.issuer_layer.as_ref(), &resolved_module_rules);
 
@@ -715,6 +726,18 @@ module.exports = "data:,";
             _ => unreachable!(),
           },
         ),
+        ModuleType::Json5 | ModuleType::Yaml | ModuleType::Toml => {
+          rspack_util::merge_from_optional_with(
+            p.get("json").cloned(),
+            options,
+            |json_options, options| match (json_options, options) {
+              (ParserOptions::Json(a), ParserOptions::Json(b)) => {
+                ParserOptions::Json(a.merge_from(b))
+              }
+              _ => unreachable!(),
+            },
+          )
+        }
         _ => options.cloned(),
       }
     });
@@ -754,16 +777,18 @@ module.exports = "data:,";
             _ => unreachable!(),
           },
         ),
-        ModuleType::Json => rspack_util::merge_from_optional_with(
-          g.get("json").cloned(),
-          options,
-          |json_options, options| match (json_options, options) {
-            (GeneratorOptions::Json(a), GeneratorOptions::Json(b)) => {
-              GeneratorOptions::Json(a.merge_from(b))
-            }
-            _ => unreachable!(),
-          },
-        ),
+        ModuleType::Json | ModuleType::Json5 | ModuleType::Yaml | ModuleType::Toml => {
+          rspack_util::merge_from_optional_with(
+            g.get("json").cloned(),
+            options,
+            |json_options, options| match (json_options, options) {
+              (GeneratorOptions::Json(a), GeneratorOptions::Json(b)) => {
+                GeneratorOptions::Json(a.merge_from(b))
+              }
+              _ => unreachable!(),
+            },
+          )
+        }
         _ => options.cloned(),
       }
     });
@@ -950,6 +975,25 @@ fn match_ext(mut input: &str) -> winnow::ModalResult<(&str, &str)> {
   terminated(parser, eof).parse_next(&mut input)
 }
 
+/// Checks the module type resolved from the module rules against the `type` import attribute,
+/// e.g. `with { type: "text" }`, returns `None` for the unknown attribute types.
+fn is_module_type_compatible_with_import_attribute(
+  module_type: &ModuleType,
+  attribute_type: &str,
+) -> Option<bool> {
+  Some(match attribute_type {
+    // the yaml, toml and json5 modules are lowered into json modules
+    "json" => module_type.is_json_like(),
+    "text" => matches!(module_type, ModuleType::AssetSource),
+    "bytes" => matches!(module_type, ModuleType::AssetBytes),
+    "css" => matches!(
+      module_type,
+      ModuleType::Css | ModuleType::CssAuto | ModuleType::CssModule
+    ),
+    _ => return None,
+  })
+}
+
 #[test]
 fn test_split_element() {
   assert_eq!(split_element("a!a"), vec!["a", "a"]);
@@ -16,7 +16,9 @@ pub struct Cache {
 impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
     Self {
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(
+        &options.memory_cache_options(),
+      )),
       process_runtime_requirements_occasion: ProcessRuntimeRequirementsOccasion::new(new_storage(
         &options.cache,
       )),
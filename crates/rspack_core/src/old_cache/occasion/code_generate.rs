@@ -2,8 +2,9 @@ use std::future::Future;
 
 use rspack_collections::Identifier;
 use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
 
-use crate::{CodeGenerationJob, CodeGenerationResult, old_cache::storage};
+use crate::{CodeGenerationJob, CodeGenerationResult, ModuleIdentifier, old_cache::storage};
 
 type Storage = dyn storage::Storage<CodeGenerationResult>;
 
@@ -23,6 +24,34 @@ impl CodeGenerateOccasion {
     }
   }
 
+  fn cache_key(module: &ModuleIdentifier, hash: &RspackHashDigest) -> Identifier {
+    Identifier::from(format!("{}|{}", module, hash.encoded()))
+  }
+
+  /// Get the cached result of the module with the chunk graph module hash.
+  pub(crate) fn get(
+    &self,
+    module: &ModuleIdentifier,
+    hash: &RspackHashDigest,
+  ) -> Option<CodeGenerationResult> {
+    let storage = self.storage.as_ref()?;
+    storage.get(&Self::cache_key(module, hash))
+  }
+
+  /// Set the cached result of the module with the chunk graph module hash.
+  ///
+  /// It's used by persistent cache to restore the results of the last compilation.
+  pub(crate) fn set(
+    &self,
+    module: &ModuleIdentifier,
+    hash: &RspackHashDigest,
+    result: CodeGenerationResult,
+  ) {
+    if let Some(storage) = &self.storage {
+      storage.set(Self::cache_key(module, hash), result);
+    }
+  }
+
   // #[tracing::instrument(skip_all, fields(module = ?job.module))]
   pub async fn use_cache<G, F>(
     &self,
@@ -40,7 +69,7 @@ impl CodeGenerateOccasion {
         return (res, false);
       }
     };
-    let cache_key = Identifier::from(format!("{}|{}", job.module, job.hash.encoded()));
+    let cache_key = Self::cache_key(&job.module, &job.hash);
     if let Some(value) = storage.get(&cache_key) {
       (Ok(value), true)
     } else {
@@ -1,6 +1,6 @@
 use crate::{
-  CacheOptions, Context, Experiments, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
-  Resolve, StatsOptions,
+  CacheOptions, Context, ExperimentCacheOptions, Experiments, Mode, ModuleOptions, NodeOption,
+  Optimization, OutputOptions, Resolve, StatsOptions,
 };
 
 #[derive(Debug)]
@@ -23,4 +23,19 @@ pub struct CompilerOptions {
   pub __references: References,
 }
 
+impl CompilerOptions {
+  /// The options of the memory caches of code generation, chunk render and minify.
+  ///
+  /// Persistent cache restores the results of the last compilation into these memory caches,
+  /// so they are enabled whenever persistent cache is enabled.
+  pub fn memory_cache_options(&self) -> CacheOptions {
+    match (&self.cache, &self.experiments.cache) {
+      (CacheOptions::Disabled, ExperimentCacheOptions::Persistent(_)) => CacheOptions::Memory {
+        max_generations: None,
+      },
+      (cache, _) => cache.clone(),
+    }
+  }
+}
+
 pub type References = serde_json::Map<String, serde_json::Value>;
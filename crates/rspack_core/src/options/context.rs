@@ -4,17 +4,16 @@ use std::{
   path::{Path, PathBuf},
 };
 
-use rspack_cacheable::{cacheable, with::AsPreset};
+use rspack_cacheable::{cacheable, utils::PortablePath, with::As};
 use rspack_loader_runner::ResourceData;
 use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
 use rspack_util::atom::Atom;
 
 use crate::{contextify, parse_resource};
 
-#[cacheable]
+#[cacheable(with=As<PortablePath>)]
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Context {
-  #[cacheable(with=AsPreset)]
   inner: Atom,
 }
 
@@ -266,6 +266,16 @@ bitflags! {
     const ASYNC_MODULE_EXPORT_SYMBOL = 1 << 70;
     const MAKE_DEFERRED_NAMESPACE_OBJECT = 1 << 71;
     const MAKE_DEFERRED_NAMESPACE_OBJECT_SYMBOL = 1 << 72;
+
+    /**
+     * compile a WebAssembly.Module from id and hash, for source phase imports
+     */
+    const COMPILE_WASM = 1 << 73;
+
+    /**
+     * an object containing all installed WebAssembly.Instance export objects keyed by module id
+     */
+    const WASM_INSTANCES = 1 << 74;
   }
 }
 
@@ -314,6 +324,8 @@ pub fn runtime_globals_to_string(
     RuntimeGlobals::GLOBAL => format!("{scope_name}.g"),
     RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime".to_string(),
     RuntimeGlobals::INSTANTIATE_WASM => format!("{scope_name}.v"),
+    RuntimeGlobals::COMPILE_WASM => format!("{scope_name}.vs"),
+    RuntimeGlobals::WASM_INSTANCES => format!("{scope_name}.w"),
     RuntimeGlobals::ASYNC_MODULE => format!("{scope_name}.a"),
     RuntimeGlobals::ASYNC_MODULE_EXPORT_SYMBOL => format!("{scope_name}.aE"),
     RuntimeGlobals::BASE_URI => format!("{scope_name}.b"),
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use either::Either;
 use itertools::Itertools;
 use rayon::iter::{
@@ -5,9 +7,11 @@ use rayon::iter::{
   ParallelIterator,
 };
 use rspack_collections::{DatabaseItem, IdentifierSet};
-use rspack_error::{Diagnostic, Display, Result, StringDisplayer};
+use rspack_error::{Diagnostic, Display, Result, StringDisplayer, ToStringResultToRspackResultExt};
 use rustc_hash::FxHashMap as HashMap;
 
+mod printer;
+pub use printer::*;
 mod utils;
 pub use utils::*;
 mod r#struct;
@@ -728,6 +732,81 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  /// Serializes the stats into JSON which follows the schema of webpack `stats.toJson()`.
+  pub fn to_json(&self, options: &ExtendedStatsOptions) -> Result<serde_json::Value> {
+    let mut json = serde_json::Map::new();
+
+    if options.hash
+      && let Some(hash) = self.get_hash()
+    {
+      json.insert("hash".to_string(), hash.into());
+    }
+
+    if options.assets {
+      let (assets, assets_by_chunk_name) = self.get_assets();
+      json.insert("assets".to_string(), to_json_value(&assets)?);
+      let assets_by_chunk_name = assets_by_chunk_name
+        .iter()
+        .map(|item| (item.name, &item.files))
+        .collect::<BTreeMap<_, _>>();
+      json.insert(
+        "assetsByChunkName".to_string(),
+        to_json_value(&assets_by_chunk_name)?,
+      );
+    }
+
+    if options.chunks {
+      let chunks = self.get_chunks(options, |chunks| to_json_value(&chunks))??;
+      json.insert("chunks".to_string(), chunks);
+    }
+
+    if options.modules {
+      let modules = self.get_modules(options, |modules| to_json_value(&modules))??;
+      json.insert("modules".to_string(), modules);
+    }
+
+    if matches!(
+      options.entrypoints,
+      EntrypointsStatsOption::Bool(true) | EntrypointsStatsOption::String(_)
+    ) {
+      let entrypoints =
+        self.get_entrypoints(options.chunk_group_auxiliary, options.chunk_group_children);
+      json.insert(
+        "entrypoints".to_string(),
+        to_json_value(&chunk_groups_by_name(&entrypoints))?,
+      );
+    }
+
+    if options.chunk_groups {
+      let named_chunk_groups =
+        self.get_named_chunk_groups(options.chunk_group_auxiliary, options.chunk_group_children);
+      json.insert(
+        "namedChunkGroups".to_string(),
+        to_json_value(&chunk_groups_by_name(&named_chunk_groups))?,
+      );
+    }
+
+    let (errors, errors_count) =
+      self.get_errors(|errors| (options.errors.then(|| to_json_value(&errors)), errors.len()));
+    if let Some(errors) = errors {
+      json.insert("errors".to_string(), errors?);
+    }
+    json.insert("errorsCount".to_string(), errors_count.into());
+
+    let (warnings, warnings_count) = self.get_warnings(|warnings| {
+      (
+        options.warnings.then(|| to_json_value(&warnings)),
+        warnings.len(),
+      )
+    });
+    if let Some(warnings) = warnings {
+      json.insert("warnings".to_string(), warnings?);
+    }
+    json.insert("warningsCount".to_string(), warnings_count.into());
+
+    Ok(serde_json::Value::Object(json))
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
@@ -1328,6 +1407,16 @@ impl Stats<'_> {
   }
 }
 
+fn to_json_value<T: serde::Serialize>(value: &T) -> Result<serde_json::Value> {
+  serde_json::to_value(value).to_rspack_result()
+}
+
+fn chunk_groups_by_name<'a>(
+  chunk_groups: &'a [StatsChunkGroup<'a>],
+) -> BTreeMap<&'a str, &'a StatsChunkGroup<'a>> {
+  chunk_groups.iter().map(|cg| (cg.name, cg)).collect()
+}
+
 pub fn create_stats_errors<'a>(
   compilation: &'a Compilation,
   module_graph: &'a ModuleGraph,
@@ -0,0 +1,316 @@
+use std::fmt::Write;
+
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_util::size::format_size;
+
+use super::{
+  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsAsset, StatsChunk, StatsChunkGroup,
+  StatsError, StatsModule, StatsPreset,
+};
+use crate::ModuleType;
+
+/// The max count of modules printed by the `normal` preset, same as `modulesSpace` of webpack.
+const NORMAL_MODULES_SPACE: usize = 15;
+
+/// `StatsPrinter` prints the human-readable summary of [`Stats`], like `stats.toString()` of webpack.
+#[derive(Debug, Default, Clone)]
+pub struct StatsPrinter {
+  pub preset: StatsPreset,
+  pub colors: bool,
+}
+
+impl StatsPrinter {
+  pub fn new(preset: StatsPreset, colors: bool) -> Self {
+    Self { preset, colors }
+  }
+
+  pub fn print(&self, stats: &Stats) -> Result<String> {
+    let options = ExtendedStatsOptions::from_preset(self.preset);
+    let verbose = matches!(self.preset, StatsPreset::Verbose);
+    let mut out = String::new();
+
+    if options.assets {
+      let (mut assets, _) = stats.get_assets();
+      assets.sort_by(|a, b| a.name.cmp(b.name));
+      for asset in &assets {
+        self.print_asset(&mut out, asset).to_rspack_result()?;
+      }
+    }
+
+    if verbose
+      && matches!(
+        options.entrypoints,
+        EntrypointsStatsOption::Bool(true) | EntrypointsStatsOption::String(_)
+      )
+    {
+      for entrypoint in stats.get_entrypoints(false, false) {
+        self
+          .print_entrypoint(&mut out, &entrypoint)
+          .to_rspack_result()?;
+      }
+    }
+
+    if options.chunks && verbose {
+      let chunks = stats.get_chunks(&options, |chunks| {
+        let mut out = String::new();
+        chunks
+          .iter()
+          .try_for_each(|chunk| self.print_chunk(&mut out, chunk))
+          .map(|_| out)
+      })?;
+      out.push_str(&chunks.to_rspack_result()?);
+    }
+
+    if options.modules {
+      let modules = stats.get_modules(&options, |modules| self.print_modules(&modules, verbose))?;
+      out.push_str(&modules.to_rspack_result()?);
+    }
+
+    let (warnings, warnings_count) = stats.get_warnings(|warnings| {
+      let mut out = String::new();
+      let res = warnings
+        .iter()
+        .try_for_each(|warning| self.print_error(&mut out, "WARNING", warning));
+      (res.map(|_| out), warnings.len())
+    });
+    if options.warnings {
+      out.push_str(&warnings.to_rspack_result()?);
+    }
+
+    let (errors, errors_count) = stats.get_errors(|errors| {
+      let mut out = String::new();
+      let res = errors
+        .iter()
+        .try_for_each(|error| self.print_error(&mut out, "ERROR", error));
+      (res.map(|_| out), errors.len())
+    });
+    if options.errors {
+      out.push_str(&errors.to_rspack_result()?);
+    }
+
+    if !matches!(self.preset, StatsPreset::ErrorsOnly) {
+      self
+        .print_summary(&mut out, stats, &options, errors_count, warnings_count)
+        .to_rspack_result()?;
+    }
+    Ok(out)
+  }
+
+  fn print_modules(
+    &self,
+    modules: &[StatsModule],
+    verbose: bool,
+  ) -> std::result::Result<String, std::fmt::Error> {
+    let mut out = String::new();
+    // runtime modules are only shown in the verbose preset
+    let modules = modules
+      .iter()
+      .filter(|module| verbose || !matches!(module.module_type, ModuleType::Runtime))
+      .collect::<Vec<_>>();
+    if matches!(self.preset, StatsPreset::Minimal) {
+      writeln!(out, "{} modules", modules.len())?;
+      return Ok(out);
+    }
+
+    let space = if verbose {
+      modules.len()
+    } else {
+      NORMAL_MODULES_SPACE
+    };
+    for module in modules.iter().take(space) {
+      self.print_module(&mut out, module, "", verbose)?;
+    }
+    if modules.len() > space {
+      writeln!(out, "+ {} modules", modules.len() - space)?;
+    }
+    Ok(out)
+  }
+
+  fn print_asset(&self, out: &mut String, asset: &StatsAsset) -> std::fmt::Result {
+    write!(
+      out,
+      "asset {} {}",
+      self.green(asset.name),
+      format_size(asset.size)
+    )?;
+    if asset.emitted {
+      write!(out, " {}", self.green("[emitted]"))?;
+    }
+    if asset.info.minimized.unwrap_or_default() {
+      write!(out, " {}", self.green("[minimized]"))?;
+    }
+    if !asset.chunk_names.is_empty() {
+      write!(out, " (name: {})", asset.chunk_names.join(", "))?;
+    }
+    writeln!(out)
+  }
+
+  fn print_entrypoint(&self, out: &mut String, entrypoint: &StatsChunkGroup) -> std::fmt::Result {
+    writeln!(
+      out,
+      "Entrypoint {} {} = {}",
+      self.bold(entrypoint.name),
+      format_size(entrypoint.assets_size as f64),
+      entrypoint
+        .assets
+        .iter()
+        .map(|asset| self.green(asset.name))
+        .collect::<Vec<_>>()
+        .join(" ")
+    )
+  }
+
+  fn print_chunk(&self, out: &mut String, chunk: &StatsChunk) -> std::fmt::Result {
+    let mut runtime = chunk.runtime.iter().map(|r| r.as_str()).collect::<Vec<_>>();
+    runtime.sort_unstable();
+    write!(out, "chunk (runtime: {})", runtime.join(", "))?;
+    for file in &chunk.files {
+      write!(out, " {}", self.green(file))?;
+    }
+    if !chunk.names.is_empty() {
+      write!(out, " ({})", chunk.names.join(", "))?;
+    }
+    write!(out, " {}", format_size(chunk.size))?;
+    if chunk.entry {
+      write!(out, " {}", self.yellow("[entry]"))?;
+    } else if chunk.initial {
+      write!(out, " {}", self.yellow("[initial]"))?;
+    }
+    if chunk.rendered {
+      write!(out, " {}", self.green("[rendered]"))?;
+    }
+    writeln!(out)?;
+    for module in chunk.modules.iter().flatten() {
+      self.print_module(out, module, "  ", true)?;
+    }
+    Ok(())
+  }
+
+  fn print_module(
+    &self,
+    out: &mut String,
+    module: &StatsModule,
+    indent: &str,
+    verbose: bool,
+  ) -> std::fmt::Result {
+    write!(
+      out,
+      "{indent}{} {}",
+      self.bold(module.name.as_deref().unwrap_or_default()),
+      format_size(module.size)
+    )?;
+    if module.built {
+      write!(out, " {}", self.green("[built]"))?;
+    }
+    if module.code_generated {
+      write!(out, " {}", self.green("[code generated]"))?;
+    }
+    if module.cached {
+      write!(out, " [cached]")?;
+    }
+    if module.failed.unwrap_or_default() {
+      write!(out, " {}", self.red("[failed]"))?;
+    }
+    writeln!(out)?;
+
+    if verbose {
+      for reason in module.reasons.iter().flatten() {
+        write!(out, "{indent}  {}", reason.r#type.unwrap_or_default())?;
+        if let Some(module_name) = &reason.module_name {
+          write!(out, " {}", self.cyan(module_name))?;
+        }
+        if let Some(user_request) = reason.user_request {
+          write!(out, " {user_request}")?;
+        }
+        if let Some(loc) = &reason.loc {
+          write!(out, " {loc}")?;
+        }
+        writeln!(out)?;
+      }
+    }
+    Ok(())
+  }
+
+  fn print_error(&self, out: &mut String, kind: &str, error: &StatsError) -> std::fmt::Result {
+    let kind = match kind {
+      "ERROR" => self.red(kind),
+      _ => self.yellow(kind),
+    };
+    let location = error
+      .module_name
+      .as_deref()
+      .map(|name| match &error.loc {
+        Some(loc) => format!("{name} {loc}"),
+        None => name.to_string(),
+      })
+      .or_else(|| error.file.map(|file| file.to_string()));
+    match location {
+      Some(location) => writeln!(out, "{kind} in {}", self.bold(&location))?,
+      None => writeln!(out, "{kind}")?,
+    }
+    writeln!(out, "{}", error.message.trim_end())?;
+    writeln!(out)
+  }
+
+  fn print_summary(
+    &self,
+    out: &mut String,
+    stats: &Stats,
+    options: &ExtendedStatsOptions,
+    errors_count: usize,
+    warnings_count: usize,
+  ) -> std::fmt::Result {
+    let plural = |count: usize, word: &str| {
+      if count == 1 {
+        format!("{count} {word}")
+      } else {
+        format!("{count} {word}s")
+      }
+    };
+    let status = match (errors_count, warnings_count) {
+      (0, 0) => self.green("compiled successfully"),
+      (0, warnings) => self.yellow(&format!("compiled with {}", plural(warnings, "warning"))),
+      (errors, 0) => self.red(&format!("compiled with {}", plural(errors, "error"))),
+      (errors, warnings) => self.red(&format!(
+        "compiled with {} and {}",
+        plural(errors, "error"),
+        plural(warnings, "warning")
+      )),
+    };
+    write!(out, "Rspack {status}")?;
+    if options.hash
+      && let Some(hash) = stats.get_hash()
+    {
+      write!(out, " ({hash})")?;
+    }
+    writeln!(out)
+  }
+
+  fn paint(&self, code: &str, text: &str) -> String {
+    if self.colors {
+      format!("\u{1b}[{code}m{text}\u{1b}[0m")
+    } else {
+      text.to_string()
+    }
+  }
+
+  fn bold(&self, text: &str) -> String {
+    self.paint("1", text)
+  }
+
+  fn green(&self, text: &str) -> String {
+    self.paint("1;32", text)
+  }
+
+  fn yellow(&self, text: &str) -> String {
+    self.paint("1;33", text)
+  }
+
+  fn red(&self, text: &str) -> String {
+    self.paint("1;31", text)
+  }
+
+  fn cyan(&self, text: &str) -> String {
+    self.paint("1;36", text)
+  }
+}
@@ -1,9 +1,10 @@
-use std::{borrow::Cow, fmt::Debug};
+use std::{borrow::Cow, fmt::Debug, str::FromStr};
 
 use rspack_paths::Utf8Path;
 use rspack_sources::BoxSource;
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
+use serde::{Serialize, Serializer, ser::SerializeStruct};
 
 use crate::{ChunkGroupOrderKey, ModuleId, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType};
 
@@ -67,22 +68,149 @@ impl Default for ExtendedStatsOptions {
   }
 }
 
-#[derive(Debug)]
+/// Presets of the stats output, same as the string values of webpack `stats` option.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPreset {
+  #[default]
+  Normal,
+  Minimal,
+  Verbose,
+  ErrorsOnly,
+}
+
+impl StatsPreset {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Normal => "normal",
+      Self::Minimal => "minimal",
+      Self::Verbose => "verbose",
+      Self::ErrorsOnly => "errors-only",
+    }
+  }
+}
+
+impl FromStr for StatsPreset {
+  type Err = rspack_error::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "normal" => Ok(Self::Normal),
+      "minimal" => Ok(Self::Minimal),
+      "verbose" => Ok(Self::Verbose),
+      "errors-only" => Ok(Self::ErrorsOnly),
+      _ => Err(rspack_error::error!(
+        "Unknown stats preset `{s}`, expected one of `normal`, `minimal`, `verbose` and `errors-only`"
+      )),
+    }
+  }
+}
+
+impl ExtendedStatsOptions {
+  pub fn from_preset(preset: StatsPreset) -> Self {
+    match preset {
+      StatsPreset::Normal => Self {
+        modules: true,
+        entrypoints: EntrypointsStatsOption::String("auto".to_string()),
+        ..Default::default()
+      },
+      StatsPreset::Minimal => Self {
+        modules: true,
+        errors: true,
+        warnings: true,
+        ..Self::none()
+      },
+      StatsPreset::Verbose => Self {
+        assets: true,
+        cached_modules: true,
+        chunks: true,
+        chunk_group_auxiliary: true,
+        chunk_group_children: true,
+        chunk_groups: true,
+        chunk_modules: true,
+        chunk_relations: true,
+        depth: true,
+        entrypoints: EntrypointsStatsOption::Bool(true),
+        errors: true,
+        hash: true,
+        ids: true,
+        modules: false,
+        module_assets: true,
+        nested_modules: true,
+        optimization_bailout: true,
+        provided_exports: true,
+        reasons: true,
+        source: false,
+        used_exports: true,
+        warnings: true,
+      },
+      StatsPreset::ErrorsOnly => Self {
+        errors: true,
+        ..Self::none()
+      },
+    }
+  }
+
+  fn none() -> Self {
+    Self {
+      assets: false,
+      cached_modules: false,
+      chunks: false,
+      chunk_group_auxiliary: false,
+      chunk_group_children: false,
+      chunk_groups: false,
+      chunk_modules: false,
+      chunk_relations: false,
+      depth: false,
+      entrypoints: EntrypointsStatsOption::Bool(false),
+      errors: false,
+      hash: false,
+      ids: false,
+      modules: false,
+      module_assets: false,
+      nested_modules: false,
+      optimization_bailout: false,
+      provided_exports: false,
+      reasons: false,
+      source: false,
+      used_exports: false,
+      warnings: false,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsError<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<String>,
   pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_name: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub loc: Option<String>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_option_display"
+  )]
   pub file: Option<&'a Utf8Path>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_name: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_entry: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_initial: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunk_id: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub details: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub stack: Option<String>,
   pub module_trace: Vec<StatsModuleTrace<'a>>,
 }
@@ -94,19 +222,45 @@ pub struct StatsModuleTrace<'a> {
   pub dependencies: Vec<StatsErrorModuleTraceDependency>,
 }
 
-#[derive(Debug)]
+/// Flattened as `originIdentifier`, `moduleIdentifier`, etc., same as the module trace of webpack.
+impl Serialize for StatsModuleTrace<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let mut state = serializer.serialize_struct("StatsModuleTrace", 7)?;
+    state.serialize_field("originIdentifier", &self.origin.identifier)?;
+    state.serialize_field("originName", &self.origin.name)?;
+    if let Some(id) = &self.origin.id {
+      state.serialize_field("originId", id)?;
+    }
+    state.serialize_field("moduleIdentifier", &self.module.identifier)?;
+    state.serialize_field("moduleName", &self.module.name)?;
+    if let Some(id) = &self.module.id {
+      state.serialize_field("moduleId", id)?;
+    }
+    state.serialize_field("dependencies", &self.dependencies)?;
+    state.end()
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsErrorModuleTraceModule<'a> {
   pub identifier: ModuleIdentifier,
   pub name: Cow<'a, str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<ModuleId>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsErrorModuleTraceDependency {
   pub loc: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAsset<'a> {
   pub r#type: &'static str,
   pub name: &'a str,
@@ -121,25 +275,39 @@ pub struct StatsAsset<'a> {
   pub auxiliary_chunks: Vec<Option<&'a str>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetsByChunkName<'a> {
   pub name: &'a str,
   pub files: Vec<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetInfo<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub minimized: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub development: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub hot_module_replacement: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub source_filename: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub copied: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub immutable: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub javascript_module: Option<bool>,
+  #[serde(rename = "chunkhash")]
   pub chunk_hash: Vec<&'a str>,
+  #[serde(rename = "contenthash")]
   pub content_hash: Vec<&'a str>,
+  #[serde(rename = "fullhash")]
   pub full_hash: Vec<&'a str>,
+  #[serde(serialize_with = "serialize_related")]
   pub related: Vec<StatsAssetInfoRelated<'a>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub is_over_size_limit: Option<bool>,
 }
 
@@ -149,43 +317,80 @@ pub struct StatsAssetInfoRelated<'a> {
   pub value: Vec<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModule<'a> {
   pub r#type: &'static str,
+  #[serde(serialize_with = "serialize_display")]
   pub module_type: ModuleType,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub layer: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub name_for_condition: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub chunks: Option<Vec<&'a str>>, // has id after the call of chunkIds hook
   pub size: f64,
+  #[serde(serialize_with = "serialize_source_type_sizes")]
   pub sizes: Vec<StatsSourceTypeSize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub dependent: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_name: Option<Cow<'a, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub issuer_path: Option<Vec<StatsModuleIssuer<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reasons: Option<Vec<StatsModuleReason<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub assets: Option<Vec<&'a str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_option_source"
+  )]
   pub source: Option<&'a BoxSource>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub profile: Option<StatsModuleProfile>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub orphan: Option<bool>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_option_atoms"
+  )]
   pub provided_exports: Option<Vec<Atom>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub used_exports: Option<StatsUsedExports>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub optimization_bailout: Option<&'a [String]>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub depth: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub pre_order_index: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub post_order_index: Option<u32>,
   pub built: bool,
   pub code_generated: bool,
   pub build_time_executed: bool,
   pub cached: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub cacheable: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub optional: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub failed: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub errors: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub warnings: Option<u32>,
 }
 
@@ -196,98 +401,150 @@ pub enum StatsUsedExports {
   Null,
 }
 
-#[derive(Debug)]
+impl Serialize for StatsUsedExports {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::Vec(exports) => serializer.collect_seq(exports.iter().map(|e| e.as_str())),
+      Self::Bool(used) => serializer.serialize_bool(*used),
+      Self::Null => serializer.serialize_none(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleProfile {
   pub factory: u64,
   pub building: u64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsOriginRecord<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
   pub module_name: Cow<'a, str>,
   pub loc: String,
   pub request: &'a str,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunk<'a> {
   pub r#type: &'static str,
   pub files: Vec<&'a str>,
   pub auxiliary_files: Vec<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<&'a str>,
   pub entry: bool,
   pub initial: bool,
   pub names: Vec<&'a str>,
   pub size: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub parents: Option<Vec<&'a str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<Vec<&'a str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub siblings: Option<Vec<&'a str>>,
+  #[serde(serialize_with = "serialize_display_key_map")]
   pub children_by_order: HashMap<ChunkGroupOrderKey, Vec<String>>,
+  #[serde(serialize_with = "serialize_runtime")]
   pub runtime: &'a RuntimeSpec,
+  #[serde(serialize_with = "serialize_display_key_map")]
   pub sizes: HashMap<SourceType, f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reason: Option<&'a str>,
   pub rendered: bool,
   pub origins: Vec<StatsOriginRecord<'a>>,
   pub id_hints: Vec<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub hash: Option<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroupAsset<'a> {
   pub name: &'a str,
   pub size: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroup<'a> {
   pub name: &'a str,
   pub chunks: Vec<&'a str>,
   pub assets: Vec<StatsChunkGroupAsset<'a>>,
   pub assets_size: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub auxiliary_assets: Option<Vec<StatsChunkGroupAsset<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub auxiliary_assets_size: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<StatsChunkGroupChildren<'a>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub is_over_size_limit: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub child_assets: Option<StatschunkGroupChildAssets<'a>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroupChildren<'a> {
   pub preload: Vec<StatsChunkGroup<'a>>,
   pub prefetch: Vec<StatsChunkGroup<'a>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatschunkGroupChildAssets<'a> {
   pub preload: Vec<&'a str>,
   pub prefetch: Vec<&'a str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleIssuer<'s> {
   pub identifier: ModuleIdentifier,
   pub name: Cow<'s, str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<ModuleId>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleReason<'s> {
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_id: Option<ModuleId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub module_chunks: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_identifier: Option<ModuleIdentifier>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_name: Option<Cow<'s, str>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved_module_id: Option<ModuleId>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub r#type: Option<&'static str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub user_request: Option<&'s str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub explanation: Option<&'static str>,
   pub active: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub loc: Option<String>,
 }
 
@@ -296,3 +553,88 @@ pub struct StatsSourceTypeSize {
   pub source_type: SourceType,
   pub size: f64,
 }
+
+fn serialize_display<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+  T: std::fmt::Display,
+  S: Serializer,
+{
+  serializer.collect_str(value)
+}
+
+fn serialize_option_display<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+  T: std::fmt::Display,
+  S: Serializer,
+{
+  match value {
+    Some(value) => serializer.collect_str(value),
+    None => serializer.serialize_none(),
+  }
+}
+
+fn serialize_option_source<S>(source: &Option<&BoxSource>, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  match source {
+    Some(source) => serializer.serialize_str(&source.source().into_string_lossy()),
+    None => serializer.serialize_none(),
+  }
+}
+
+fn serialize_option_atoms<S>(atoms: &Option<Vec<Atom>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  match atoms {
+    Some(atoms) => serializer.collect_seq(atoms.iter().map(|atom| atom.as_str())),
+    None => serializer.serialize_none(),
+  }
+}
+
+fn serialize_runtime<S>(runtime: &&RuntimeSpec, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  let mut runtime = runtime.iter().map(|r| r.as_str()).collect::<Vec<_>>();
+  runtime.sort_unstable();
+  serializer.collect_seq(runtime)
+}
+
+/// Serializes a map as an object with sorted keys, the keys are converted by `Display`.
+fn serialize_display_key_map<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+  K: std::fmt::Display,
+  V: Serialize,
+  S: Serializer,
+{
+  let mut entries = map
+    .iter()
+    .map(|(key, value)| (key.to_string(), value))
+    .collect::<Vec<_>>();
+  entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  serializer.collect_map(entries)
+}
+
+fn serialize_source_type_sizes<S>(
+  sizes: &[StatsSourceTypeSize],
+  serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  let mut sizes = sizes
+    .iter()
+    .map(|s| (s.source_type.to_string(), s.size))
+    .collect::<Vec<_>>();
+  sizes.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  serializer.collect_map(sizes)
+}
+
+fn serialize_related<S>(related: &[StatsAssetInfoRelated], serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serializer.collect_map(related.iter().map(|r| (r.name, &r.value)))
+}
@@ -0,0 +1,404 @@
+use std::{
+  collections::{HashMap, HashSet},
+  io::{Cursor, Write},
+  sync::{Arc, Mutex},
+};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_util::node_path::NodePath;
+
+use crate::{
+  Error, FileMetadata, Result, RspackResultToFsResultExt, WritableFileSystem,
+  file_metadata::FilePermissions,
+};
+
+/// The mode of all the entries in the archive.
+const ENTRY_MODE: u32 = 0o644;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+  Zip,
+  Tar,
+  TarGz,
+}
+
+impl ArchiveFormat {
+  pub fn extension(&self) -> &'static str {
+    match self {
+      Self::Zip => "zip",
+      Self::Tar => "tar",
+      Self::TarGz => "tar.gz",
+    }
+  }
+}
+
+enum ArchiveWriter {
+  Zip(zip::ZipWriter<Cursor<Vec<u8>>>),
+  Tar(tar::Builder<Vec<u8>>),
+  TarGz(tar::Builder<flate2::write::GzEncoder<Vec<u8>>>),
+}
+
+impl ArchiveWriter {
+  fn new(format: ArchiveFormat) -> Self {
+    match format {
+      ArchiveFormat::Zip => Self::Zip(zip::ZipWriter::new(Cursor::new(Vec::new()))),
+      ArchiveFormat::Tar => Self::Tar(tar::Builder::new(Vec::new())),
+      ArchiveFormat::TarGz => {
+        // the gzip header contains no file name and a zero mtime by default
+        let encoder = flate2::GzBuilder::new().write(Vec::new(), flate2::Compression::default());
+        Self::TarGz(tar::Builder::new(encoder))
+      }
+    }
+  }
+
+  fn append(&mut self, name: &str, content: &[u8]) -> Result<()> {
+    match self {
+      Self::Zip(writer) => {
+        let options = zip::write::SimpleFileOptions::default()
+          .compression_method(zip::CompressionMethod::Deflated)
+          .last_modified_time(zip::DateTime::default())
+          .unix_permissions(ENTRY_MODE);
+        writer.start_file(name, options).to_fs_result()?;
+        writer.write_all(content)?;
+        Ok(())
+      }
+      Self::Tar(builder) => append_tar(builder, name, content),
+      Self::TarGz(builder) => append_tar(builder, name, content),
+    }
+  }
+
+  fn finish(self) -> Result<Vec<u8>> {
+    match self {
+      Self::Zip(writer) => Ok(writer.finish().to_fs_result()?.into_inner()),
+      Self::Tar(builder) => Ok(builder.into_inner()?),
+      Self::TarGz(builder) => Ok(builder.into_inner()?.finish()?),
+    }
+  }
+}
+
+fn append_tar<W: Write>(builder: &mut tar::Builder<W>, name: &str, content: &[u8]) -> Result<()> {
+  let mut header = tar::Header::new_gnu();
+  header.set_size(content.len() as u64);
+  header.set_mode(ENTRY_MODE);
+  header.set_mtime(0);
+  header.set_uid(0);
+  header.set_gid(0);
+  header.set_entry_type(tar::EntryType::Regular);
+  builder.append_data(&mut header, name, content)?;
+  Ok(())
+}
+
+#[derive(Debug, Default)]
+struct ArchiveState {
+  finished: bool,
+  /// The contents of the emitted files, which are written to the archive when it is finished.
+  files: HashMap<Utf8PathBuf, Vec<u8>>,
+  dirs: HashSet<Utf8PathBuf>,
+}
+
+/// `ArchiveFileSystem` is a [`WritableFileSystem`] which packs the emitted files into a zip or tar(.gz) archive.
+///
+/// The emitted files are kept in memory until the archive is finished, then they are written
+/// sorted by their entry names with fixed timestamps and permissions. Assets are emitted
+/// concurrently, so sorting makes the same output always produce the same archive byte-for-byte.
+#[derive(Debug, Clone)]
+pub struct ArchiveFileSystem {
+  format: ArchiveFormat,
+  root: Utf8PathBuf,
+  state: Arc<Mutex<ArchiveState>>,
+}
+
+impl ArchiveFileSystem {
+  /// Create an archive file system, the entries in the archive are relative to `root`,
+  /// which is usually the output path.
+  pub fn new(format: ArchiveFormat, root: impl Into<Utf8PathBuf>) -> Self {
+    let root: Utf8PathBuf = root.into();
+    Self {
+      format,
+      root: root.node_normalize(),
+      state: Default::default(),
+    }
+  }
+
+  pub fn format(&self) -> ArchiveFormat {
+    self.format
+  }
+
+  /// Finish the archive and return its content, no more files can be written after it.
+  pub async fn finish(&self) -> Result<Vec<u8>> {
+    let files = {
+      let mut state = self.lock();
+      if state.finished {
+        return Err(finished_error());
+      }
+      state.finished = true;
+      std::mem::take(&mut state.files)
+    };
+    let mut entries = files
+      .into_iter()
+      .map(|(file, content)| Ok((self.entry_name(&file)?, content)))
+      .collect::<Result<Vec<_>>>()?;
+    entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let mut writer = ArchiveWriter::new(self.format);
+    for (name, content) in entries {
+      writer.append(&name, &content)?;
+    }
+    writer.finish()
+  }
+
+  /// Finish the archive and write it to `file` of `fs`.
+  pub async fn finish_to(&self, fs: &dyn WritableFileSystem, file: &Utf8Path) -> Result<()> {
+    let archive = self.finish().await?;
+    if let Some(dir) = file.parent() {
+      fs.create_dir_all(dir).await?;
+    }
+    fs.write(file, &archive).await
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, ArchiveState> {
+    self.state.lock().expect("should get archive lock")
+  }
+
+  /// Get the path of the entry in the archive, which is relative to the root and joined by `/`.
+  fn entry_name(&self, path: &Utf8Path) -> Result<String> {
+    if let Ok(relative) = path.strip_prefix(&self.root)
+      && relative.components().next().is_some()
+    {
+      return Ok(
+        relative
+          .components()
+          .map(|component| component.as_str())
+          .collect::<Vec<_>>()
+          .join("/"),
+      );
+    }
+    Err(Error::new(
+      std::io::ErrorKind::InvalidInput,
+      &format!("{path} is outside of the archive root {}", self.root),
+    ))
+  }
+}
+
+fn finished_error() -> Error {
+  Error::new(
+    std::io::ErrorKind::InvalidInput,
+    "the archive has been finished",
+  )
+}
+
+fn not_found_error(path: &Utf8Path) -> Error {
+  Error::new(
+    std::io::ErrorKind::NotFound,
+    &format!("{path} does not exist"),
+  )
+}
+
+fn metadata(is_file: bool, size: u64) -> FileMetadata {
+  FileMetadata {
+    is_file,
+    is_directory: !is_file,
+    is_symlink: false,
+    atime_ms: 0,
+    mtime_ms: 0,
+    ctime_ms: 0,
+    size,
+  }
+}
+
+#[async_trait::async_trait]
+impl WritableFileSystem for ArchiveFileSystem {
+  async fn create_dir(&self, dir: &Utf8Path) -> Result<()> {
+    self.lock().dirs.insert(dir.node_normalize());
+    Ok(())
+  }
+
+  async fn create_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    let dir = dir.node_normalize();
+    let mut state = self.lock();
+    for ancestor in dir.ancestors() {
+      if !state.dirs.insert(ancestor.to_path_buf()) {
+        break;
+      }
+    }
+    Ok(())
+  }
+
+  async fn write(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    let file = file.node_normalize();
+    self.entry_name(&file)?;
+    let mut state = self.lock();
+    if state.finished {
+      return Err(finished_error());
+    }
+    state.files.insert(file, data.to_vec());
+    Ok(())
+  }
+
+  async fn remove_file(&self, file: &Utf8Path) -> Result<()> {
+    let file = file.node_normalize();
+    match self.lock().files.remove(&file) {
+      Some(_) => Ok(()),
+      None => Err(not_found_error(&file)),
+    }
+  }
+
+  async fn remove_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    let dir = dir.node_normalize();
+    let mut state = self.lock();
+    state.files.retain(|path, _| !path.starts_with(&dir));
+    state.dirs.retain(|path| !path.starts_with(&dir));
+    Ok(())
+  }
+
+  async fn read_dir(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    let dir = dir.node_normalize();
+    let state = self.lock();
+    let mut names = state
+      .files
+      .keys()
+      .chain(state.dirs.iter())
+      .filter(|path| path.parent() == Some(dir.as_path()))
+      .filter_map(|path| path.file_name().map(ToString::to_string))
+      .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    Ok(names)
+  }
+
+  async fn read_file(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+    let file = file.node_normalize();
+    self
+      .lock()
+      .files
+      .get(&file)
+      .cloned()
+      .ok_or_else(|| not_found_error(&file))
+  }
+
+  async fn stat(&self, file: &Utf8Path) -> Result<FileMetadata> {
+    let file = file.node_normalize();
+    let state = self.lock();
+    if let Some(content) = state.files.get(&file) {
+      return Ok(metadata(true, content.len() as u64));
+    }
+    if state.dirs.contains(&file) {
+      return Ok(metadata(false, 0));
+    }
+    Err(not_found_error(&file))
+  }
+
+  async fn set_permissions(&self, _path: &Utf8Path, _perm: FilePermissions) -> Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Read;
+
+  use rspack_paths::Utf8Path;
+
+  use super::{ArchiveFileSystem, ArchiveFormat};
+  use crate::WritableFileSystem;
+
+  async fn emit(fs: &ArchiveFileSystem) {
+    fs.create_dir_all(Utf8Path::new("/dist/static"))
+      .await
+      .unwrap();
+    fs.write(Utf8Path::new("/dist/static/b.js"), b"b")
+      .await
+      .unwrap();
+    fs.write(Utf8Path::new("/dist/main.js"), b"main")
+      .await
+      .unwrap();
+    fs.write(Utf8Path::new("/dist/a.js"), b"a").await.unwrap();
+  }
+
+  #[tokio::test]
+  async fn archive_fs_test() {
+    let fs = ArchiveFileSystem::new(ArchiveFormat::Tar, "/dist");
+    emit(&fs).await;
+    assert!(fs.write(Utf8Path::new("/other/a.js"), b"a").await.is_err());
+    assert!(
+      fs.write(Utf8Path::new("/dist/../other/a.js"), b"a")
+        .await
+        .is_err()
+    );
+    // the entries can be overwritten and read back before the archive is finished
+    fs.write(Utf8Path::new("/dist/a.js"), b"aa").await.unwrap();
+    assert_eq!(
+      fs.read_file(Utf8Path::new("/dist/./a.js")).await.unwrap(),
+      b"aa"
+    );
+    assert_eq!(
+      fs.stat(Utf8Path::new("/dist/main.js")).await.unwrap().size,
+      4
+    );
+    fs.write(Utf8Path::new("/dist/removed.js"), b"removed")
+      .await
+      .unwrap();
+    fs.remove_file(Utf8Path::new("/dist/removed.js"))
+      .await
+      .unwrap();
+    assert_eq!(
+      fs.read_dir(Utf8Path::new("/dist")).await.unwrap(),
+      vec!["a.js", "main.js", "static"]
+    );
+
+    let tar = fs.finish().await.unwrap();
+    assert!(fs.write(Utf8Path::new("/dist/c.js"), b"c").await.is_err());
+    let mut archive = tar::Archive::new(tar.as_slice());
+    let entries = archive
+      .entries()
+      .unwrap()
+      .map(|entry| {
+        let mut entry = entry.unwrap();
+        assert_eq!(entry.header().mtime().unwrap(), 0);
+        let mut content = String::new();
+        entry.read_to_string(&mut content).unwrap();
+        (entry.path().unwrap().to_string_lossy().to_string(), content)
+      })
+      .collect::<Vec<_>>();
+    // the entries are sorted by name regardless of the emission order
+    assert_eq!(
+      entries,
+      vec![
+        ("a.js".to_string(), "aa".to_string()),
+        ("main.js".to_string(), "main".to_string()),
+        ("static/b.js".to_string(), "b".to_string()),
+      ]
+    );
+
+    let fs = ArchiveFileSystem::new(ArchiveFormat::Zip, "/dist");
+    emit(&fs).await;
+    let mut archive =
+      zip::ZipArchive::new(std::io::Cursor::new(fs.finish().await.unwrap())).unwrap();
+    assert_eq!(archive.len(), 3);
+    let mut content = String::new();
+    archive
+      .by_name("static/b.js")
+      .unwrap()
+      .read_to_string(&mut content)
+      .unwrap();
+    assert_eq!(content, "b");
+  }
+
+  #[tokio::test]
+  async fn archive_fs_reproducible() {
+    for format in [ArchiveFormat::Zip, ArchiveFormat::Tar, ArchiveFormat::TarGz] {
+      let a = ArchiveFileSystem::new(format, "/dist");
+      emit(&a).await;
+      // emit the same files in another order
+      let b = ArchiveFileSystem::new(format, "/dist");
+      b.write(Utf8Path::new("/dist/a.js"), b"a").await.unwrap();
+      b.write(Utf8Path::new("/dist/main.js"), b"main")
+        .await
+        .unwrap();
+      b.write(Utf8Path::new("/dist/static/b.js"), b"b")
+        .await
+        .unwrap();
+      assert_eq!(a.finish().await.unwrap(), b.finish().await.unwrap());
+    }
+  }
+}
@@ -19,6 +19,12 @@ pub use native_fs::{NativeFileSystem, NativeReadStream, NativeWriteStream};
 mod memory_fs;
 pub use memory_fs::{MemoryFileSystem, MemoryReadStream, MemoryWriteStream};
 
+mod overlay_fs;
+pub use overlay_fs::{OverlayChanges, OverlayFileSystem};
+
+mod archive_fs;
+pub use archive_fs::{ArchiveFileSystem, ArchiveFormat};
+
 mod error;
 pub use error::{
   Error, FsResultToIoResultExt, IoResultToFsResultExt, Result, RspackResultToFsResultExt,
@@ -0,0 +1,343 @@
+use std::{
+  collections::{BTreeMap, HashSet},
+  sync::{Arc, Mutex, RwLock},
+};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_util::current_time;
+
+use crate::{FileMetadata, FilePermissions, NativeFileSystem, ReadableFileSystem, Result};
+
+#[derive(Debug)]
+struct VirtualFile {
+  content: Vec<u8>,
+  metadata: FileMetadata,
+}
+
+impl VirtualFile {
+  fn new(content: Vec<u8>) -> Self {
+    let now = current_time();
+    Self {
+      metadata: FileMetadata {
+        is_file: true,
+        is_directory: false,
+        is_symlink: false,
+        atime_ms: now,
+        mtime_ms: now,
+        ctime_ms: now,
+        size: content.len() as u64,
+      },
+      content,
+    }
+  }
+}
+
+fn virtual_dir_metadata() -> FileMetadata {
+  let now = current_time();
+  FileMetadata {
+    is_file: false,
+    is_directory: true,
+    is_symlink: false,
+    atime_ms: now,
+    mtime_ms: now,
+    ctime_ms: now,
+    size: 0,
+  }
+}
+
+/// The virtual files changed since the last [`OverlayFileSystem::take_changes`].
+#[derive(Debug, Default)]
+pub struct OverlayChanges {
+  pub changed: HashSet<Utf8PathBuf>,
+  pub removed: HashSet<Utf8PathBuf>,
+}
+
+/// `OverlayFileSystem` layers in-memory virtual files on top of another [`ReadableFileSystem`].
+///
+/// Virtual files shadow the files with the same path in the base file system,
+/// and the ancestors of virtual files are treated as directories, so they can be resolved
+/// and listed by `read_dir` like real files.
+///
+/// The file system is cheap to clone and all the clones share the same virtual files.
+#[derive(Debug, Clone)]
+pub struct OverlayFileSystem {
+  base: Arc<dyn ReadableFileSystem>,
+  files: Arc<RwLock<BTreeMap<Utf8PathBuf, VirtualFile>>>,
+  changes: Arc<Mutex<OverlayChanges>>,
+}
+
+impl Default for OverlayFileSystem {
+  fn default() -> Self {
+    Self::new(Arc::new(NativeFileSystem::new(false)))
+  }
+}
+
+impl OverlayFileSystem {
+  pub fn new(base: Arc<dyn ReadableFileSystem>) -> Self {
+    Self {
+      base,
+      files: Default::default(),
+      changes: Default::default(),
+    }
+  }
+
+  /// Add or update a virtual file.
+  pub fn write_file(&self, path: &Utf8Path, content: Vec<u8>) {
+    self
+      .files
+      .write()
+      .expect("should get lock")
+      .insert(path.to_path_buf(), VirtualFile::new(content));
+    let mut changes = self.changes.lock().expect("should get lock");
+    changes.removed.remove(path);
+    changes.changed.insert(path.to_path_buf());
+  }
+
+  /// Remove a virtual file, returns false if the virtual file does not exist.
+  pub fn remove_file(&self, path: &Utf8Path) -> bool {
+    let removed = self
+      .files
+      .write()
+      .expect("should get lock")
+      .remove(path)
+      .is_some();
+    if removed {
+      let mut changes = self.changes.lock().expect("should get lock");
+      changes.changed.remove(path);
+      changes.removed.insert(path.to_path_buf());
+    }
+    removed
+  }
+
+  /// Whether the path is a virtual file.
+  pub fn contains(&self, path: &Utf8Path) -> bool {
+    self
+      .files
+      .read()
+      .expect("should get lock")
+      .contains_key(path)
+  }
+
+  /// The paths of all virtual files.
+  pub fn virtual_files(&self) -> Vec<Utf8PathBuf> {
+    self
+      .files
+      .read()
+      .expect("should get lock")
+      .keys()
+      .cloned()
+      .collect()
+  }
+
+  /// Take the virtual files added, updated or removed since the last call.
+  pub fn take_changes(&self) -> OverlayChanges {
+    std::mem::take(&mut *self.changes.lock().expect("should get lock"))
+  }
+
+  fn get_content(&self, path: &Utf8Path) -> Option<Vec<u8>> {
+    self
+      .files
+      .read()
+      .expect("should get lock")
+      .get(path)
+      .map(|file| file.content.clone())
+  }
+
+  fn get_metadata(&self, path: &Utf8Path) -> Option<FileMetadata> {
+    let files = self.files.read().expect("should get lock");
+    if let Some(file) = files.get(path) {
+      return Some(file.metadata.clone());
+    }
+    files
+      .range::<Utf8Path, _>((std::ops::Bound::Excluded(path), std::ops::Bound::Unbounded))
+      .next()
+      .is_some_and(|(file, _)| file.starts_with(path))
+      .then(virtual_dir_metadata)
+  }
+
+  /// The names of the virtual entries directly under the directory,
+  /// returns `None` if there is no virtual file in the directory.
+  fn read_virtual_dir(&self, dir: &Utf8Path) -> Option<Vec<String>> {
+    let files = self.files.read().expect("should get lock");
+    let mut entries: Vec<String> = vec![];
+    for path in files
+      .range::<Utf8Path, _>((std::ops::Bound::Excluded(dir), std::ops::Bound::Unbounded))
+      .map(|(path, _)| path)
+      .take_while(|path| path.starts_with(dir))
+    {
+      if let Ok(relative) = path.strip_prefix(dir)
+        && let Some(name) = relative.iter().next()
+        && entries.last().is_none_or(|last| last != name)
+      {
+        entries.push(name.to_string());
+      }
+    }
+    (!entries.is_empty()).then_some(entries)
+  }
+
+  fn merge_dir_entries(
+    &self,
+    virtual_entries: Option<Vec<String>>,
+    base_entries: Result<Vec<String>>,
+  ) -> Result<Vec<String>> {
+    let Some(virtual_entries) = virtual_entries else {
+      return base_entries;
+    };
+    let mut entries = base_entries.unwrap_or_default();
+    for entry in virtual_entries {
+      if !entries.contains(&entry) {
+        entries.push(entry);
+      }
+    }
+    Ok(entries)
+  }
+}
+
+#[async_trait::async_trait]
+impl ReadableFileSystem for OverlayFileSystem {
+  async fn read(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    match self.get_content(path) {
+      Some(content) => Ok(content),
+      None => self.base.read(path).await,
+    }
+  }
+
+  fn read_sync(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    match self.get_content(path) {
+      Some(content) => Ok(content),
+      None => self.base.read_sync(path),
+    }
+  }
+
+  async fn metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    match self.base.metadata(path).await {
+      Ok(metadata) if !self.contains(path) => Ok(metadata),
+      res => self.get_metadata(path).map_or(res, Ok),
+    }
+  }
+
+  fn metadata_sync(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    match self.base.metadata_sync(path) {
+      Ok(metadata) if !self.contains(path) => Ok(metadata),
+      res => self.get_metadata(path).map_or(res, Ok),
+    }
+  }
+
+  async fn symlink_metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    match self.base.symlink_metadata(path).await {
+      Ok(metadata) if !self.contains(path) => Ok(metadata),
+      res => self.get_metadata(path).map_or(res, Ok),
+    }
+  }
+
+  async fn canonicalize(&self, path: &Utf8Path) -> Result<Utf8PathBuf> {
+    if self.get_metadata(path).is_some() {
+      return Ok(path.to_path_buf());
+    }
+    self.base.canonicalize(path).await
+  }
+
+  async fn read_dir(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    let base_entries = self.base.read_dir(dir).await;
+    self.merge_dir_entries(self.read_virtual_dir(dir), base_entries)
+  }
+
+  fn read_dir_sync(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    let base_entries = self.base.read_dir_sync(dir);
+    self.merge_dir_entries(self.read_virtual_dir(dir), base_entries)
+  }
+
+  async fn permissions(&self, path: &Utf8Path) -> Result<Option<FilePermissions>> {
+    if self.contains(path) {
+      return Ok(Some(FilePermissions::from_mode(0o644)));
+    }
+    self.base.permissions(path).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_paths::Utf8Path;
+
+  use super::OverlayFileSystem;
+  use crate::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+
+  #[tokio::test]
+  async fn overlay_fs_test() {
+    let base = MemoryFileSystem::default();
+    WritableFileSystem::create_dir_all(&base, Utf8Path::new("/a/b"))
+      .await
+      .unwrap();
+    WritableFileSystem::write(&base, Utf8Path::new("/a/b/real.js"), b"real")
+      .await
+      .unwrap();
+    let fs = OverlayFileSystem::new(Arc::new(base));
+
+    fs.write_file(Utf8Path::new("/a/b/real.js"), b"shadowed".to_vec());
+    fs.write_file(Utf8Path::new("/a/b/virtual.js"), b"virtual".to_vec());
+    fs.write_file(Utf8Path::new("/a/c/d/deep.js"), b"deep".to_vec());
+
+    // read
+    assert_eq!(
+      fs.read(Utf8Path::new("/a/b/real.js")).await.unwrap(),
+      b"shadowed"
+    );
+    assert_eq!(
+      fs.read_sync(Utf8Path::new("/a/b/virtual.js")).unwrap(),
+      b"virtual"
+    );
+    assert!(fs.read(Utf8Path::new("/a/b/missing.js")).await.is_err());
+
+    // metadata
+    let metadata = fs.metadata(Utf8Path::new("/a/b/real.js")).await.unwrap();
+    assert!(metadata.is_file);
+    assert_eq!(metadata.size, 8);
+    assert!(
+      fs.metadata_sync(Utf8Path::new("/a/c"))
+        .unwrap()
+        .is_directory
+    );
+    assert!(
+      fs.metadata(Utf8Path::new("/a/c/d"))
+        .await
+        .unwrap()
+        .is_directory
+    );
+    assert!(fs.metadata(Utf8Path::new("/a/c/d/e")).await.is_err());
+    assert!(
+      fs.metadata(Utf8Path::new("/a/b"))
+        .await
+        .unwrap()
+        .is_directory
+    );
+
+    // read_dir
+    let mut entries = fs.read_dir(Utf8Path::new("/a")).await.unwrap();
+    entries.sort();
+    assert_eq!(entries, vec!["b", "c"]);
+    let mut entries = fs.read_dir_sync(Utf8Path::new("/a/b")).unwrap();
+    entries.sort();
+    assert_eq!(entries, vec!["real.js", "virtual.js"]);
+    assert_eq!(fs.read_dir(Utf8Path::new("/a/c")).await.unwrap(), vec!["d"]);
+
+    // changes
+    let changes = fs.take_changes();
+    assert_eq!(changes.changed.len(), 3);
+    assert!(changes.removed.is_empty());
+    assert!(fs.take_changes().changed.is_empty());
+
+    assert!(fs.remove_file(Utf8Path::new("/a/b/real.js")));
+    assert!(!fs.remove_file(Utf8Path::new("/a/b/real.js")));
+    assert_eq!(
+      fs.read(Utf8Path::new("/a/b/real.js")).await.unwrap(),
+      b"real"
+    );
+    assert!(fs.remove_file(Utf8Path::new("/a/c/d/deep.js")));
+    assert!(fs.metadata(Utf8Path::new("/a/c")).await.is_err());
+    let changes = fs.take_changes();
+    assert!(changes.changed.is_empty());
+    assert_eq!(changes.removed.len(), 2);
+  }
+}
@@ -6,25 +6,37 @@ use std::{
 use md4::Digest;
 use rspack_cacheable::{cacheable, with::AsPreset};
 use smol_str::SmolStr;
-use xxhash_rust::xxh64::Xxh64;
+use xxhash_rust::{xxh3::Xxh3, xxh64::Xxh64};
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  Xxhash3_128,
   MD4,
   SHA256,
+  SHA512,
+  Blake3,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      "sha256" => HashFunction::SHA256,
-      _ => panic!(
-        "Unsupported hash function: '{}'. Expected one of: xxhash64, md4, sha256",
-        value
-      ),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash3-128" => Ok(HashFunction::Xxhash3_128),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::SHA256),
+      "sha512" => Ok(HashFunction::SHA512),
+      "blake3" => Ok(HashFunction::Blake3),
+      _ => {
+        let mut error = rspack_error::Error::error(format!(
+          "Unsupported hash function: '{value}'. Expected one of: xxhash64, xxhash3-128, md4, sha256, sha512, blake3"
+        ));
+        error.code = Some("InvalidHashFunction".into());
+        error.help = Some("Check the `output.hashFunction` option.".into());
+        Err(error)
+      }
     }
   }
 }
@@ -32,13 +44,34 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  /// Standard base64 with padding, may contain `+`, `/` and `=`.
+  Base64,
+  /// URL and filename safe base64 without padding.
+  Base64Url,
+  /// `0-9a-zA-Z`, left padded with `0` to a fixed length.
+  Base62,
+  /// `0-9a-z`, left padded with `0` to a fixed length.
+  Base36,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => panic!("Unsupported hash digest: '{}'. Expected: hex", value),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base62" => Ok(HashDigest::Base62),
+      "base36" => Ok(HashDigest::Base36),
+      _ => {
+        let mut error = rspack_error::Error::error(format!(
+          "Unsupported hash digest: '{value}'. Expected one of: hex, base64, base64url, base62, base36"
+        ));
+        error.code = Some("InvalidHashDigest".into());
+        error.help = Some("Check the `output.hashDigest` option.".into());
+        Err(error)
+      }
     }
   }
 }
@@ -61,16 +94,22 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<Xxh64>),
+  Xxhash3_128(Box<Xxh3>),
   MD4(Box<md4::Md4>),
   SHA256(Box<sha2::Sha256>),
+  SHA512(Box<sha2::Sha512>),
+  Blake3(Box<blake3::Hasher>),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash3_128(_) => write!(f, "RspackHash(Xxhash3_128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
-      Self::SHA256(_) => write!(f, "RspackHash(SHA256"),
+      Self::SHA256(_) => write!(f, "RspackHash(SHA256)"),
+      Self::SHA512(_) => write!(f, "RspackHash(SHA512)"),
+      Self::Blake3(_) => write!(f, "RspackHash(Blake3)"),
     }
   }
 }
@@ -79,8 +118,11 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(Xxh64::new(0))),
+      HashFunction::Xxhash3_128 => Self::Xxhash3_128(Box::new(Xxh3::new())),
       HashFunction::MD4 => Self::MD4(Box::new(md4::Md4::new())),
       HashFunction::SHA256 => Self::SHA256(Box::new(sha2::Sha256::new())),
+      HashFunction::SHA512 => Self::SHA512(Box::new(sha2::Sha512::new())),
+      HashFunction::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
     }
   }
 
@@ -93,8 +135,8 @@ impl RspackHash {
   }
 
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
-    // The maximum value of sha256, the largest possible hash
-    let mut result = [0; 32];
+    // The maximum value of sha512, the largest possible hash
+    let mut result = [0; 64];
     let len;
 
     match self {
@@ -103,6 +145,11 @@ impl RspackHash {
         len = buf.len();
         result[..len].copy_from_slice(&buf);
       }
+      RspackHash::Xxhash3_128(hasher) => {
+        let buf = hasher.digest128().to_be_bytes();
+        len = buf.len();
+        result[..len].copy_from_slice(&buf);
+      }
       RspackHash::MD4(hash) => {
         let buf = hash.finalize();
         len = buf.len();
@@ -113,6 +160,16 @@ impl RspackHash {
         len = buf.len();
         result[..len].copy_from_slice(&buf);
       }
+      RspackHash::SHA512(hash) => {
+        let buf = hash.finalize();
+        len = buf.len();
+        result[..len].copy_from_slice(&buf);
+      }
+      RspackHash::Blake3(hasher) => {
+        let buf = hasher.finalize();
+        len = buf.as_bytes().len();
+        result[..len].copy_from_slice(buf.as_bytes());
+      }
     }
 
     RspackHashDigest::new(&result[..len], digest)
@@ -123,43 +180,36 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = (**hasher).clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
-      RspackHash::SHA256(hasher) => {
-        let hash = (**hasher).clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      RspackHash::Xxhash3_128(hasher) => (hasher.digest128() >> 64) as u64,
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&(**hasher).clone().finalize()),
+      RspackHash::SHA256(hasher) => msb_u64(&(**hasher).clone().finalize()),
+      RspackHash::SHA512(hasher) => msb_u64(&(**hasher).clone().finalize()),
+      RspackHash::Blake3(hasher) => msb_u64(hasher.finalize().as_bytes()),
     }
   }
 
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash3_128(hasher) => hasher.update(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
       RspackHash::SHA256(hasher) => hasher.update(bytes),
+      RspackHash::SHA512(hasher) => hasher.update(bytes),
+      RspackHash::Blake3(hasher) => {
+        hasher.update(bytes);
+      }
     }
   }
 }
 
+/// The most significant 64 bits of a hash.
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut msb = [0; 8];
+  msb.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(msb)
+}
+
 #[cacheable]
 #[derive(Debug, Clone, Eq)]
 pub struct RspackHashDigest {
@@ -176,14 +226,26 @@ impl From<&str> for RspackHashDigest {
 }
 
 impl RspackHashDigest {
-  /// `inner ` must be empty or come from a hash up to 256 bits
+  /// `inner ` must be empty or come from a hash up to 512 bits
   pub fn new(inner: &[u8], digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => {
-        let mut buf = [0; 64];
+        let mut buf = [0; 128];
         let s = hex(inner, &mut buf);
         s.into()
       }
+      HashDigest::Base64 => base64_simd::STANDARD.encode_to_string(inner).into(),
+      HashDigest::Base64Url => base64_simd::URL_SAFE_NO_PAD.encode_to_string(inner).into(),
+      HashDigest::Base62 => {
+        let mut buf = [0; 128];
+        let s = base_n(inner, BASE62_TABLE, &mut buf);
+        s.into()
+      }
+      HashDigest::Base36 => {
+        let mut buf = [0; 128];
+        let s = base_n(inner, &BASE62_TABLE[..36], &mut buf);
+        s.into()
+      }
     };
     Self { encoded }
   }
@@ -231,3 +293,95 @@ fn hex<'a>(data: &[u8], output: &'a mut [u8]) -> &'a str {
   // hex is always ascii
   unsafe { std::str::from_utf8_unchecked(&output[..i]) }
 }
+
+const BASE62_TABLE: &[u8; 62] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Encode `data` as a big-endian unsigned integer in the base of `table.len()`.
+///
+/// The output is left padded with the first char of `table` to the max length of `data.len()` bytes,
+/// so digests of the same hash function always have the same length, which is required when
+/// replacing hashes in place like `RealContentHashPlugin`.
+fn base_n<'a>(data: &[u8], table: &[u8], output: &'a mut [u8]) -> &'a str {
+  let base = table.len() as u32;
+  let len = (data.len() as f64 * 8.0 / (base as f64).log2()).ceil() as usize;
+  assert!(len <= output.len());
+
+  let mut number = [0u8; 64];
+  let number = &mut number[..data.len()];
+  number.copy_from_slice(data);
+  // long division from the least significant digit, each round divides `number` by `base` in place
+  for i in (0..len).rev() {
+    let mut remainder = 0u32;
+    for byte in number.iter_mut() {
+      let acc = (remainder << 8) | *byte as u32;
+      *byte = (acc / base) as u8;
+      remainder = acc % base;
+    }
+    output[i] = table[remainder as usize];
+  }
+
+  // # Safety
+  //
+  // table is always ascii
+  unsafe { std::str::from_utf8_unchecked(&output[..len]) }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::hash::Hasher;
+
+  use super::{HashDigest, HashFunction, RspackHash, RspackHashDigest};
+
+  #[test]
+  fn hash_digest_encodings() {
+    let data = [0x00, 0xff, 0x10, 0x7e, 0xfb, 0xbf];
+    let encode = |digest: &str| {
+      RspackHashDigest::new(&data, &HashDigest::try_from(digest).unwrap())
+        .encoded()
+        .to_string()
+    };
+    assert_eq!(encode("hex"), "00ff107efbbf");
+    assert_eq!(encode("base64"), "AP8Qfvu/");
+    assert_eq!(encode("base64url"), "AP8Qfvu_");
+    // 0x00ff107efbbf = 1095493417919
+    assert_eq!(encode("base36"), "00dz9ghv3z");
+    assert_eq!(encode("base62"), "00jhMmJS7");
+
+    let zero = RspackHashDigest::new(&[0; 8], &HashDigest::Base62);
+    assert_eq!(zero.encoded(), "00000000000");
+    let max = RspackHashDigest::new(&[0xff; 32], &HashDigest::Base36);
+    assert_eq!(max.encoded().len(), 50);
+    assert!(
+      RspackHashDigest::new(&[], &HashDigest::Base36)
+        .encoded()
+        .is_empty()
+    );
+
+    let error = HashDigest::try_from("base32").unwrap_err();
+    assert_eq!(error.code.as_deref(), Some("InvalidHashDigest"));
+  }
+
+  #[test]
+  fn hash_functions() {
+    let digest = |name: &str| {
+      let function = HashFunction::try_from(name).unwrap();
+      let mut hasher = RspackHash::new(&function);
+      hasher.write(b"rspack");
+      hasher.digest(&HashDigest::Hex).encoded().to_string()
+    };
+    assert_eq!(digest("xxhash64").len(), 16);
+    assert_eq!(digest("xxhash3-128").len(), 32);
+    assert_eq!(digest("md4").len(), 32);
+    assert_eq!(digest("sha256").len(), 64);
+    assert_eq!(digest("sha512").len(), 128);
+    assert_eq!(digest("blake3").len(), 64);
+    assert_eq!(
+      digest("sha512"),
+      "0dd7654f5e061e224d285a27b3aa2c067a3e1f556f2b58fcab2f93d15627cee290e02c1e11a4be0a0e5f4ab434479cb7bbfd9492ae812af469a966df258ff2ec"
+    );
+
+    let error = HashFunction::try_from("sha1").unwrap_err();
+    assert_eq!(error.code.as_deref(), Some("InvalidHashFunction"));
+    assert!(error.message.contains("'sha1'"));
+  }
+}
@@ -34,7 +34,7 @@ async fn module_ids(
     module_ids.clear();
   }
 
-  let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+  let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, module_ids, None);
 
   let mut module_ids_map = std::mem::take(module_ids);
   let context = compilation.options.context.as_ref();
@@ -0,0 +1,106 @@
+use std::hash::Hasher;
+
+use rspack_core::{
+  ChunkGraph, Compilation, CompilationModuleIds, ModuleIdsArtifact, Plugin,
+  incremental::IncrementalPasses,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{
+  compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
+  get_used_module_ids_and_modules,
+};
+
+#[derive(Debug)]
+pub struct HashedModuleIdsPluginOptions {
+  /// The context the module identifiers are relative to, defaults to the context of the compiler.
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashDigest,
+  /// The length of the ids, longer ids are used for the modules whose hashes conflict.
+  pub hash_digest_length: usize,
+}
+
+impl Default for HashedModuleIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::MD4,
+      hash_digest: HashDigest::Base64,
+      hash_digest_length: 4,
+    }
+  }
+}
+
+/// `HashedModuleIdsPlugin` uses a truncated hash of the module identifier relative to the context
+/// as the module id, which is compatible with the `HashedModuleIdsPlugin` of webpack.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedModuleIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedModuleIdsPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationModuleIds for HashedModuleIdsPlugin)]
+async fn module_ids(
+  &self,
+  compilation: &Compilation,
+  module_ids: &mut ModuleIdsArtifact,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::MODULE_IDS,
+    "HashedModuleIdsPlugin",
+    "it requires calculating the id of all the modules, which is a global effect",
+  ) {
+    if let Some(diagnostic) = diagnostic {
+      diagnostics.push(diagnostic);
+    }
+    module_ids.clear();
+  }
+
+  let (mut used_ids, mut modules) = get_used_module_ids_and_modules(compilation, module_ids, None);
+  let context = self
+    .options
+    .context
+    .as_deref()
+    .unwrap_or(compilation.options.context.as_str());
+  let module_graph = compilation.get_module_graph();
+  modules
+    .sort_unstable_by(|a, b| compare_modules_by_pre_order_index_or_identifier(module_graph, a, b));
+
+  for identifier in modules {
+    let module = module_graph
+      .module_by_identifier(&identifier)
+      .expect("should have module");
+    let mut hasher = RspackHash::new(&self.options.hash_function);
+    hasher.write(get_full_module_name(module, context).as_bytes());
+    let digest = hasher.digest(&self.options.hash_digest);
+    let mut len = self.options.hash_digest_length;
+    while len < digest.encoded().len() && used_ids.contains(digest.rendered(len)) {
+      len += 1;
+    }
+    let id = digest.rendered(len).to_string();
+    ChunkGraph::set_module_id(module_ids, identifier, id.as_str().into());
+    used_ids.insert(id);
+  }
+  Ok(())
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HashedModuleIdsPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compilation_hooks.module_ids.tap(module_ids::new(self));
+    Ok(())
+  }
+}
@@ -13,3 +13,7 @@ mod natural_chunk_ids_plugin;
 pub use natural_chunk_ids_plugin::NaturalChunkIdsPlugin;
 mod occurrence_chunk_ids_plugin;
 pub use occurrence_chunk_ids_plugin::*;
+mod record_ids_plugin;
+pub use record_ids_plugin::*;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::*;
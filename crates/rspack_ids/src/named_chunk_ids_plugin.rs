@@ -228,13 +228,20 @@ async fn chunk_ids(
       .retain(|chunk| chunk_by_ukey.contains(chunk) && !affected_chunks.contains(chunk));
   }
 
+  let mut used_ids: FxHashMap<ChunkId, ChunkUkey> = Default::default();
+
   let mut chunks: UkeySet<ChunkUkey> = chunk_by_ukey
     .values_mut()
-    .map(|chunk| {
+    .filter_map(|chunk| {
+      // Keep the ids assigned by the plugins run before, e.g. the ids revived by RecordIdsPlugin
+      if let Some(id) = chunk.id() {
+        used_ids.insert(id.clone(), chunk.ukey());
+        return None;
+      }
       if let Some(id) = named_chunk_ids_artifact.chunk_ids.get(&chunk.ukey()) {
         chunk.set_id(id.clone());
       }
-      chunk.ukey()
+      Some(chunk.ukey())
     })
     .collect();
 
@@ -245,13 +252,15 @@ async fn chunk_ids(
     .mutations_writeable()
     .then(Mutations::default);
 
-  let mut used_ids: FxHashMap<ChunkId, ChunkUkey> = Default::default();
-
   // Use chunk name as default chunk id
   chunks.retain(|chunk_ukey| {
     let chunk = chunk_by_ukey.expect_get_mut(chunk_ukey);
     if let Some(chunk_name) = chunk.name() {
       let name = chunk_name.to_string();
+      // The name is already used as the id of another chunk
+      if used_ids.contains_key(name.as_str()) {
+        return true;
+      }
       used_ids.insert(name.clone().into(), *chunk_ukey);
       if chunk.set_id(name)
         && let Some(mutations) = &mut mutations
@@ -31,7 +31,8 @@ async fn module_ids(
     module_ids.clear();
   }
 
-  let (used_ids, mut modules_in_natural_order) = get_used_module_ids_and_modules(compilation, None);
+  let (used_ids, mut modules_in_natural_order) =
+    get_used_module_ids_and_modules(compilation, module_ids, None);
 
   let mut module_ids_artifact = std::mem::take(module_ids);
   let module_graph = compilation.get_module_graph();
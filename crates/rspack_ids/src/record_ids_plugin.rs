@@ -0,0 +1,281 @@
+use std::{collections::BTreeMap, io::ErrorKind, sync::Mutex};
+
+use rspack_collections::DatabaseItem;
+use rspack_core::{
+  ChunkByUkey, ChunkGraph, ChunkNamedIdArtifact, Compilation, CompilationChunkIds,
+  CompilationModuleIds, CompilerEmit, ModuleIdsArtifact, Plugin, incremental::IncrementalPasses,
+};
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt, error};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8PathBuf;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+
+use crate::id_helpers::{get_full_chunk_name, get_full_module_name};
+
+/// A module or chunk id in the records file, numeric ids are written as numbers like webpack.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecordId {
+  Number(u32),
+  String(String),
+}
+
+impl RecordId {
+  fn new(id: &str) -> Self {
+    match id.parse::<u32>() {
+      Ok(n) if n.to_string() == id => Self::Number(n),
+      _ => Self::String(id.to_string()),
+    }
+  }
+
+  fn to_id(&self) -> String {
+    match self {
+      Self::Number(n) => n.to_string(),
+      Self::String(s) => s.clone(),
+    }
+  }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleRecords {
+  /// Module ids keyed by the module identifier relative to the context.
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, RecordId>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecords {
+  /// Ids of named chunks keyed by the chunk name.
+  #[serde(default)]
+  pub by_name: BTreeMap<String, RecordId>,
+  /// Ids of unnamed chunks keyed by the relative identifiers of their root modules.
+  #[serde(default)]
+  pub by_modules: BTreeMap<String, RecordId>,
+}
+
+/// The content of the records file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Records {
+  #[serde(default)]
+  pub modules: ModuleRecords,
+  #[serde(default)]
+  pub chunks: ChunkRecords,
+}
+
+#[derive(Debug, Default)]
+pub struct RecordIdsPluginOptions {
+  /// The records file to read the previous ids from, like `recordsInputPath` of webpack.
+  pub input_path: Option<Utf8PathBuf>,
+  /// The records file to write the ids to, like `recordsOutputPath` of webpack.
+  pub output_path: Option<Utf8PathBuf>,
+}
+
+/// `RecordIdsPlugin` keeps the module and chunk ids stable across builds with a records file.
+///
+/// The ids in the records are reused by the modules and chunks that still exist before the
+/// other id plugins run, so only the new modules and chunks get new ids from them.
+#[plugin]
+#[derive(Debug)]
+pub struct RecordIdsPlugin {
+  options: RecordIdsPluginOptions,
+  records: Mutex<Records>,
+}
+
+impl RecordIdsPlugin {
+  pub fn new(options: RecordIdsPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+}
+
+fn is_not_found(error: &rspack_fs::Error) -> bool {
+  let rspack_fs::Error::Io(error) = error;
+  error.kind() == ErrorKind::NotFound
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = -100)]
+async fn module_ids(
+  &self,
+  compilation: &Compilation,
+  module_ids: &mut ModuleIdsArtifact,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::MODULE_IDS,
+    "RecordIdsPlugin",
+    "it requires reviving the id of all the modules from records, which is a global effect",
+  ) {
+    if let Some(diagnostic) = diagnostic {
+      diagnostics.push(diagnostic);
+    }
+    module_ids.clear();
+  }
+
+  // the records are read from the same filesystem they are written to
+  let records = match &self.options.input_path {
+    Some(input_path) => match compilation
+      .intermediate_filesystem
+      .read_file(input_path)
+      .await
+    {
+      Ok(content) => serde_json::from_slice::<Records>(&content)
+        .map_err(|e| error!("Failed to parse records file {input_path}: {e}"))?,
+      // the records file does not exist in the first build
+      Err(e) if is_not_found(&e) => Records::default(),
+      Err(e) => return Err(error!("Failed to read records file {input_path}: {e}")),
+    },
+    None => Records::default(),
+  };
+
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = module_ids
+    .values()
+    .map(|id| id.to_string())
+    .collect::<FxHashSet<_>>();
+  let mut modules = module_graph
+    .modules()
+    .values()
+    .filter(|module| {
+      module.need_id()
+        && ChunkGraph::get_module_id(module_ids, module.identifier()).is_none()
+        && compilation
+          .chunk_graph
+          .get_number_of_module_chunks(module.identifier())
+          != 0
+    })
+    .map(|module| (get_full_module_name(module, context), module.identifier()))
+    .collect::<Vec<_>>();
+  // revive in a stable order, so the conflicted ids are always assigned to the same module
+  modules.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  for (name, module) in modules {
+    if let Some(id) = records.modules.by_identifier.get(&name) {
+      let id = id.to_id();
+      if used_ids.insert(id.clone()) {
+        ChunkGraph::set_module_id(module_ids, module, id.into());
+      }
+    }
+  }
+
+  *self.records.lock().expect("should lock records") = records;
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = -100)]
+async fn chunk_ids(
+  &self,
+  compilation: &Compilation,
+  chunk_by_ukey: &mut ChunkByUkey,
+  _named_chunk_ids_artifact: &mut ChunkNamedIdArtifact,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::CHUNK_IDS,
+    "RecordIdsPlugin",
+    "it requires reviving the id of all the chunks from records, which is a global effect",
+  ) && let Some(diagnostic) = diagnostic
+  {
+    diagnostics.push(diagnostic);
+  }
+
+  let records = std::mem::take(&mut *self.records.lock().expect("should lock records"));
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = chunk_by_ukey
+    .values()
+    .filter_map(|chunk| chunk.id().map(|id| id.to_string()))
+    .collect::<FxHashSet<_>>();
+  let mut chunks = chunk_by_ukey
+    .values()
+    .filter(|chunk| chunk.id().is_none())
+    .map(|chunk| {
+      let full_name = get_full_chunk_name(
+        chunk,
+        &compilation.chunk_graph,
+        module_graph,
+        &compilation.module_graph_cache_artifact,
+        context,
+      );
+      (full_name, chunk.name().is_some(), chunk.ukey())
+    })
+    .collect::<Vec<_>>();
+  chunks.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+  for (full_name, named, chunk) in chunks {
+    let recorded = if named {
+      records.chunks.by_name.get(&full_name)
+    } else {
+      records.chunks.by_modules.get(&full_name)
+    };
+    if let Some(id) = recorded {
+      let id = id.to_id();
+      if used_ids.insert(id.clone()) {
+        chunk_by_ukey.expect_get_mut(&chunk).set_id(id);
+      }
+    }
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilerEmit for RecordIdsPlugin)]
+async fn emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(output_path) = &self.options.output_path else {
+    return Ok(());
+  };
+
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let mut records = Records::default();
+  for module in module_graph.modules().values() {
+    if let Some(id) =
+      ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+    {
+      records.modules.by_identifier.insert(
+        get_full_module_name(module, context),
+        RecordId::new(id.as_str()),
+      );
+    }
+  }
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = chunk.id() else {
+      continue;
+    };
+    let full_name = get_full_chunk_name(
+      chunk,
+      &compilation.chunk_graph,
+      module_graph,
+      &compilation.module_graph_cache_artifact,
+      context,
+    );
+    let by = if chunk.name().is_some() {
+      &mut records.chunks.by_name
+    } else {
+      &mut records.chunks.by_modules
+    };
+    by.insert(full_name, RecordId::new(id.as_str()));
+  }
+
+  let json = serde_json::to_string_pretty(&records).to_rspack_result()?;
+  let intermediate_filesystem = compilation.intermediate_filesystem.as_ref();
+  if let Some(dir) = output_path.parent() {
+    intermediate_filesystem.create_dir_all(dir).await?;
+  }
+  intermediate_filesystem
+    .write(output_path, json.as_bytes())
+    .await?;
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.RecordIdsPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compilation_hooks.module_ids.tap(module_ids::new(self));
+    ctx.compilation_hooks.chunk_ids.tap(chunk_ids::new(self));
+    ctx.compiler_hooks.emit.tap(emit::new(self));
+    Ok(())
+  }
+}
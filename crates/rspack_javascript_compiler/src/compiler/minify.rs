@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+  collections::BTreeMap,
+  sync::{Arc, RwLock},
+};
 
 use rspack_error::BatchErrors;
 use rspack_util::swc::minify_file_comments;
@@ -12,12 +15,12 @@ use swc_core::{
     config::{IsModule, JsMinifyCommentOption, JsMinifyFormatOptions, SourceMapsConfig},
   },
   common::{
-    BytePos, FileName, Mark,
+    BytePos, FileName, Mark, SyntaxContext,
     comments::{Comments, SingleThreadedComments},
     errors::HANDLER,
   },
   ecma::{
-    ast::Ident,
+    ast::{Id, Ident},
     parser::{EsSyntax, Syntax},
     transforms::base::{
       fixer::{fixer, paren_remover},
@@ -27,6 +30,7 @@ use swc_core::{
     visit::{Visit, VisitMutWith, noop_visit_type},
   },
 };
+use swc_ecma_minifier::option::MangleCache;
 pub use swc_ecma_minifier::option::{
   MangleOptions, MinifyOptions, TopLevelOptions,
   terser::{TerserCompressorOptions, TerserEcmaVersion},
@@ -135,6 +139,12 @@ impl JavaScriptCompiler {
 
         let unresolved_mark = Mark::new();
         let top_level_mark = Mark::new();
+        let mangle_name_cache = opts.name_cache.as_ref().map(|name_cache| {
+          Arc::new(ProgramNameCache {
+            name_cache: name_cache.clone(),
+            top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+          }) as Arc<dyn MangleCache>
+        });
 
         let is_mangler_enabled = min_opts.mangle.is_some();
 
@@ -151,7 +161,7 @@ impl JavaScriptCompiler {
             &swc_ecma_minifier::option::ExtraOptions {
               unresolved_mark,
               top_level_mark,
-              mangle_name_cache: None,
+              mangle_name_cache,
             },
           );
 
@@ -255,6 +265,10 @@ pub struct JsMinifyOptions {
   #[serde(default = "true_as_default")]
   /// Indicates whether to inline the source content in the source map.
   pub inline_sources_content: bool,
+
+  #[serde(skip)]
+  /// The name cache shared with other minifications, to mangle the same names consistently.
+  pub name_cache: Option<Arc<NameCache>>,
 }
 
 const fn true_as_default() -> bool {
@@ -269,6 +283,117 @@ pub struct TerserSourceMapKind {
   pub content: Option<String>,
 }
 
+#[derive(Default, Serialize, Deserialize)]
+struct NameCacheJsonProps {
+  #[serde(default)]
+  props: BTreeMap<String, String>,
+}
+
+/// The `nameCache` format of terser, the names are prefixed with `$`.
+#[derive(Default, Serialize, Deserialize)]
+struct NameCacheJson {
+  #[serde(default)]
+  vars: NameCacheJsonProps,
+  #[serde(default)]
+  props: NameCacheJsonProps,
+}
+
+/// The mangled names of top level variables and properties, which can be shared across
+/// minifications and saved to a json file in the same format as the `nameCache` of terser.
+#[derive(Debug, Default)]
+pub struct NameCache {
+  vars: RwLock<FxHashMap<Atom, Atom>>,
+  props: RwLock<FxHashMap<Atom, Atom>>,
+}
+
+impl NameCache {
+  /// Add the names in the json to the name cache.
+  pub fn load_json(&self, json: &str) -> serde_json::Result<()> {
+    let json: NameCacheJson = serde_json::from_str(json)?;
+    let into_names = |props: BTreeMap<String, String>| {
+      props.into_iter().map(|(name, mangled)| {
+        let name = name.strip_prefix('$').unwrap_or(&name);
+        (Atom::from(name), Atom::from(mangled))
+      })
+    };
+    self
+      .vars
+      .write()
+      .expect("should get lock")
+      .extend(into_names(json.vars.props));
+    self
+      .props
+      .write()
+      .expect("should get lock")
+      .extend(into_names(json.props.props));
+    Ok(())
+  }
+
+  /// Serialize the name cache to json, the names are sorted to make the output stable.
+  pub fn to_json(&self) -> String {
+    let from_names = |names: &FxHashMap<Atom, Atom>| NameCacheJsonProps {
+      props: names
+        .iter()
+        .map(|(name, mangled)| (format!("${name}"), mangled.to_string()))
+        .collect(),
+    };
+    let json = NameCacheJson {
+      vars: from_names(&self.vars.read().expect("should get lock")),
+      props: from_names(&self.props.read().expect("should get lock")),
+    };
+    serde_json::to_string(&json).expect("should serialize name cache")
+  }
+}
+
+/// The name cache used by the mangler of a single program.
+///
+/// The variables are identified by the syntax context in the mangler,
+/// only the top level variables are shared by their names with other programs.
+struct ProgramNameCache {
+  name_cache: Arc<NameCache>,
+  top_level_ctxt: SyntaxContext,
+}
+
+impl MangleCache for ProgramNameCache {
+  fn vars_cache(&self, op: &mut dyn FnMut(&FxHashMap<Id, Atom>)) {
+    let vars = self
+      .name_cache
+      .vars
+      .read()
+      .expect("should get lock")
+      .iter()
+      .map(|(name, mangled)| ((name.clone(), self.top_level_ctxt), mangled.clone()))
+      .collect();
+    op(&vars);
+  }
+
+  fn props_cache(&self, op: &mut dyn FnMut(&FxHashMap<Atom, Atom>)) {
+    op(&self.name_cache.props.read().expect("should get lock"));
+  }
+
+  fn update_vars_cache(&self, new_data: &FxHashMap<Id, Atom>) {
+    let mut vars = self.name_cache.vars.write().expect("should get lock");
+    for ((name, ctxt), mangled) in new_data {
+      if *ctxt == self.top_level_ctxt {
+        vars.insert(name.clone(), mangled.clone());
+      }
+    }
+  }
+
+  fn update_props_cache(&self, new_data: &FxHashMap<Atom, Atom>) {
+    self
+      .name_cache
+      .props
+      .write()
+      .expect("should get lock")
+      .extend(
+        new_data
+          .iter()
+          .map(|(name, mangled)| (name.clone(), mangled.clone())),
+      );
+  }
+}
+
 pub struct IdentCollector {
   pub names: FxHashMap<BytePos, Atom>,
 }
@@ -8,7 +8,8 @@ use anymap::CloneAny;
 use once_cell::sync::OnceCell;
 use rspack_cacheable::{
   cacheable,
-  with::{AsInner, AsOption, AsPreset, AsString},
+  utils::{PortablePath, PortableString},
+  with::{As, AsInner, AsOption, AsPreset},
 };
 use rspack_error::{Error, Result, ToStringResultToRspackResultExt};
 use rspack_paths::{Utf8Path, Utf8PathBuf};
@@ -120,9 +121,10 @@ impl Debug for Content {
 #[derive(Debug, Clone)]
 pub struct ResourceData {
   /// Resource with absolute path, query and fragment
+  #[cacheable(with=As<PortableString>)]
   resource: String,
   /// Absolute resource path only
-  #[cacheable(with=AsOption<AsPreset>)]
+  #[cacheable(with=AsOption<As<PortablePath>>)]
   resource_path: Option<Utf8PathBuf>,
   /// Resource query with `?` prefix
   resource_query: Option<String>,
@@ -133,6 +135,7 @@ pub struct ResourceData {
   parameters: Option<String>,
   encoding: Option<String>,
   encoded_content: Option<String>,
+  #[cacheable(with=AsOption<As<PortablePath>>)]
   context: Option<String>,
   #[cacheable(with=AsInner)]
   scheme: OnceCell<Scheme>,
@@ -307,7 +310,7 @@ impl ResourceData {
 #[derive(Debug, Clone)]
 pub struct DescriptionData {
   /// Path to package.json
-  #[cacheable(with=AsString)]
+  #[cacheable(with=As<PortablePath>)]
   path: PathBuf,
 
   /// Raw package.json
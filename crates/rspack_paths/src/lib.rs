@@ -12,7 +12,8 @@ use dashmap::{DashMap, DashSet};
 use indexmap::IndexSet;
 use rspack_cacheable::{
   cacheable,
-  with::{AsRefStr, AsRefStrConverter},
+  utils::PortablePath,
+  with::{As, AsRefStrConverter},
 };
 use rustc_hash::FxHasher;
 use ustr::IdentityHasher;
@@ -52,7 +53,8 @@ impl<'a> AssertUtf8 for &'a Path {
   }
 }
 
-#[cacheable(with=AsRefStr, hashable)]
+/// Serialized relative to the project root when it is provided by the cacheable context.
+#[cacheable(with=As<PortablePath>, hashable)]
 #[derive(Clone, PartialEq, Eq)]
 pub struct ArcPath {
   path: Arc<Path>,
@@ -120,6 +122,12 @@ impl From<&ArcPath> for ArcPath {
   }
 }
 
+impl From<String> for ArcPath {
+  fn from(value: String) -> Self {
+    ArcPath::new(PathBuf::from(value).into())
+  }
+}
+
 impl From<&str> for ArcPath {
   fn from(value: &str) -> Self {
     ArcPath::new(<str as std::convert::AsRef<Path>>::as_ref(value).into())
@@ -51,6 +51,9 @@ pub struct ESMImportSpecifierDependency {
   phase: ImportPhase,
   attributes: Option<ImportAttributes>,
   pub evaluated_in_operator: bool,
+  /// The number of arguments of a direct call, `None` if it's not called directly
+  /// or the arguments contain a spread element.
+  pub call_arguments: Option<usize>,
   loc: Option<DependencyLocation>,
   pub namespace_object_as_context: bool,
   factorize_info: FactorizeInfo,
@@ -91,6 +94,7 @@ impl ESMImportSpecifierDependency {
       export_presence_mode,
       used_by_exports: None,
       evaluated_in_operator: false,
+      call_arguments: None,
       namespace_object_as_context: false,
       referenced_properties_in_destructuring,
       phase,
@@ -154,6 +158,11 @@ impl ESMImportSpecifierDependency {
   pub fn set_used_by_exports(&mut self, used_by_exports: Option<UsedByExports>) {
     self.used_by_exports = used_by_exports;
   }
+
+  /// Whether the imported binding is called directly, e.g. `foo()`.
+  pub fn is_call(&self) -> bool {
+    self.call
+  }
 }
 
 #[cacheable_dyn]
@@ -51,8 +51,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       parser.add_error(rspack_error::error!("deferImport is still an experimental feature. To continue using it, please enable 'experiments.deferImport'.").into());
     }
     if phase == ImportPhase::Source {
-      parser
-        .add_error(rspack_error::error!("Source phase imports is not supported in Rspack.").into());
+      parser.add_error(rspack_error::error!("Source phase imports is only supported for WebAssembly modules. To continue using it, please enable 'experiments.asyncWebAssembly'.").into());
     }
     let dependency = ESMImportSideEffectDependency::new(
       source.into(),
@@ -260,7 +259,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
     let mut ids = settings.ids;
     ids.extend(non_optional_members.iter().cloned());
     let direct_import = members.is_empty();
-    let dep = ESMImportSpecifierDependency::new(
+    let mut dep = ESMImportSpecifierDependency::new(
       settings.source,
       settings.name,
       settings.source_order,
@@ -278,6 +277,9 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       settings.attributes,
       Some(parser.source_rope().clone()),
     );
+    if direct_import && call_expr.args.iter().all(|arg| arg.spread.is_none()) {
+      dep.call_arguments = Some(call_expr.args.len());
+    }
     let dep_idx = parser.next_dependency_idx();
     parser.add_dependency(Box::new(dep));
 
@@ -0,0 +1,221 @@
+//! The parsers of the data formats which are lowered into json, so that they
+//! share the tree shaking of the json modules.
+
+use std::fmt;
+
+use json::{JsonValue, number::Number, object::Object};
+use rspack_error::{Error, error};
+use serde::{
+  Deserialize, Deserializer,
+  de::{MapAccess, SeqAccess, Visitor},
+};
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// Creates an error which points to the `line` and `column` (both 1-based) of the source.
+fn error_at_location(
+  source: &str,
+  line: usize,
+  column: usize,
+  title: &str,
+  message: &str,
+) -> Error {
+  let line_offset = source
+    .split_inclusive('\n')
+    .take(line.saturating_sub(1))
+    .map(str::len)
+    .sum::<usize>();
+  let offset = source[line_offset..]
+    .chars()
+    .take(column.saturating_sub(1))
+    .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+  Error::from_string(
+    Some(source.to_string()),
+    offset,
+    offset,
+    title.to_string(),
+    message.to_string(),
+  )
+}
+
+pub(crate) fn parse_json5(source: &str) -> Result<JsonValue, Error> {
+  json5::from_str::<JsonData>(source)
+    .map(|data| data.0)
+    .map_err(|e| match e {
+      json5::Error::Message {
+        msg,
+        location: Some(location),
+      } => error_at_location(
+        source,
+        location.line,
+        location.column,
+        "JSON5 parse error",
+        &msg,
+      ),
+      json5::Error::Message {
+        msg,
+        location: None,
+      } => error!("JSON5 parse error: {msg}"),
+    })
+}
+
+pub(crate) fn parse_yaml(source: &str) -> Result<JsonValue, Error> {
+  let mut documents = YamlLoader::load_from_str(source).map_err(|e| {
+    let marker = e.marker();
+    error_at_location(
+      source,
+      marker.line(),
+      marker.col() + 1,
+      "YAML parse error",
+      e.info(),
+    )
+  })?;
+  if documents.len() > 1 {
+    return Err(error!(
+      "YAML parse error: expected a single document, but found {}",
+      documents.len()
+    ));
+  }
+  documents
+    .pop()
+    .map_or(Ok(JsonValue::Null), yaml_to_json)
+    .map_err(|msg| error!("YAML parse error: {msg}"))
+}
+
+fn yaml_to_json(yaml: Yaml) -> Result<JsonValue, String> {
+  Ok(match yaml {
+    Yaml::Null => JsonValue::Null,
+    Yaml::Boolean(b) => JsonValue::Boolean(b),
+    Yaml::Integer(i) => JsonValue::Number(Number::from(i)),
+    Yaml::Real(_) => JsonValue::Number(Number::from(
+      yaml.as_f64().ok_or_else(|| "invalid float".to_string())?,
+    )),
+    Yaml::String(s) => JsonValue::String(s),
+    Yaml::Array(items) => JsonValue::Array(
+      items
+        .into_iter()
+        .map(yaml_to_json)
+        .collect::<Result<Vec<_>, _>>()?,
+    ),
+    Yaml::Hash(hash) => {
+      let mut object = Object::with_capacity(hash.len());
+      for (key, value) in hash {
+        let key = match key {
+          Yaml::String(s) | Yaml::Real(s) => s,
+          Yaml::Integer(i) => i.to_string(),
+          Yaml::Boolean(b) => b.to_string(),
+          Yaml::Null => "null".to_string(),
+          key => return Err(format!("unsupported mapping key {key:?}")),
+        };
+        object.insert(&key, yaml_to_json(value)?);
+      }
+      JsonValue::Object(object)
+    }
+    Yaml::Alias(_) | Yaml::BadValue => return Err(format!("unsupported value {yaml:?}")),
+  })
+}
+
+pub(crate) fn parse_toml(source: &str) -> Result<JsonValue, Error> {
+  source
+    .parse::<toml::Table>()
+    .map(|table| toml_to_json(toml::Value::Table(table)))
+    .map_err(|e| match e.span() {
+      Some(span) => Error::from_string(
+        Some(source.to_string()),
+        span.start,
+        span.end,
+        "TOML parse error".to_string(),
+        e.message().to_string(),
+      ),
+      None => error!("TOML parse error: {}", e.message()),
+    })
+}
+
+fn toml_to_json(value: toml::Value) -> JsonValue {
+  match value {
+    toml::Value::String(s) => JsonValue::String(s),
+    toml::Value::Integer(i) => JsonValue::Number(Number::from(i)),
+    toml::Value::Float(f) => JsonValue::Number(Number::from(f)),
+    toml::Value::Boolean(b) => JsonValue::Boolean(b),
+    // there is no date type in json, keep the RFC 3339 representation like `JSON.stringify`
+    toml::Value::Datetime(datetime) => JsonValue::String(datetime.to_string()),
+    toml::Value::Array(items) => JsonValue::Array(items.into_iter().map(toml_to_json).collect()),
+    toml::Value::Table(table) => {
+      let mut object = Object::with_capacity(table.len());
+      for (key, value) in table {
+        object.insert(&key, toml_to_json(value));
+      }
+      JsonValue::Object(object)
+    }
+  }
+}
+
+/// Deserializes into a [JsonValue] directly, which keeps the order of the keys.
+struct JsonData(JsonValue);
+
+impl<'de> Deserialize<'de> for JsonData {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(JsonDataVisitor).map(JsonData)
+  }
+}
+
+struct JsonDataVisitor;
+
+impl<'de> Visitor<'de> for JsonDataVisitor {
+  type Value = JsonValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a json value")
+  }
+
+  fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+    Ok(JsonValue::Boolean(v))
+  }
+
+  fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+    Ok(JsonValue::Number(Number::from(v)))
+  }
+
+  fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+    Ok(JsonValue::Number(Number::from(v)))
+  }
+
+  fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+    Ok(JsonValue::Number(Number::from(v)))
+  }
+
+  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+    Ok(JsonValue::String(v.to_string()))
+  }
+
+  fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+    Ok(JsonValue::String(v))
+  }
+
+  fn visit_unit<E>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue::Null)
+  }
+
+  fn visit_none<E>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue::Null)
+  }
+
+  fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+    JsonData::deserialize(deserializer).map(|data| data.0)
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+    while let Some(JsonData(item)) = seq.next_element()? {
+      items.push(item);
+    }
+    Ok(JsonValue::Array(items))
+  }
+
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+    let mut object = Object::with_capacity(map.size_hint().unwrap_or_default());
+    while let Some((key, JsonData(value))) = map.next_entry::<String, JsonData>()? {
+      object.insert(&key, value);
+    }
+    Ok(JsonValue::Object(object))
+  }
+}
@@ -13,9 +13,9 @@ use json::{
 use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_core::{
   BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ExportsInfoGetter, GenerateContext,
-  Module, ModuleGraph, NAMESPACE_OBJECT_EXPORT, ParseOption, ParserAndGenerator, Plugin,
-  PrefetchExportsInfoMode, PrefetchedExportsInfoWrapper, RuntimeGlobals, RuntimeSpec, SourceType,
-  UsageState, UsedNameItem,
+  Module, ModuleGraph, ModuleType, NAMESPACE_OBJECT_EXPORT, ParseOption, ParserAndGenerator,
+  Plugin, PrefetchExportsInfoMode, PrefetchedExportsInfoWrapper, RuntimeGlobals, RuntimeSpec,
+  SourceType, UsageState, UsedNameItem,
   diagnostics::ModuleParseError,
   rspack_sources::{BoxSource, RawStringSource, Source, SourceExt},
 };
@@ -24,6 +24,7 @@ use rspack_util::itoa;
 
 use crate::json_exports_dependency::JsonExportsDependency;
 
+mod data_formats;
 mod json_exports_dependency;
 mod utils;
 
@@ -59,6 +60,7 @@ impl ParserAndGenerator for JsonParserAndGenerator {
       build_info,
       build_meta,
       loaders,
+      module_type,
       module_parser_options,
       ..
     } = parse_context;
@@ -80,45 +82,53 @@ impl ParserAndGenerator for JsonParserAndGenerator {
       None
     };
 
-    let parse_result = json::parse(parse_result_str.as_deref().unwrap_or(strip_bom_source))
-      .map_err(|e| {
-        match e {
-          UnexpectedCharacter { ch, line, column } => {
-            let rope = ropey::Rope::from_str(&source);
-            let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
-            let start_offset = source[line_offset..]
-              .chars()
-              .take(column)
-              .fold(line_offset, |acc, cur| acc + cur.len_utf8());
-            let start_offset = if need_strip_bom {
-              start_offset + 1
-            } else {
-              start_offset
-            };
-            Error::from_string(
-              Some(source.into_owned()),
-              // one character offset
-              start_offset,
-              start_offset + 1,
-              "JSON parse error".to_string(),
-              format!("Unexpected character {ch}"),
-            )
+    // The result of the custom parse is always json, otherwise the yaml, toml and json5
+    // sources are lowered into json by their own parsers.
+    let parse_result = match (parse_result_str.as_deref(), module_type) {
+      (None, ModuleType::Json5) => data_formats::parse_json5(strip_bom_source),
+      (None, ModuleType::Yaml) => data_formats::parse_yaml(strip_bom_source),
+      (None, ModuleType::Toml) => data_formats::parse_toml(strip_bom_source),
+      (parse_result_str, _) => {
+        json::parse(parse_result_str.unwrap_or(strip_bom_source)).map_err(|e| {
+          match e {
+            UnexpectedCharacter { ch, line, column } => {
+              let rope = ropey::Rope::from_str(&source);
+              let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
+              let start_offset = source[line_offset..]
+                .chars()
+                .take(column)
+                .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+              let start_offset = if need_strip_bom {
+                start_offset + 1
+              } else {
+                start_offset
+              };
+              Error::from_string(
+                Some(source.into_owned()),
+                // one character offset
+                start_offset,
+                start_offset + 1,
+                "JSON parse error".to_string(),
+                format!("Unexpected character {ch}"),
+              )
+            }
+            ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => error!("{}", e),
+            UnexpectedEndOfJson => {
+              // End offset of json file
+              let length = source.len();
+              let offset = if length > 0 { length - 1 } else { length };
+              Error::from_string(
+                Some(source.into_owned()),
+                offset,
+                offset,
+                "JSON parse error".to_string(),
+                format!("{e}"),
+              )
+            }
           }
-          ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => error!("{}", e),
-          UnexpectedEndOfJson => {
-            // End offset of json file
-            let length = source.len();
-            let offset = if length > 0 { length - 1 } else { length };
-            Error::from_string(
-              Some(source.into_owned()),
-              offset,
-              offset,
-              "JSON parse error".to_string(),
-              format!("{e}"),
-            )
-          }
-        }
-      });
+        })
+      }
+    };
 
     let data = match parse_result {
       Ok(data) => data,
@@ -252,23 +262,30 @@ impl Plugin for JsonPlugin {
   }
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
-    ctx.register_parser_and_generator_builder(
-      rspack_core::ModuleType::Json,
-      Box::new(|p, g| {
-        let p = p
-          .and_then(|p| p.get_json())
-          .expect("should have JsonParserOptions");
+    for module_type in [
+      ModuleType::Json,
+      ModuleType::Json5,
+      ModuleType::Yaml,
+      ModuleType::Toml,
+    ] {
+      ctx.register_parser_and_generator_builder(
+        module_type,
+        Box::new(|p, g| {
+          let p = p
+            .and_then(|p| p.get_json())
+            .expect("should have JsonParserOptions");
 
-        let g = g
-          .and_then(|g| g.get_json())
-          .expect("should have JsonGeneratorOptions");
+          let g = g
+            .and_then(|g| g.get_json())
+            .expect("should have JsonGeneratorOptions");
 
-        Box::new(JsonParserAndGenerator {
-          exports_depth: p.exports_depth.expect("should have exports_depth"),
-          json_parse: g.json_parse.expect("should have json_parse"),
-        })
-      }),
-    );
+          Box::new(JsonParserAndGenerator {
+            exports_depth: p.exports_depth.expect("should have exports_depth"),
+            json_parse: g.json_parse.expect("should have json_parse"),
+          })
+        }),
+      );
+    }
 
     Ok(())
   }
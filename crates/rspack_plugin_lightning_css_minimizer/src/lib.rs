@@ -1,7 +1,8 @@
 use std::{
   collections::HashSet,
   hash::Hash,
-  sync::{Arc, LazyLock, RwLock},
+  sync::{Arc, LazyLock, Mutex, RwLock},
+  time::{Duration, Instant},
 };
 
 pub use lightningcss::targets::Browsers;
@@ -13,7 +14,8 @@ use lightningcss::{
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  ChunkUkey, Compilation, CompilationChunkHash, CompilationProcessAssets, Plugin,
+  ChunkUkey, Compilation, CompilationChunkHash, CompilationProcessAssets, Logger, MinifyResult,
+  Plugin,
   diagnostics::MinifyError,
   rspack_sources::{
     MapOptions, ObjectPool, RawStringSource, SourceExt, SourceMap, SourceMapSource,
@@ -26,6 +28,8 @@ use rspack_hook::{plugin, plugin_hook};
 use rspack_util::asset_condition::{AssetConditions, AssetConditionsObject, match_object};
 use thread_local::ThreadLocal;
 
+const PLUGIN_NAME: &str = "rspack.LightningCssMinimizerRspackPlugin";
+
 static CSS_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.css(\?.*)?$").expect("Invalid RegExp"));
 
@@ -132,8 +136,13 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     exclude: options.exclude.as_ref(),
   };
 
+  // the assets are minified while `compilation.assets` is borrowed mutably
+  let minify_cache = std::mem::take(&mut compilation.minify_cache_artifact);
+  let output_options = compilation.options.clone();
+  let timings: Mutex<Vec<(String, Duration, bool)>> = Default::default();
+
   let tls: ThreadLocal<ObjectPool> = ThreadLocal::new();
-  compilation
+  let result = compilation
     .assets_mut()
     .par_iter_mut()
     .filter(|(filename, original)| {
@@ -155,9 +164,41 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       }
 
       if let Some(original_source) = original.get_source() {
+        let start = Instant::now();
         let input = original_source.source().into_string_lossy().into_owned();
         let object_pool = tls.get_or(ObjectPool::default);
         let input_source_map = original_source.map(object_pool, &MapOptions::default());
+        let css_unused_idents = if self.options.remove_unused_local_idents {
+          original.info.css_unused_idents.take()
+        } else {
+          None
+        };
+
+        let cache_key = {
+          let mut hasher = RspackHash::from(&output_options.output);
+          PLUGIN_NAME.hash(&mut hasher);
+          options.hash(&mut hasher);
+          filename.hash(&mut hasher);
+          input.hash(&mut hasher);
+          if let Some(input_source_map) = &input_source_map {
+            input_source_map.to_json().to_rspack_result()?.hash(&mut hasher);
+          }
+          if let Some(css_unused_idents) = &css_unused_idents {
+            let mut css_unused_idents = css_unused_idents.iter().collect::<Vec<_>>();
+            css_unused_idents.sort_unstable();
+            css_unused_idents.hash(&mut hasher);
+          }
+          hasher.digest(&output_options.output.hash_digest)
+        };
+        if let Some(result) = minify_cache.get(&cache_key) {
+          original.set_source(Some(result.source));
+          original.get_info_mut().minimized.replace(true);
+          timings
+            .lock()
+            .expect("timings lock failed")
+            .push((filename.to_string(), start.elapsed(), true));
+          return Ok(());
+        }
 
         let mut parser_flags = ParserFlags::empty();
         parser_flags.set(
@@ -208,9 +249,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
               .unwrap_or(Features::empty()),
           };
           let mut unused_symbols = HashSet::from_iter(minimizer_options.unused_symbols.clone());
-          if self.options.remove_unused_local_idents
-            && let Some(css_unused_idents) = original.info.css_unused_idents.take()
-          {
+          if let Some(css_unused_idents) = css_unused_idents {
             unused_symbols.extend(css_unused_idents);
           }
           stylesheet
@@ -279,20 +318,50 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           RawStringSource::from(result.code).boxed()
         };
 
+        minify_cache.set(
+          &cache_key,
+          MinifyResult {
+            source: minimized_source.clone(),
+            extracted_comments: None,
+          },
+        );
         original.set_source(Some(minimized_source));
+        timings
+          .lock()
+          .expect("timings lock failed")
+          .push((filename.to_string(), start.elapsed(), false));
       }
       original.get_info_mut().minimized.replace(true);
       Ok(())
-    }).map_err(MinifyError)?;
+    });
+  compilation.minify_cache_artifact = minify_cache;
+  result.map_err(MinifyError)?;
 
   compilation.extend_diagnostics(all_warnings.into_inner().expect("should lock"));
 
+  let logger = compilation.get_logger(PLUGIN_NAME);
+  let mut cache_count = logger.cache("minify cache");
+  let mut timings = timings.into_inner().expect("timings lock failed");
+  timings.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  for (filename, duration, cached) in timings {
+    if cached {
+      cache_count.hit();
+      logger.log(format!(
+        "{filename}: restored from minify cache in {duration:?}"
+      ));
+    } else {
+      cache_count.miss();
+      logger.log(format!("{filename}: minified in {duration:?}"));
+    }
+  }
+  logger.cache_end(cache_count);
+
   Ok(())
 }
 
 impl Plugin for LightningCssMinimizerRspackPlugin {
   fn name(&self) -> &'static str {
-    "rspack.LightningCssMinimizerRspackPlugin"
+    PLUGIN_NAME
   }
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
@@ -73,8 +73,9 @@ const GLOBALS_ON_REQUIRE: &[RuntimeGlobals] = &[
   RuntimeGlobals::SCRIPT_NONCE,
   RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
   RuntimeGlobals::ASYNC_MODULE,
-  // RuntimeGlobals::WASM_INSTANCES,
+  RuntimeGlobals::WASM_INSTANCES,
   RuntimeGlobals::INSTANTIATE_WASM,
+  RuntimeGlobals::COMPILE_WASM,
   RuntimeGlobals::SHARE_SCOPE_MAP,
   RuntimeGlobals::INITIALIZE_SHARING,
   RuntimeGlobals::LOAD_SCRIPT,
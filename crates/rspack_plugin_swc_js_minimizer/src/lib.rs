@@ -2,7 +2,12 @@ use std::{
   collections::HashMap,
   hash::Hash,
   path::Path,
-  sync::{LazyLock, Mutex, mpsc},
+  sync::{
+    Arc, LazyLock, Mutex,
+    atomic::{AtomicBool, Ordering},
+    mpsc,
+  },
+  time::{Duration, Instant},
 };
 
 use cow_utils::CowUtils;
@@ -11,23 +16,30 @@ use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
   AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
-  CompilerCompilation, Plugin,
+  CompilerCompilation, Logger, MinifyResult, Plugin,
   diagnostics::MinifyError,
   rspack_sources::{
     ConcatSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt, SourceMapSource,
     SourceMapSourceOptions,
   },
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt, error};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
-use rspack_javascript_compiler::JavaScriptCompiler;
+use rspack_javascript_compiler::{JavaScriptCompiler, minify::NameCache};
+use rspack_paths::Utf8PathBuf;
 use rspack_plugin_javascript::{ExtractedCommentsInfo, JavascriptModulesChunkHash, JsPlugin};
-use rspack_util::asset_condition::AssetConditions;
+use rspack_util::{asset_condition::AssetConditions, swc::minify_file_comments};
 use swc_config::types::BoolOrDataConfig;
 use swc_core::{
-  base::config::JsMinifyFormatOptions,
-  common::comments::{CommentKind, SingleThreadedComments},
+  base::{
+    BoolOr,
+    config::{JsMinifyCommentOption, JsMinifyFormatOptions},
+  },
+  common::{
+    BytePos,
+    comments::{Comment, CommentKind, Comments, SingleThreadedComments},
+  },
 };
 pub use swc_ecma_minifier::option::{
   MangleOptions,
@@ -47,6 +59,34 @@ pub struct PluginOptions {
   pub exclude: Option<AssetConditions>,
   pub extract_comments: Option<ExtractComments>,
   pub minimizer_options: MinimizerOptions,
+  /// Share the mangled names across all the assets, and across builds if a path is provided.
+  pub name_cache: Option<NameCacheOptions>,
+  /// How to handle the legal comments, `extract_comments` is ignored if it's set.
+  pub legal_comments: Option<LegalComments>,
+}
+
+/// The modes of handling the legal comments, like the `legalComments` option of esbuild.
+///
+/// Legal comments are the comments that start with `//!` or `/*!`, or contain `@license` or
+/// `@preserve`, they are deduplicated in each asset except for the `Inline` mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LegalComments {
+  /// Preserve the legal comments where they are.
+  Inline,
+  /// Move the legal comments to the end of the asset.
+  Eof,
+  /// Move the legal comments to a `.LEGAL.txt` file, and link to it with a comment at the end
+  /// of the asset.
+  Linked,
+  /// Move the legal comments to a `.LEGAL.txt` file without linking to it.
+  External,
+}
+
+#[derive(Debug, Hash)]
+pub struct NameCacheOptions {
+  /// The json file to read the name cache from before the first minification and write it to
+  /// after each minification, relative paths are resolved against the context.
+  pub path: Option<Utf8PathBuf>,
 }
 
 #[derive(Debug, Default)]
@@ -113,6 +153,39 @@ impl Hash for ExtractComments {
   }
 }
 
+fn is_legal_comment(comment: &Comment) -> bool {
+  comment.text.starts_with('!')
+    || comment.text.contains("@license")
+    || comment.text.contains("@preserve")
+}
+
+fn comment_to_string(comment: &Comment) -> String {
+  match comment.kind {
+    CommentKind::Line => format!("//{}", comment.text),
+    CommentKind::Block => format!("/*{}*/", comment.text),
+  }
+}
+
+/// Remove the legal comments from the comments, and return them in the order of their positions.
+fn take_legal_comments(comments: &SingleThreadedComments) -> Vec<(BytePos, bool, Comment)> {
+  let mut legal_comments = vec![];
+  let (mut leading, mut trailing) = comments.borrow_all_mut();
+  for (is_leading, comments) in [(true, &mut *leading), (false, &mut *trailing)] {
+    for (pos, comments) in comments.iter_mut() {
+      comments.retain(|comment| {
+        if is_legal_comment(comment) {
+          legal_comments.push((*pos, is_leading, comment.clone()));
+          false
+        } else {
+          true
+        }
+      });
+    }
+  }
+  legal_comments.sort_by_key(|(pos, ..)| *pos);
+  legal_comments
+}
+
 #[derive(Debug)]
 struct NormalizedExtractComments<'a> {
   filename: String,
@@ -124,11 +197,19 @@ struct NormalizedExtractComments<'a> {
 #[derive(Debug)]
 pub struct SwcJsMinimizerRspackPlugin {
   options: PluginOptions,
+  name_cache: Option<Arc<NameCache>>,
+  name_cache_loaded: AtomicBool,
 }
 
 impl SwcJsMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    let name_cache = options.name_cache.as_ref().map(|_| Default::default());
+    Self::new_inner(options, name_cache, AtomicBool::new(false))
+  }
+
+  fn name_cache_path(&self, compilation: &Compilation) -> Option<Utf8PathBuf> {
+    let path = self.options.name_cache.as_ref()?.path.as_ref()?;
+    Some(compilation.options.context.as_path().join(path))
   }
 }
 
@@ -160,6 +241,20 @@ async fn js_chunk_hash(
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let options = &self.options;
   let minimizer_options = &self.options.minimizer_options;
+  let name_cache = self.name_cache.as_ref();
+  let name_cache_path = self.name_cache_path(compilation);
+
+  if let Some(name_cache) = name_cache
+    && let Some(path) = &name_cache_path
+    && !self.name_cache_loaded.swap(true, Ordering::Relaxed)
+  {
+    // the name cache file does not exist in the first build
+    if let Ok(content) = compilation.input_filesystem.read_to_string(path).await {
+      name_cache
+        .load_json(&content)
+        .map_err(|e| error!("Failed to parse name cache file {path}: {e}"))?;
+    }
+  }
 
   let (tx, rx) = mpsc::channel::<Vec<Diagnostic>>();
   // collect all extracted comments info
@@ -172,232 +267,446 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       Regex::new(condition)
         .unwrap_or_else(|_| panic!("`{condition}` is invalid extractComments condition"))
     });
+  let extract_comments = options
+    .extract_comments
+    .as_ref()
+    .filter(|_| options.legal_comments.is_none());
   let enter_span = tracing::Span::current();
+  // the assets are minified while `compilation.assets` is borrowed mutably
+  let minify_cache = std::mem::take(&mut compilation.minify_cache_artifact);
+  let output_options = compilation.options.clone();
+  let timings: Mutex<Vec<(String, Duration, bool)>> = Default::default();
 
   let tls: ThreadLocal<ObjectPool> = ThreadLocal::new();
-  compilation
+  let mut assets = compilation
     .assets_mut()
-    .par_iter_mut()
+    .iter_mut()
     .filter(|(filename, original)| {
-      // propagate span in rayon to keep parent relation
       let is_matched = match_object(options, filename);
 
       if !is_matched || original.get_info().minimized.unwrap_or(false) {
-        return false
+        return false;
       }
 
       true
     })
-    .try_for_each_with(tx,|tx, (filename, original)| -> Result<()>  {
-      let _guard = enter_span.enter();
-      let filename = filename.split('?').next().expect("Should have filename");
-      if let Some(original_source) = original.get_source() {
-        let input = original_source.source().into_string_lossy().into_owned();
-        let object_pool = tls.get_or(ObjectPool::default);
-        let input_source_map = original_source.map(object_pool, &MapOptions::default());
-
-        let is_module = if let Some(module) = minimizer_options.module {
-          Some(module)
-        } else if let Some(module) = original.info.javascript_module {
-          Some(module)
-        } else if filename.ends_with(".mjs") {
-          Some(true)
-        } else if filename.ends_with(".cjs") {
-          Some(false)
-        } else {
-          None
-        };
-
-        let js_minify_options = rspack_javascript_compiler::minify::JsMinifyOptions {
-          minify: minimizer_options.minify.unwrap_or(true),
-          compress: minimizer_options.compress.clone(),
-          mangle: minimizer_options.mangle.clone(),
-          format: minimizer_options.format.clone(),
-          ecma: minimizer_options.ecma.clone(),
-          source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
-          inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
-          module: is_module,
-          ..Default::default()
-          };
-        let extract_comments_option = options.extract_comments.as_ref().map(|extract_comments| {
-          let comments_filename = format!("{filename}.LICENSE.txt");
-          let banner = match &extract_comments.banner {
-            OptionWrapper::Default => {
-              let dir = Path::new(filename).parent().expect("should has parent");
-              let raw = Path::new(&comments_filename).strip_prefix(dir).expect("should has common prefix").to_string_lossy();
-              let relative = raw.cow_replace('\\', "/");
-              Some(format!("/*! For license information please see {relative} */"))
-            },
-            OptionWrapper::Disabled => None,
-            OptionWrapper::Custom(value) => Some(format!("/*! {value} */"))
+    .collect::<Vec<_>>();
+  let minify_asset = |tx: &mut mpsc::Sender<Vec<Diagnostic>>,
+                      (filename, original): (&String, &mut CompilationAsset)|
+   -> Result<()> {
+    // propagate span in rayon to keep parent relation
+    let _guard = enter_span.enter();
+    let filename = filename.split('?').next().expect("Should have filename");
+    if let Some(original_source) = original.get_source() {
+      let start = Instant::now();
+      let input = original_source.source().into_string_lossy().into_owned();
+      let object_pool = tls.get_or(ObjectPool::default);
+      let input_source_map = original_source.map(object_pool, &MapOptions::default());
+
+      let is_module = if let Some(module) = minimizer_options.module {
+        Some(module)
+      } else if let Some(module) = original.info.javascript_module {
+        Some(module)
+      } else if filename.ends_with(".mjs") {
+        Some(true)
+      } else if filename.ends_with(".cjs") {
+        Some(false)
+      } else {
+        None
+      };
+
+      let cache_key = {
+        let mut hasher = RspackHash::from(&output_options.output);
+        PLUGIN_NAME.hash(&mut hasher);
+        options.hash(&mut hasher);
+        serde_json::to_string(&minimizer_options.ecma)
+          .expect("Should be able to serialize")
+          .hash(&mut hasher);
+        minimizer_options.minify.hash(&mut hasher);
+        is_module.hash(&mut hasher);
+        filename.hash(&mut hasher);
+        input.hash(&mut hasher);
+        if let Some(input_source_map) = &input_source_map {
+          input_source_map
+            .to_json()
+            .to_rspack_result()?
+            .hash(&mut hasher);
+        }
+        hasher.digest(&output_options.output.hash_digest)
+      };
+      // the mangled names depend on the name cache, which is not covered by the cache key
+      if name_cache.is_none()
+        && let Some(result) = minify_cache.get(&cache_key)
+      {
+        if let Some(extracted_comments) = result.extracted_comments {
+          let comments_file_name = if options.legal_comments.is_some() {
+            format!("{filename}.LEGAL.txt")
+          } else {
+            format!("{filename}.LICENSE.txt")
           };
-          NormalizedExtractComments {
-            filename: comments_filename,
-            condition: extract_comments_condition.as_ref().expect("must exists"),
-            banner
+          all_extracted_comments
+            .lock()
+            .expect("all_extract_comments lock failed")
+            .insert(
+              filename.to_string(),
+              ExtractedCommentsInfo {
+                source: RawStringSource::from(extracted_comments).boxed(),
+                comments_file_name,
+              },
+            );
+        }
+        original.set_source(Some(result.source));
+        original.get_info_mut().minimized.replace(true);
+        timings.lock().expect("timings lock failed").push((
+          filename.to_string(),
+          start.elapsed(),
+          true,
+        ));
+        return Ok(());
+      }
+
+      let mut format = minimizer_options.format.clone();
+      if options.legal_comments == Some(LegalComments::Inline) {
+        // the comments are minified in `comments_op` to keep the legal comments
+        format.comments = BoolOrDataConfig::from_bool(true);
+      }
+      let js_minify_options = rspack_javascript_compiler::minify::JsMinifyOptions {
+        minify: minimizer_options.minify.unwrap_or(true),
+        compress: minimizer_options.compress.clone(),
+        mangle: minimizer_options.mangle.clone(),
+        format,
+        ecma: minimizer_options.ecma.clone(),
+        source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
+        inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
+        module: is_module,
+        name_cache: name_cache.cloned(),
+        ..Default::default()
+      };
+      let extract_comments_option = extract_comments.map(|extract_comments| {
+        let comments_filename = format!("{filename}.LICENSE.txt");
+        let banner = match &extract_comments.banner {
+          OptionWrapper::Default => {
+            let dir = Path::new(filename).parent().expect("should has parent");
+            let raw = Path::new(&comments_filename)
+              .strip_prefix(dir)
+              .expect("should has common prefix")
+              .to_string_lossy();
+            let relative = raw.cow_replace('\\', "/");
+            Some(format!(
+              "/*! For license information please see {relative} */"
+            ))
           }
-        });
-
-        let javascript_compiler = JavaScriptCompiler::new();
-        let comments_op = |comments: &SingleThreadedComments| {
-          if let Some(ref extract_comments) = extract_comments_option {
-            let mut extracted_comments = vec![];
-            // add all matched comments to source
-
-            let (leading_trivial, trailing_trivial) = comments.borrow_all();
-
-            leading_trivial.iter().for_each(|(_, comments)| {
-              comments.iter().for_each(|c| {
-                if extract_comments.condition.is_match(&c.text) {
-                  let comment = match c.kind {
-                    CommentKind::Line => {
-                      format!("//{}", c.text)
-                    }
-                    CommentKind::Block => {
-                      format!("/*{}*/", c.text)
-                    }
-                  };
-                  if !extracted_comments.contains(&comment) {
-                    extracted_comments.push(comment);
+          OptionWrapper::Disabled => None,
+          OptionWrapper::Custom(value) => Some(format!("/*! {value} */")),
+        };
+        NormalizedExtractComments {
+          filename: comments_filename,
+          condition: extract_comments_condition.as_ref().expect("must exists"),
+          banner,
+        }
+      });
+
+      let javascript_compiler = JavaScriptCompiler::new();
+      let mut legal_comments = vec![];
+      let comments_op = |comments: &SingleThreadedComments| {
+        if let Some(mode) = options.legal_comments {
+          let taken = take_legal_comments(comments);
+          if mode == LegalComments::Inline {
+            minify_file_comments(
+              comments,
+              &minimizer_options
+                .format
+                .comments
+                .clone()
+                .into_inner()
+                .unwrap_or(BoolOr::Data(JsMinifyCommentOption::PreserveSomeComments)),
+              minimizer_options.format.preserve_annotations,
+            );
+            for (pos, is_leading, comment) in taken {
+              if is_leading {
+                comments.add_leading(pos, comment);
+              } else {
+                comments.add_trailing(pos, comment);
+              }
+            }
+          } else {
+            for (_, _, comment) in taken {
+              let comment = comment_to_string(&comment);
+              if !legal_comments.contains(&comment) {
+                legal_comments.push(comment);
+              }
+            }
+          }
+        }
+        if let Some(ref extract_comments) = extract_comments_option {
+          let mut extracted_comments = vec![];
+          // add all matched comments to source
+
+          let (leading_trivial, trailing_trivial) = comments.borrow_all();
+
+          leading_trivial.iter().for_each(|(_, comments)| {
+            comments.iter().for_each(|c| {
+              if extract_comments.condition.is_match(&c.text) {
+                let comment = match c.kind {
+                  CommentKind::Line => {
+                    format!("//{}", c.text)
                   }
+                  CommentKind::Block => {
+                    format!("/*{}*/", c.text)
+                  }
+                };
+                if !extracted_comments.contains(&comment) {
+                  extracted_comments.push(comment);
                 }
-              });
+              }
             });
-            trailing_trivial.iter().for_each(|(_, comments)| {
-              comments.iter().for_each(|c| {
-                if extract_comments.condition.is_match(&c.text) {
-                  let comment = match c.kind {
-                    CommentKind::Line => {
-                      format!("//{}", c.text)
-                    }
-                    CommentKind::Block => {
-                      format!("/*{}*/", c.text)
-                    }
-                  };
-                  if !extracted_comments.contains(&comment) {
-                    extracted_comments.push(comment);
+          });
+          trailing_trivial.iter().for_each(|(_, comments)| {
+            comments.iter().for_each(|c| {
+              if extract_comments.condition.is_match(&c.text) {
+                let comment = match c.kind {
+                  CommentKind::Line => {
+                    format!("//{}", c.text)
                   }
+                  CommentKind::Block => {
+                    format!("/*{}*/", c.text)
+                  }
+                };
+                if !extracted_comments.contains(&comment) {
+                  extracted_comments.push(comment);
                 }
-              });
+              }
             });
-
-            // if not matched comments, we don't need to emit .License.txt file
-            if !extracted_comments.is_empty() {
-              extracted_comments.sort();
-              all_extracted_comments
-                .lock()
-                .expect("all_extract_comments lock failed")
-                .insert(
-                  filename.to_string(),
-                  ExtractedCommentsInfo {
-                    source: RawStringSource::from(extracted_comments.join("\n\n")).boxed(),
-                    comments_file_name: extract_comments.filename.to_string(),
-                  },
-                );
+          });
+
+          // if not matched comments, we don't need to emit .License.txt file
+          if !extracted_comments.is_empty() {
+            extracted_comments.sort();
+            all_extracted_comments
+              .lock()
+              .expect("all_extract_comments lock failed")
+              .insert(
+                filename.to_string(),
+                ExtractedCommentsInfo {
+                  source: RawStringSource::from(extracted_comments.join("\n\n")).boxed(),
+                  comments_file_name: extract_comments.filename.to_string(),
+                },
+              );
+          }
+        }
+      };
+
+      let mut output = match javascript_compiler.minify(
+        swc_core::common::FileName::Custom(filename.to_string()),
+        input,
+        js_minify_options,
+        Some(comments_op),
+      ) {
+        Ok(r) => r,
+        Err(e) => {
+          let errors = e
+            .into_inner()
+            .into_iter()
+            .map(|err| {
+              let mut d = Diagnostic::from(MinifyError(err));
+              d.file = Some(filename.into());
+              d
+            })
+            .collect::<Vec<_>>();
+          tx.send(errors)?;
+          return Ok(());
+        }
+      };
+
+      let banner = if all_extracted_comments
+        .lock()
+        .expect("all_extract_comments lock failed")
+        .contains_key(filename)
+      {
+        extract_comments_option.and_then(|option| option.banner)
+      } else {
+        None
+      };
+
+      let source = match banner {
+        Some(banner) => {
+          // There are two cases with banner:
+          // 1. There's no shebang, we just prepend the banner to the code.
+          // 2. There's a shebang, we prepend the shebang, then the banner, then the code.
+
+          let mut shebang = None;
+          if output.code.starts_with("#!") {
+            if let Some(line_pos) = output.code.find('\n') {
+              shebang = Some(output.code[0..line_pos + 1].to_string());
+              output.code = output.code[line_pos + 1..].to_string();
+            } else {
+              // Handle shebang without newline - treat entire content as shebang
+              shebang = Some(output.code.clone());
+              output.code = String::new();
             }
           }
-        };
 
-        let mut output = match javascript_compiler.minify(
-          swc_core::common::FileName::Custom(filename.to_string()),
-          input,
-          js_minify_options,
-          Some(comments_op),
-        ) {
-            Ok(r) => r,
-            Err(e) => {
-              let errors = e.into_inner().into_iter().map(|err| {
-                let mut d = Diagnostic::from(MinifyError(err));
-                d.file = Some(filename.into());
-                d
-              }).collect::<Vec<_>>();
-              tx.send(errors)?;
-              return Ok(())
-            },
-        };
-
-        let banner = if all_extracted_comments
-          .lock()
-          .expect("all_extract_comments lock failed")
-          .contains_key(filename) {
-            extract_comments_option.and_then(|option| option.banner)
+          let source = if let Some(source_map) = output.map {
+            SourceMapSource::new(SourceMapSourceOptions {
+              value: output.code,
+              name: filename,
+              source_map,
+              original_source: None,
+              inner_source_map: input_source_map,
+              remove_original_source: true,
+            })
+            .boxed()
           } else {
-            None
+            RawStringSource::from(output.code).boxed()
           };
 
-        let source = match banner {
-            Some(banner) => {
-              // There are two cases with banner:
-              // 1. There's no shebang, we just prepend the banner to the code.
-              // 2. There's a shebang, we prepend the shebang, then the banner, then the code.
-
-              let mut shebang = None;
-              if output.code.starts_with("#!") {
-                if let Some(line_pos) = output.code.find('\n') {
-                  shebang = Some(output.code[0..line_pos + 1].to_string());
-                  output.code = output.code[line_pos + 1..].to_string();
-                } else {
-                  // Handle shebang without newline - treat entire content as shebang
-                  shebang = Some(output.code.clone());
-                  output.code = String::new();
-                }
-              }
-
-              let source = if let Some(source_map) = output.map {
-                SourceMapSource::new(SourceMapSourceOptions {
-                  value: output.code,
-                  name: filename,
-                  source_map,
-                  original_source: None,
-                  inner_source_map: input_source_map,
-                  remove_original_source: true,
-                })
-                .boxed()
-              } else {
-                RawStringSource::from(output.code).boxed()
-              };
-
-              if let Some(shebang) = shebang {
-                ConcatSource::new([
-                  RawStringSource::from(shebang).boxed(),
-                  RawStringSource::from(banner).boxed(),
-                  RawStringSource::from_static("\n").boxed(),
-                  source
-                ]).boxed()
-              } else {
-                ConcatSource::new([
-                  RawStringSource::from(banner).boxed(),
-                  RawStringSource::from_static("\n").boxed(),
-                  source
-                ]).boxed()
-              }
-            },
-            None => {
-              // If there's no banner, we don't need to handle `output.code` at all.
-              if let Some(source_map) = output.map {
-                SourceMapSource::new(SourceMapSourceOptions {
-                  value: output.code,
-                  name: filename,
-                  source_map,
-                  original_source: None,
-                  inner_source_map: input_source_map,
-                  remove_original_source: true,
-                })
-                .boxed()
-              } else {
-                RawStringSource::from(output.code).boxed()
-              }
-            },
+          if let Some(shebang) = shebang {
+            ConcatSource::new([
+              RawStringSource::from(shebang).boxed(),
+              RawStringSource::from(banner).boxed(),
+              RawStringSource::from_static("\n").boxed(),
+              source,
+            ])
+            .boxed()
+          } else {
+            ConcatSource::new([
+              RawStringSource::from(banner).boxed(),
+              RawStringSource::from_static("\n").boxed(),
+              source,
+            ])
+            .boxed()
+          }
+        }
+        None => {
+          // If there's no banner, we don't need to handle `output.code` at all.
+          if let Some(source_map) = output.map {
+            SourceMapSource::new(SourceMapSourceOptions {
+              value: output.code,
+              name: filename,
+              source_map,
+              original_source: None,
+              inner_source_map: input_source_map,
+              remove_original_source: true,
+            })
+            .boxed()
+          } else {
+            RawStringSource::from(output.code).boxed()
+          }
+        }
+      };
+
+      let source = if legal_comments.is_empty() {
+        source
+      } else {
+        let legal_file_name = format!("{filename}.LEGAL.txt");
+        let eof = match options.legal_comments {
+          Some(LegalComments::Eof) => Some(legal_comments.join("\n")),
+          Some(LegalComments::Linked) => {
+            let relative = Path::new(&legal_file_name)
+              .file_name()
+              .expect("should have file name")
+              .to_string_lossy();
+            Some(format!(
+              "/*! For license information please see {relative} */"
+            ))
+          }
+          _ => None,
         };
-
-        original.set_source(Some(source));
-        original.get_info_mut().minimized.replace(true);
+        if matches!(
+          options.legal_comments,
+          Some(LegalComments::Linked | LegalComments::External)
+        ) {
+          all_extracted_comments
+            .lock()
+            .expect("all_extract_comments lock failed")
+            .insert(
+              filename.to_string(),
+              ExtractedCommentsInfo {
+                source: RawStringSource::from(legal_comments.join("\n\n")).boxed(),
+                comments_file_name: legal_file_name,
+              },
+            );
+        }
+        match eof {
+          Some(eof) => ConcatSource::new([
+            source,
+            RawStringSource::from_static("\n").boxed(),
+            RawStringSource::from(eof).boxed(),
+          ])
+          .boxed(),
+          None => source,
+        }
+      };
+
+      let extracted_comments = all_extracted_comments
+        .lock()
+        .expect("all_extract_comments lock failed")
+        .get(filename)
+        .map(|info| info.source.source().into_string_lossy().into_owned());
+      if name_cache.is_none() {
+        minify_cache.set(
+          &cache_key,
+          MinifyResult {
+            source: source.clone(),
+            extracted_comments,
+          },
+        );
       }
+      original.set_source(Some(source));
+      original.get_info_mut().minimized.replace(true);
+      timings.lock().expect("timings lock failed").push((
+        filename.to_string(),
+        start.elapsed(),
+        false,
+      ));
+    }
 
-      Ok(())
-  })?;
+    Ok(())
+  };
+  let result = if name_cache.is_some() {
+    // the names are mangled in order with the name cache, minify the assets one by one
+    // in a stable order to get the same names in each build
+    assets.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    let mut tx = tx;
+    assets
+      .into_iter()
+      .try_for_each(|asset| minify_asset(&mut tx, asset))
+  } else {
+    assets.into_par_iter().try_for_each_with(tx, minify_asset)
+  };
+  compilation.minify_cache_artifact = minify_cache;
+  result?;
   compilation.extend_diagnostics(rx.into_iter().flatten().collect::<Vec<_>>());
 
+  if let Some(name_cache) = name_cache
+    && let Some(path) = &name_cache_path
+  {
+    let intermediate_filesystem = compilation.intermediate_filesystem.as_ref();
+    if let Some(dir) = path.parent() {
+      intermediate_filesystem.create_dir_all(dir).await?;
+    }
+    intermediate_filesystem
+      .write(path, name_cache.to_json().as_bytes())
+      .await?;
+  }
+
+  let logger = compilation.get_logger(PLUGIN_NAME);
+  let mut cache_count = logger.cache("minify cache");
+  let mut timings = timings.into_inner().expect("timings lock failed");
+  timings.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+  for (filename, duration, cached) in timings {
+    if cached {
+      cache_count.hit();
+      logger.log(format!(
+        "{filename}: restored from minify cache in {duration:?}"
+      ));
+    } else {
+      cache_count.miss();
+      logger.log(format!("{filename}: minified in {duration:?}"));
+    }
+  }
+  logger.cache_end(cache_count);
+
   // write all extracted comments to assets
   all_extracted_comments
     .lock()
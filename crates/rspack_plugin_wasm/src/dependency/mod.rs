@@ -1,2 +1,8 @@
+mod wasm_exports_dependency;
 mod wasm_import_dependency;
+mod wasm_source_phase_import_dependency;
+pub use wasm_exports_dependency::{WasmExport, WasmExportType, WasmExportsDependency};
 pub use wasm_import_dependency::WasmImportDependency;
+pub use wasm_source_phase_import_dependency::{
+  WasmSourcePhaseImportDependency, WasmSourcePhaseImportDependencyTemplate,
+};
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
+use rspack_core::{
+  AffectType, AsContextDependency, AsDependencyCodeGeneration, AsModuleDependency, Dependency,
+  DependencyId, DependencyType, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
+  ModuleGraphCacheArtifact,
+};
+use swc_core::ecma::atoms::Atom;
+
+/// The type of a wasm export, read from the type, function and global sections.
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WasmExportType {
+  Function {
+    params: Vec<String>,
+    results: Vec<String>,
+  },
+  Global {
+    content_type: String,
+    mutable: bool,
+  },
+  Memory,
+  Table,
+  Tag,
+}
+
+impl fmt::Display for WasmExportType {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Function { params, results } => {
+        write!(f, "function ({})", params.join(", "))?;
+        match results.as_slice() {
+          [] => Ok(()),
+          [result] => write!(f, " => {result}"),
+          results => write!(f, " => [{}]", results.join(", ")),
+        }
+      }
+      Self::Global {
+        content_type,
+        mutable,
+      } => {
+        if *mutable {
+          write!(f, "mutable global {content_type}")
+        } else {
+          write!(f, "global {content_type}")
+        }
+      }
+      Self::Memory => write!(f, "memory"),
+      Self::Table => write!(f, "table"),
+      Self::Tag => write!(f, "tag"),
+    }
+  }
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct WasmExport {
+  #[cacheable(with=AsPreset)]
+  pub name: Atom,
+  pub ty: WasmExportType,
+}
+
+/// Provides the typed exports of a wasm module, the export names can't be mangled
+/// since they are defined by the wasm binary.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct WasmExportsDependency {
+  id: DependencyId,
+  exports: Vec<WasmExport>,
+}
+
+impl WasmExportsDependency {
+  pub fn new(exports: Vec<WasmExport>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      exports,
+    }
+  }
+
+  pub fn exports(&self) -> &[WasmExport] {
+    &self.exports
+  }
+
+  pub fn get_export(&self, name: &Atom) -> Option<&WasmExport> {
+    self.exports.iter().find(|export| &export.name == name)
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for WasmExportsDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::WasmExports
+  }
+
+  fn get_exports(
+    &self,
+    _mg: &ModuleGraph,
+    _mg_cache: &ModuleGraphCacheArtifact,
+  ) -> Option<ExportsSpec> {
+    Some(ExportsSpec {
+      exports: ExportsOfExportsSpec::Names(
+        self
+          .exports
+          .iter()
+          .map(|export| ExportNameOrSpec::String(export.name.clone()))
+          .collect(),
+      ),
+      can_mangle: Some(false),
+      ..Default::default()
+    })
+  }
+
+  fn could_affect_referencing_module(&self) -> AffectType {
+    AffectType::True
+  }
+}
+
+impl AsDependencyCodeGeneration for WasmExportsDependency {}
+impl AsModuleDependency for WasmExportsDependency {}
+impl AsContextDependency for WasmExportsDependency {}
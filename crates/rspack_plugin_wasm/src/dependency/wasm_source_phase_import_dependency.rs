@@ -0,0 +1,183 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsOption, AsPreset},
+};
+use rspack_core::{
+  AsContextDependency, ChunkGraph, Dependency, DependencyCategory, DependencyCodeGeneration,
+  DependencyId, DependencyRange, DependencyTemplate, DependencyTemplateType, DependencyType,
+  ExtendedReferencedExport, FactorizeInfo, ModuleDependency, ModuleGraph, ModuleGraphCacheArtifact,
+  ModuleType, RuntimeGlobals, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::Diagnostic;
+use rspack_util::json_stringify;
+use swc_core::ecma::atoms::Atom;
+
+/// The dependency of `import source name from "./x.wasm"` and `import.source("./x.wasm")`,
+/// which get the compiled `WebAssembly.Module` of the wasm module without instantiating it.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct WasmSourcePhaseImportDependency {
+  id: DependencyId,
+  #[cacheable(with=AsPreset)]
+  request: Atom,
+  /// The local name of the static import, `None` for `import.source()`.
+  #[cacheable(with=AsOption<AsPreset>)]
+  name: Option<Atom>,
+  range: DependencyRange,
+  factorize_info: FactorizeInfo,
+}
+
+impl WasmSourcePhaseImportDependency {
+  pub fn new(request: Atom, name: Option<Atom>, range: DependencyRange) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      name,
+      range,
+      factorize_info: Default::default(),
+    }
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for WasmSourcePhaseImportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::WasmSourcePhaseImport
+  }
+
+  fn range(&self) -> Option<DependencyRange> {
+    Some(self.range)
+  }
+
+  fn get_diagnostics(
+    &self,
+    module_graph: &ModuleGraph,
+    _module_graph_cache: &ModuleGraphCacheArtifact,
+  ) -> Option<Vec<Diagnostic>> {
+    let module = module_graph.get_module_by_dependency_id(&self.id)?;
+    if module.module_type() == &ModuleType::WasmAsync {
+      return None;
+    }
+    let mut diagnostic = Diagnostic::error(
+      "Source Phase Import Error".into(),
+      format!(
+        "Source phase imports is only supported for WebAssembly modules, but '{}' is a '{}' module.",
+        self.request,
+        module.module_type()
+      ),
+    );
+    diagnostic.module_identifier = module_graph.get_parent_module(&self.id).copied();
+    Some(vec![diagnostic])
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _module_graph_cache: &ModuleGraphCacheArtifact,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    // the source phase doesn't evaluate the module, so none of the exports are used
+    vec![]
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+}
+
+#[cacheable_dyn]
+impl ModuleDependency for WasmSourcePhaseImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn factorize_info(&self) -> &FactorizeInfo {
+    &self.factorize_info
+  }
+
+  fn factorize_info_mut(&mut self) -> &mut FactorizeInfo {
+    &mut self.factorize_info
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyCodeGeneration for WasmSourcePhaseImportDependency {
+  fn dependency_template(&self) -> Option<DependencyTemplateType> {
+    Some(WasmSourcePhaseImportDependencyTemplate::template_type())
+  }
+}
+
+impl AsContextDependency for WasmSourcePhaseImportDependency {}
+
+#[cacheable]
+#[derive(Debug, Clone, Default)]
+pub struct WasmSourcePhaseImportDependencyTemplate;
+
+impl WasmSourcePhaseImportDependencyTemplate {
+  pub fn template_type() -> DependencyTemplateType {
+    DependencyTemplateType::Dependency(DependencyType::WasmSourcePhaseImport)
+  }
+}
+
+impl DependencyTemplate for WasmSourcePhaseImportDependencyTemplate {
+  fn render(
+    &self,
+    dep: &dyn DependencyCodeGeneration,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let dep = dep
+      .as_any()
+      .downcast_ref::<WasmSourcePhaseImportDependency>()
+      .expect(
+        "WasmSourcePhaseImportDependencyTemplate should be used for WasmSourcePhaseImportDependency",
+      );
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+
+    let module_graph = compilation.get_module_graph();
+    let module = module_graph
+      .get_module_by_dependency_id(&dep.id)
+      .filter(|module| module.module_type() == &ModuleType::WasmAsync);
+    let expr = if let Some(module) = module
+      && let Some(hash) = &module.build_info().hash
+      && let Some(module_id) =
+        ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+    {
+      runtime_requirements.insert(RuntimeGlobals::COMPILE_WASM);
+      format!(
+        "{}({}, {})",
+        compilation
+          .runtime_template
+          .render_runtime_globals(&RuntimeGlobals::COMPILE_WASM),
+        json_stringify(module_id),
+        json_stringify(hash.rendered(16))
+      )
+    } else {
+      compilation
+        .runtime_template
+        .missing_module_promise(&dep.request)
+    };
+
+    let content = match &dep.name {
+      Some(name) => format!("var {name} = await {expr};\n"),
+      None => expr,
+    };
+    source.replace(dep.range.start, dep.range.end, &content, None);
+  }
+}
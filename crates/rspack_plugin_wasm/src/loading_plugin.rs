@@ -2,16 +2,152 @@ use rspack_core::{
   BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginExt,
   RuntimeGlobals, RuntimeModuleExt, WasmLoading, WasmLoadingType,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result, error};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::runtime::AsyncWasmLoadingRuntimeModule;
+use crate::runtime::{
+  AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule, get_sync_wasm_modules,
+};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new().boxed(),
-    WasmLoadingType::Universal => UniversalCompileAsyncWasmPlugin::default().boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin::default().boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin::default().boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::default().boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new().boxed());
+    }
+    WasmLoadingType::Universal => {
+      plugins.push(UniversalCompileWasmPlugin::default().boxed());
+      plugins.push(UniversalCompileAsyncWasmPlugin::default().boxed());
+    }
+  }
+}
+
+/// Whether the async chunks of the chunk contain `webassembly/sync` modules to be loaded.
+fn has_sync_wasm_modules(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> bool {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  chunk
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .iter()
+    .any(|chunk| !get_sync_wasm_modules(compilation, chunk).is_empty())
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FetchCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for FetchCompileWasmPlugin)]
+async fn fetch_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    || !has_sync_wasm_modules(compilation, chunk_ukey)
+  {
+    return Ok(None);
+  }
+
+  runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+  runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+  compilation.add_runtime_module(
+    chunk_ukey,
+    WasmChunkLoadingRuntimeModule::new(
+      &compilation.runtime_template,
+      format!(
+        "fetch({} + $PATH)",
+        compilation
+          .runtime_template
+          .render_runtime_globals(&RuntimeGlobals::PUBLIC_PATH)
+      ),
+      true,
+      *chunk_ukey,
+    )
+    .boxed(),
+  )?;
+
+  Ok(None)
+}
+
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compilation_hooks.runtime_requirement_in_tree.tap(
+      fetch_compile_wasm_plugin_runtime_requirements_in_tree::new(self),
+    );
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct ReadFileCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileWasmPlugin)]
+async fn read_file_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    || !has_sync_wasm_modules(compilation, chunk_ukey)
+  {
+    return Ok(None);
+  }
+
+  let import_enabled = compilation.options.output.module
+    && compilation
+      .options
+      .output
+      .environment
+      .dynamic_import
+      .unwrap_or_default();
+
+  runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+  compilation.add_runtime_module(
+    chunk_ukey,
+    WasmChunkLoadingRuntimeModule::new(
+      &compilation.runtime_template,
+      if import_enabled {
+        include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+      } else {
+        include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+      },
+      false,
+      *chunk_ukey,
+    )
+    .boxed(),
+  )?;
+
+  Ok(None)
+}
+
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(read_file_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
   }
 }
 
@@ -28,7 +164,9 @@ async fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if !runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if !runtime_requirements
+    .intersects(RuntimeGlobals::INSTANTIATE_WASM | RuntimeGlobals::COMPILE_WASM)
+  {
     return Ok(None);
   }
 
@@ -85,7 +223,9 @@ async fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   _runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if !runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if !runtime_requirements
+    .intersects(RuntimeGlobals::INSTANTIATE_WASM | RuntimeGlobals::COMPILE_WASM)
+  {
     return Ok(None);
   }
 
@@ -129,6 +269,67 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
   }
 }
 
+/// The universal wasm loading has no loader for `webassembly/sync` modules,
+/// so report an error instead of emitting chunks which can't be loaded.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct UniversalCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for UniversalCompileWasmPlugin)]
+async fn universal_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  _runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    || !has_sync_wasm_modules(compilation, chunk_ukey)
+  {
+    return Ok(None);
+  }
+
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let wasm_loading = chunk
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.wasm_loading.clone())
+    .unwrap_or_else(|| compilation.options.output.wasm_loading.clone());
+
+  if !matches!(
+    wasm_loading,
+    WasmLoading::Enable(WasmLoadingType::Universal)
+  ) {
+    return Ok(None);
+  }
+
+  let mut error = error!(
+    "The universal wasm loading doesn't support synchronous WebAssembly modules, which are used by the async chunks of chunk {}.",
+    chunk.expect_id()
+  );
+  error.code = Some("UnsupportedWasmLoading".into());
+  error.help = Some(
+    "Use `experiments.asyncWebAssembly` instead, or set `output.wasmLoading` to \"fetch\" or \"async-node\".".into(),
+  );
+  compilation.push_diagnostic(Diagnostic::from(error));
+
+  Ok(None)
+}
+
+impl Plugin for UniversalCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "UniversalCompileWasmPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(universal_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct UniversalCompileAsyncWasmPlugin;
@@ -142,7 +343,9 @@ async fn universal_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   _runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if !runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if !runtime_requirements
+    .intersects(RuntimeGlobals::INSTANTIATE_WASM | RuntimeGlobals::COMPILE_WASM)
+  {
     return Ok(None);
   }
 
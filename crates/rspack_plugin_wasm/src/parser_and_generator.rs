@@ -6,15 +6,17 @@ use rspack_core::{
   BoxDependency, BuildMetaExportsType, CodeGenerationData, Dependency, DependencyId,
   DependencyType, GenerateContext, ImportPhase, Module, ModuleDependency, ModuleGraph,
   ModuleIdentifier, ModuleInitFragments, ParseContext, ParseResult, ParserAndGenerator,
-  RuntimeGlobals, SourceType, StaticExportsDependency, StaticExportsSpec, TemplateContext,
+  RuntimeGlobals, SourceType, TemplateContext,
   rspack_sources::{BoxSource, RawStringSource, Source, SourceExt},
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_util::{itoa, json_stringify};
 use swc_core::atoms::Atom;
-use wasmparser::{Import, Parser, Payload};
+use wasmparser::{
+  CompositeInnerType, ExternalKind, FuncType, GlobalType, Import, Parser, Payload, TypeRef,
+};
 
-use crate::dependency::WasmImportDependency;
+use crate::dependency::{WasmExport, WasmExportType, WasmExportsDependency, WasmImportDependency};
 
 #[cacheable]
 #[derive(Debug)]
@@ -29,6 +31,143 @@ struct DepModule<'a> {
   deps: Vec<(DependencyId, Atom)>,
 }
 
+/// Collects the imports and the typed exports of a wasm module as dependencies.
+pub(crate) fn parse_wasm(source: &BoxSource) -> (Vec<BoxDependency>, Vec<Diagnostic>) {
+  let mut exports = Vec::with_capacity(1);
+  let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+  let mut diagnostic = Vec::with_capacity(1);
+
+  // the index spaces used to resolve the types of the exports, the imported
+  // items come first and the sections are ordered before the export section
+  let mut func_types: Vec<Option<FuncType>> = vec![];
+  let mut functions: Vec<u32> = vec![];
+  let mut globals: Vec<GlobalType> = vec![];
+
+  for payload in Parser::new(0).parse_all(&source.buffer()) {
+    match payload {
+      Ok(payload) => match payload {
+        Payload::TypeSection(s) => {
+          for rec_group in s {
+            match rec_group {
+              Ok(rec_group) => {
+                func_types.extend(rec_group.into_types().map(|sub_type| {
+                  match sub_type.composite_type.inner {
+                    CompositeInnerType::Func(func_type) => Some(func_type),
+                    _ => None,
+                  }
+                }));
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Type Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        Payload::FunctionSection(s) => {
+          for type_index in s {
+            match type_index {
+              Ok(type_index) => functions.push(type_index),
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Function Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        Payload::GlobalSection(s) => {
+          for global in s {
+            match global {
+              Ok(global) => globals.push(global.ty),
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Global Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        Payload::ExportSection(s) => {
+          for export in s {
+            match export {
+              Ok(export) => {
+                let index = export.index as usize;
+                let ty = match export.kind {
+                  ExternalKind::Func => {
+                    let func_type = functions
+                      .get(index)
+                      .and_then(|type_index| func_types.get(*type_index as usize))
+                      .and_then(|func_type| func_type.as_ref());
+                    WasmExportType::Function {
+                      params: func_type.map_or_else(Vec::new, |func_type| {
+                        func_type.params().iter().map(|t| t.to_string()).collect()
+                      }),
+                      results: func_type.map_or_else(Vec::new, |func_type| {
+                        func_type.results().iter().map(|t| t.to_string()).collect()
+                      }),
+                    }
+                  }
+                  ExternalKind::Global => {
+                    let global = globals.get(index);
+                    WasmExportType::Global {
+                      content_type: global
+                        .map(|global| global.content_type.to_string())
+                        .unwrap_or_default(),
+                      mutable: global.is_some_and(|global| global.mutable),
+                    }
+                  }
+                  ExternalKind::Memory => WasmExportType::Memory,
+                  ExternalKind::Table => WasmExportType::Table,
+                  ExternalKind::Tag => WasmExportType::Tag,
+                };
+                exports.push(WasmExport {
+                  name: export.name.into(),
+                  ty,
+                });
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Export Parse Error".into(),
+                err.to_string(),
+              )),
+            };
+          }
+        }
+        Payload::ImportSection(s) => {
+          for import in s {
+            match import {
+              Ok(Import { module, name, ty }) => {
+                match ty {
+                  TypeRef::Func(type_index) => functions.push(type_index),
+                  TypeRef::Global(global) => globals.push(global),
+                  _ => {}
+                }
+                dependencies.push(Box::new(WasmImportDependency::new(
+                  module.into(),
+                  name.into(),
+                )));
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Import Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        _ => {}
+      },
+      Err(err) => {
+        diagnostic.push(Diagnostic::error(
+          "Wasm Parse Error".into(),
+          err.to_string(),
+        ));
+      }
+    }
+  }
+
+  dependencies.push(Box::new(WasmExportsDependency::new(exports)));
+
+  (dependencies, diagnostic)
+}
+
 #[cacheable_dyn]
 #[async_trait::async_trait]
 impl ParserAndGenerator for AsyncWasmParserAndGenerator {
@@ -45,56 +184,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
     parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
 
     let source = parse_context.source;
-
-    let mut exports = Vec::with_capacity(1);
-    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
-    let mut diagnostic = Vec::with_capacity(1);
-
-    for payload in Parser::new(0).parse_all(&source.buffer()) {
-      match payload {
-        Ok(payload) => match payload {
-          Payload::ExportSection(s) => {
-            for export in s {
-              match export {
-                Ok(export) => exports.push(export.name.to_string()),
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Export Parse Error".into(),
-                  err.to_string(),
-                )),
-              };
-            }
-          }
-          Payload::ImportSection(s) => {
-            for import in s {
-              match import {
-                Ok(Import { module, name, .. }) => {
-                  dependencies.push(Box::new(WasmImportDependency::new(
-                    module.into(),
-                    name.into(),
-                  )));
-                }
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Import Parse Error".into(),
-                  err.to_string(),
-                )),
-              }
-            }
-          }
-          _ => {}
-        },
-        Err(err) => {
-          diagnostic.push(Diagnostic::error(
-            "Wasm Parse Error".into(),
-            err.to_string(),
-          ));
-        }
-      }
-    }
-
-    dependencies.push(Box::new(StaticExportsDependency::new(
-      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
-      false,
-    )));
+    let (dependencies, diagnostic) = parse_wasm(&source);
 
     Ok(
       ParseResult {
@@ -0,0 +1,102 @@
+use rspack_plugin_javascript::{JavascriptParserPlugin, visitors::JavascriptParser};
+use swc_core::{
+  common::Spanned,
+  ecma::{
+    ast::{CallExpr, Callee, Import, ImportDecl, ImportPhase, ImportSpecifier},
+    atoms::Atom,
+  },
+};
+
+use crate::dependency::WasmSourcePhaseImportDependency;
+
+/// Handles `import source name from "./x.wasm"` and `import.source("./x.wasm")`.
+#[derive(Debug, Default)]
+pub struct WasmSourcePhaseImportParserPlugin;
+
+impl JavascriptParserPlugin for WasmSourcePhaseImportParserPlugin {
+  fn import(
+    &self,
+    parser: &mut JavascriptParser,
+    import_decl: &ImportDecl,
+    source: &str,
+  ) -> Option<bool> {
+    if import_decl.phase != ImportPhase::Source {
+      return None;
+    }
+    let name = import_decl.specifiers.iter().find_map(|s| match s {
+      ImportSpecifier::Default(s) => Some(s.local.sym.clone()),
+      _ => None,
+    });
+    // the compiled `WebAssembly.Module` is awaited at the top level, and the
+    // rendered `var` declaration never continues the previous statement, so
+    // there is no need to handle the ASI position like other imports
+    parser.build_meta.has_top_level_await = true;
+    match name {
+      Some(name) => {
+        parser.add_dependency(Box::new(WasmSourcePhaseImportDependency::new(
+          source.into(),
+          Some(name),
+          import_decl.span.into(),
+        )));
+      }
+      None => {
+        parser.add_error(
+          rspack_error::error!(
+            "Source phase imports must have a default binding, e.g. `import source mod from \"{source}\"`."
+          )
+          .into(),
+        );
+      }
+    }
+    parser.unset_asi_position(import_decl.span_hi());
+    Some(true)
+  }
+
+  fn import_specifier(
+    &self,
+    _parser: &mut JavascriptParser,
+    statement: &ImportDecl,
+    _source: &Atom,
+    _export_name: Option<&Atom>,
+    _identifier_name: &Atom,
+  ) -> Option<bool> {
+    (statement.phase == ImportPhase::Source).then_some(true)
+  }
+
+  fn import_call(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &CallExpr,
+    _import_then: Option<&CallExpr>,
+  ) -> Option<bool> {
+    let Callee::Import(Import {
+      phase: ImportPhase::Source,
+      ..
+    }) = &expr.callee
+    else {
+      return None;
+    };
+    let request = expr
+      .args
+      .first()
+      .filter(|arg| arg.spread.is_none())
+      .map(|arg| parser.evaluate_expression(&arg.expr))
+      .filter(|param| param.is_string())
+      .map(|param| param.string().clone());
+    match request {
+      Some(request) => {
+        parser.add_dependency(Box::new(WasmSourcePhaseImportDependency::new(
+          request.into(),
+          None,
+          expr.span().into(),
+        )));
+      }
+      None => {
+        parser.add_error(
+          rspack_error::error!("`import.source()` only supports a string literal request.").into(),
+        );
+      }
+    }
+    Some(true)
+  }
+}
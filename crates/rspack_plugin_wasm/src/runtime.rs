@@ -1,10 +1,15 @@
 use cow_utils::CowUtils;
-use rspack_collections::Identifier;
+use indexmap::IndexMap;
+use rspack_collections::{Identifier, IdentifierSet};
 use rspack_core::{
-  ChunkUkey, Compilation, PathData, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
-  RuntimeTemplate, get_filename_without_hash_length, impl_runtime_module,
+  ChunkGraph, ChunkUkey, Compilation, Dependency, ExportsInfoGetter, GetUsedNameParam, Module,
+  ModuleDependency, ModuleType, PathData, PrefetchExportsInfoMode, RuntimeGlobals, RuntimeModule,
+  RuntimeModuleStage, RuntimeTemplate, SourceType, UsedName, get_filename_without_hash_length,
+  impl_runtime_module,
 };
-use rspack_util::itoa;
+use rspack_util::{itoa, json_stringify};
+
+use crate::dependency::WasmImportDependency;
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -65,33 +70,7 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
     self.id
   }
   async fn generate(&self, compilation: &Compilation) -> rspack_error::Result<String> {
-    let (fake_filename, hash_len_map) =
-      get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
-
-    // Even use content hash when [hash] in webpack
-    let hash = match hash_len_map
-      .get("[contenthash]")
-      .or(hash_len_map.get("[hash]"))
-    {
-      Some(hash_len) => {
-        let mut hash_len_buffer = itoa::Buffer::new();
-        let hash_len_str = hash_len_buffer.format(*hash_len);
-        format!("\" + wasmModuleHash.slice(0, {}) + \"", hash_len_str)
-      }
-      None => "\" + wasmModuleHash + \"".to_string(),
-    };
-
-    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
-    let path = compilation
-      .get_path(
-        &fake_filename,
-        PathData::default()
-          .hash(&hash)
-          .content_hash(&hash)
-          .id(&PathData::prepare_id("\" + wasmModuleId + \""))
-          .runtime(chunk.runtime().as_str()),
-      )
-      .await?;
+    let path = get_wasm_module_path(compilation, &self.chunk).await?;
 
     Ok(get_async_wasm_loading(
       &self
@@ -106,6 +85,7 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
         .cow_replace("$PATH", &format!("\"{path}\"")),
       &self.generate_before_instantiate_streaming,
       self.supports_streaming,
+      ChunkGraph::get_tree_runtime_requirements(compilation, &self.chunk),
       &compilation.runtime_template,
     ))
   }
@@ -115,29 +95,87 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
   }
 }
 
+/// The path of the wasm module with `wasmModuleId` and `wasmModuleHash` variables in it.
+async fn get_wasm_module_path(
+  compilation: &Compilation,
+  chunk: &ChunkUkey,
+) -> rspack_error::Result<String> {
+  let (fake_filename, hash_len_map) =
+    get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
+
+  // Even use content hash when [hash] in webpack
+  let hash = match hash_len_map
+    .get("[contenthash]")
+    .or(hash_len_map.get("[hash]"))
+  {
+    Some(hash_len) => {
+      let mut hash_len_buffer = itoa::Buffer::new();
+      let hash_len_str = hash_len_buffer.format(*hash_len);
+      format!("\" + wasmModuleHash.slice(0, {}) + \"", hash_len_str)
+    }
+    None => "\" + wasmModuleHash + \"".to_string(),
+  };
+
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+  compilation
+    .get_path(
+      &fake_filename,
+      PathData::default()
+        .hash(&hash)
+        .content_hash(&hash)
+        .id(&PathData::prepare_id("\" + wasmModuleId + \""))
+        .runtime(chunk.runtime().as_str()),
+    )
+    .await
+}
+
 fn get_async_wasm_loading(
   req: &str,
   generate_before_load_binary_code: &str,
   generate_before_instantiate_streaming: &str,
   supports_streaming: bool,
+  runtime_requirements: &RuntimeGlobals,
   runtime_template: &RuntimeTemplate,
 ) -> String {
-  let fallback_code = r#"
-          .then(function(x) { return x.arrayBuffer();})
-          .then(function(bytes) { return WebAssembly.instantiate(bytes, importsObj);})
-          .then(function(res) { return Object.assign(exports, res.instance.exports);});
-"#;
+  let mut code = String::new();
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+    code.push_str(&get_instantiate_wasm(
+      req,
+      generate_before_load_binary_code,
+      generate_before_instantiate_streaming,
+      supports_streaming,
+      runtime_template,
+    ));
+  }
+  if runtime_requirements.contains(RuntimeGlobals::COMPILE_WASM) {
+    code.push_str(&get_compile_wasm(
+      req,
+      generate_before_load_binary_code,
+      generate_before_instantiate_streaming,
+      supports_streaming,
+      runtime_template,
+    ));
+  }
+  code
+}
 
-  let streaming_code = format!(
+fn get_streaming_code(
+  generate_before_instantiate_streaming: &str,
+  streaming_fn: &str,
+  streaming_args: &str,
+  on_fulfilled: &str,
+) -> String {
+  let fallback_fn = streaming_fn.trim_end_matches("Streaming");
+  format!(
     r#"
       return req.then(function(res) {{
-        if (typeof WebAssembly.instantiateStreaming === "function") {{
-{generate_before_instantiate_streaming}          return WebAssembly.instantiateStreaming(res, importsObj)
+        if (typeof WebAssembly.{streaming_fn} === "function") {{
+{generate_before_instantiate_streaming}          return WebAssembly.{streaming_fn}({streaming_args})
             .then(
-              function(res) {{ return Object.assign(exports, res.instance.exports);}},
+              {on_fulfilled},
               function(e) {{
                 if(res.headers.get("Content-Type") !== "application/wasm") {{
-                  console.warn("`WebAssembly.instantiateStreaming` failed because your server does not serve wasm with `application/wasm` MIME type. Falling back to `WebAssembly.instantiate` which is slower. Original error:\n", e);
+                  console.warn("`WebAssembly.{streaming_fn}` failed because your server does not serve wasm with `application/wasm` MIME type. Falling back to `WebAssembly.{fallback_fn}` which is slower. Original error:\n", e);
                   return fallback();
                 }}
                 throw e;
@@ -147,10 +185,31 @@ fn get_async_wasm_loading(
         return fallback();
       }});
 "#
-  );
+  )
+}
+
+fn get_instantiate_wasm(
+  req: &str,
+  generate_before_load_binary_code: &str,
+  generate_before_instantiate_streaming: &str,
+  supports_streaming: bool,
+  runtime_template: &RuntimeTemplate,
+) -> String {
+  let fallback_code = r#"
+          .then(function(x) { return x.arrayBuffer();})
+          .then(function(bytes) { return WebAssembly.instantiate(bytes, importsObj);})
+          .then(function(res) { return Object.assign(exports, res.instance.exports);});
+"#;
+
   let instantiate_wasm = runtime_template.render_runtime_globals(&RuntimeGlobals::INSTANTIATE_WASM);
 
   if supports_streaming {
+    let streaming_code = get_streaming_code(
+      generate_before_instantiate_streaming,
+      "instantiateStreaming",
+      "res, importsObj",
+      "function(res) { return Object.assign(exports, res.instance.exports);}",
+    );
     format!(
       r#"
     {instantiate_wasm} = function(exports, wasmModuleId, wasmModuleHash, importsObj) {{
@@ -174,3 +233,329 @@ fn get_async_wasm_loading(
     )
   }
 }
+
+fn get_compile_wasm(
+  req: &str,
+  generate_before_load_binary_code: &str,
+  generate_before_instantiate_streaming: &str,
+  supports_streaming: bool,
+  runtime_template: &RuntimeTemplate,
+) -> String {
+  let fallback_code = r#"
+          .then(function(x) { return x.arrayBuffer();})
+          .then(function(bytes) { return WebAssembly.compile(bytes);});
+"#;
+
+  let compile_wasm = runtime_template.render_runtime_globals(&RuntimeGlobals::COMPILE_WASM);
+
+  let compile = if supports_streaming {
+    let streaming_code = get_streaming_code(
+      generate_before_instantiate_streaming,
+      "compileStreaming",
+      "res",
+      "function(module) { return module;}",
+    );
+    format!(
+      r#"function(wasmModuleId, wasmModuleHash) {{
+      {generate_before_load_binary_code}
+      var req = {req};
+      var fallback = function() {{
+        return req{fallback_code}
+      }}
+      {streaming_code}
+    }}"#
+    )
+  } else {
+    let req = req.trim_end_matches(';');
+    format!(
+      r#"function(wasmModuleId, wasmModuleHash) {{
+      return {req}{fallback_code}
+    }}"#
+    )
+  };
+
+  // the compiled `WebAssembly.Module` is cached, so the wasm module imported by
+  // source phase imports in different modules is only compiled once
+  format!(
+    r#"
+    var compiledWasmModules = {{}};
+    var compileWasm = {compile};
+    {compile_wasm} = function(wasmModuleId, wasmModuleHash) {{
+      return compiledWasmModules[wasmModuleId] = compiledWasmModules[wasmModuleId] || compileWasm(wasmModuleId, wasmModuleHash);
+    }};
+"#
+  )
+}
+
+/// Get the `webassembly/sync` modules of the chunk, which are instantiated when the chunk is loaded.
+pub(crate) fn get_sync_wasm_modules<'a>(
+  compilation: &'a Compilation,
+  chunk: &ChunkUkey,
+) -> Vec<&'a dyn Module> {
+  let mut modules = compilation
+    .chunk_graph
+    .get_chunk_modules_by_source_type(chunk, SourceType::Wasm, compilation.get_module_graph())
+    .into_iter()
+    .filter(|m| m.module_type() == &ModuleType::WasmSync)
+    .collect::<Vec<_>>();
+  modules.sort_unstable_by_key(|m| m.identifier());
+  modules
+}
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct WasmChunkLoadingRuntimeModule {
+  id: Identifier,
+  generate_load_binary_code: String,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    runtime_template: &RuntimeTemplate,
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self::with_default(
+      Identifier::from(format!(
+        "{}wasm_chunk_loading",
+        runtime_template.runtime_module_prefix()
+      )),
+      generate_load_binary_code,
+      supports_streaming,
+      chunk,
+    )
+  }
+}
+
+#[async_trait::async_trait]
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  async fn generate(&self, compilation: &Compilation) -> rspack_error::Result<String> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let runtime_template = &compilation.runtime_template;
+    let module_graph = compilation.get_module_graph();
+    let module_cache = runtime_template.render_runtime_globals(&RuntimeGlobals::MODULE_CACHE);
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    let mut module_hashes = vec![];
+    let mut chunk_module_ids = vec![];
+    let mut visited = IdentifierSet::default();
+    for async_chunk in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let Some(chunk_id) = compilation.chunk_by_ukey.expect_get(&async_chunk).id() else {
+        continue;
+      };
+      let mut module_ids = vec![];
+      for module in get_sync_wasm_modules(compilation, &async_chunk) {
+        let Some(module_id) =
+          ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+        else {
+          continue;
+        };
+        let module_id = json_stringify(module_id);
+        module_ids.push(module_id.clone());
+        if !visited.insert(module.identifier()) {
+          continue;
+        }
+
+        if let Some(hash) = &module.build_info().hash {
+          module_hashes.push(format!(
+            "{module_id}: {}",
+            json_stringify(hash.rendered(16))
+          ));
+        }
+
+        // the imports of the wasm module, grouped by the request
+        let mut properties = IndexMap::<&str, Vec<String>>::new();
+        // the instances of the imported wasm modules need to be waited for
+        let mut wait_for_instances = IndexMap::<String, String>::new();
+        for dep in module
+          .get_dependencies()
+          .iter()
+          .map(|id| module_graph.dependency_by_id(id))
+          .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+        {
+          let Some(imported) = module_graph.get_module_by_dependency_id(dep.id()) else {
+            continue;
+          };
+          let Some(imported_id) =
+            ChunkGraph::get_module_id(&compilation.module_ids_artifact, imported.identifier())
+          else {
+            continue;
+          };
+          let imported_id = json_stringify(imported_id);
+          let name = dep.name();
+          let exports_info = module_graph.get_prefetched_exports_info(
+            &imported.identifier(),
+            PrefetchExportsInfoMode::Nested(std::slice::from_ref(name)),
+          );
+          let used_name = match ExportsInfoGetter::get_used_name(
+            GetUsedNameParam::WithNames(&exports_info),
+            Some(chunk.runtime()),
+            std::slice::from_ref(name),
+          ) {
+            Some(UsedName::Normal(names)) if names.len() == 1 => json_stringify(&names[0]),
+            _ => json_stringify(name),
+          };
+
+          let value = if imported.module_type() == &ModuleType::WasmSync {
+            let len = wait_for_instances.len();
+            let instance = wait_for_instances
+              .entry(imported_id)
+              .or_insert_with(|| format!("array[{len}]"));
+            format!("{instance}[{used_name}]")
+          } else {
+            let cache = format!("wasmImportedFuncCache{}", declarations.len());
+            declarations.push(format!("var {cache};"));
+            format!(
+              r#"function() {{
+        if({cache} === undefined) {cache} = {module_cache}[{imported_id}].exports;
+        return {cache}[{used_name}].apply({cache}, arguments);
+      }}"#
+            )
+          };
+          properties
+            .entry(dep.request())
+            .or_default()
+            .push(format!("{}: {value}", json_stringify(name)));
+        }
+
+        let import_object = format!(
+          "{{\n{}\n    }}",
+          properties
+            .into_iter()
+            .map(|(request, properties)| format!(
+              "      {}: {{\n      {}\n      }}",
+              json_stringify(request),
+              properties.join(",\n      ")
+            ))
+            .collect::<Vec<_>>()
+            .join(",\n")
+        );
+        let factory = if wait_for_instances.is_empty() {
+          format!("function() {{\n    return {import_object};\n  }}")
+        } else {
+          let instances = wait_for_instances
+            .keys()
+            .map(|id| format!("installedWasmModules[{id}]"))
+            .collect::<Vec<_>>()
+            .join(", ");
+          format!(
+            r#"function() {{
+    return promiseResolve().then(function() {{ return Promise.all([{instances}]); }}).then(function(array) {{
+      return {import_object};
+    }});
+  }}"#
+          )
+        };
+        import_objects.push(format!("  {module_id}: {factory}"));
+      }
+      if !module_ids.is_empty() {
+        chunk_module_ids.push(format!(
+          "  {}: [{}]",
+          json_stringify(chunk_id),
+          module_ids.join(", ")
+        ));
+      }
+    }
+
+    let path = get_wasm_module_path(compilation, &self.chunk).await?;
+    let req = self
+      .generate_load_binary_code
+      .cow_replace(
+        "$IMPORT_META_NAME",
+        compilation.options.output.import_meta_name.as_str(),
+      )
+      .cow_replace("$PATH", &format!("\"{path}\""))
+      .trim_end_matches(';')
+      .to_string();
+
+    let instantiate_code = r#"var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        if(importObject && typeof importObject.then === 'function') {
+          promise = Promise.all([bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }), importObject]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else {
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });
+        }"#;
+    let instantiate_code = if self.supports_streaming {
+      format!(
+        r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {{
+        promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {{
+          return WebAssembly.instantiate(items[0], items[1]);
+        }});
+      }} else if(typeof WebAssembly.instantiateStreaming === 'function') {{
+        promise = WebAssembly.instantiateStreaming(req, importObject);
+      }} else {{
+        {instantiate_code}
+      }}"#
+      )
+    } else {
+      instantiate_code.to_string()
+    };
+
+    Ok(format!(
+      r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {{
+{chunk_module_ids}
+}};
+
+var wasmModuleHashes = {{
+  {module_hashes}
+}};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData) promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var wasmModuleHash = wasmModuleHashes[wasmModuleId];
+      var req = {req};
+      var promise;
+      {instantiate_code}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+      declarations = declarations.join("\n"),
+      import_objects = import_objects.join(",\n"),
+      chunk_module_ids = chunk_module_ids.join(",\n"),
+      module_hashes = module_hashes.join(",\n  "),
+      wasm_instances = runtime_template.render_runtime_globals(&RuntimeGlobals::WASM_INSTANCES),
+      ensure_chunk_handlers =
+        runtime_template.render_runtime_globals(&RuntimeGlobals::ENSURE_CHUNK_HANDLERS),
+    ))
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{
+  BuildMetaExportsType, DependencyId, DependencyType, GenerateContext, ImportPhase, Module,
+  ModuleGraph, ModuleIdentifier, ParseContext, ParseResult, ParserAndGenerator, RuntimeGlobals,
+  SourceType,
+  rspack_sources::{BoxSource, RawStringSource, Source, SourceExt},
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+
+use crate::parser_and_generator::{WASM_SOURCE_TYPE, parse_wasm};
+
+/// The parser and generator of `webassembly/sync` modules.
+///
+/// The wasm module is instantiated when its chunk is loaded, so the generated
+/// JavaScript only exposes the exports of the instance from `__webpack_require__.w`.
+#[cacheable]
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator;
+
+#[cacheable_dyn]
+#[async_trait::async_trait]
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self, _module: &dyn Module, _module_graph: &ModuleGraph) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  async fn parse<'a>(
+    &mut self,
+    parse_context: ParseContext<'a>,
+  ) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+    let (dependencies, diagnostic) = parse_wasm(&source);
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        code_generation_dependencies: vec![],
+        source,
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: Option<&SourceType>) -> f64 {
+    match source_type.unwrap_or(&SourceType::Wasm) {
+      SourceType::JavaScript => 100.0,
+      SourceType::Wasm => module.source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  async fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let compilation = generate_context.compilation;
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+        runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = compilation.get_module_graph();
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (DependencyId, &str)>::new();
+        for dep in module
+          .get_dependencies()
+          .iter()
+          .map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &DependencyType::WasmImport)
+        {
+          if let Some(imported) = module_graph.module_identifier_by_dependency_id(dep.id())
+            && let Some(dep) = dep.as_module_dependency()
+          {
+            dep_modules
+              .entry(*imported)
+              .or_insert((*dep.id(), dep.request()));
+          }
+        }
+
+        // the imported modules are executed before the wasm module like esm
+        let imports_code = dep_modules
+          .values()
+          .enumerate()
+          .map(|(index, (dep_id, request))| {
+            let (import_code, import_compat_code) = compilation.runtime_template.import_statement(
+              module,
+              compilation,
+              runtime_requirements,
+              dep_id,
+              &format!("rspack_import_{index}"),
+              request,
+              ImportPhase::Evaluation,
+              false,
+            );
+            format!("{import_code}{import_compat_code}")
+          })
+          .collect::<String>();
+
+        let runtime_template = &compilation.runtime_template;
+        Ok(
+          RawStringSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[{}.id];
+{}({});
+// export exports from WebAssembly module
+for (var name in wasmExports) if (name) {}[name] = wasmExports[name];
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            runtime_template.render_runtime_globals(&RuntimeGlobals::WASM_INSTANCES),
+            runtime_template.render_runtime_globals(&RuntimeGlobals::MODULE),
+            runtime_template.render_runtime_globals(&RuntimeGlobals::MAKE_NAMESPACE_OBJECT),
+            runtime_template.render_runtime_globals(&RuntimeGlobals::EXPORTS),
+            runtime_template.render_runtime_globals(&RuntimeGlobals::EXPORTS),
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<Cow<'static, str>> {
+    Some("Module Concatenation is not implemented for SyncWasmParserAndGenerator".into())
+  }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Debug;
+
+use rspack_core::{
+  AsyncModulesArtifact, ChunkUkey, Compilation, CompilationFinishModules, CompilationParams,
+  CompilationRenderManifest, CompilerCompilation, DependencyType, ModuleType, ParserAndGenerator,
+  Plugin, RenderManifestEntry,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::{
+  runtime::get_sync_wasm_modules,
+  sync_parser_and_generator::SyncWasmParserAndGenerator,
+  wasm_plugin::{render_wasm_manifest, wasm_export_call_diagnostics},
+};
+
+/// Supports the `webassembly/sync` modules, which are instantiated when their chunk is loaded,
+/// so they can be used synchronously by the other modules in the chunk.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {}
+
+#[plugin_hook(CompilerCompilation for SyncWasmPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::WasmImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilationFinishModules for SyncWasmPlugin)]
+async fn finish_modules(
+  &self,
+  compilation: &mut Compilation,
+  _async_modules_artifact: &mut AsyncModulesArtifact,
+) -> Result<()> {
+  let diagnostics = wasm_export_call_diagnostics(compilation, &ModuleType::WasmSync);
+  compilation.extend_diagnostics(diagnostics);
+  Ok(())
+}
+
+#[plugin_hook(CompilationRenderManifest for SyncWasmPlugin)]
+async fn render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  if chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+    let modules = get_sync_wasm_modules(compilation, chunk_ukey);
+    if !modules.is_empty() {
+      let modules = modules
+        .iter()
+        .map(|m| format!("* {}", m.readable_identifier(&compilation.options.context)))
+        .collect::<Vec<_>>()
+        .join("\n");
+      diagnostics.push(Diagnostic::error(
+        "WebAssemblyInInitialChunkError".into(),
+        format!(
+          "WebAssembly module is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module:\n{modules}"
+        ),
+      ));
+    }
+  }
+
+  render_wasm_manifest(compilation, chunk_ukey, manifest, &ModuleType::WasmSync).await
+}
+
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
+    ctx
+      .compilation_hooks
+      .render_manifest
+      .tap(render_manifest::new(self));
+
+    ctx.register_parser_and_generator_builder(
+      ModuleType::WasmSync,
+      Box::new(move |_, _| Box::new(SyncWasmParserAndGenerator) as Box<dyn ParserAndGenerator>),
+    );
+
+    Ok(())
+  }
+}
@@ -1,14 +1,23 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use rspack_core::{
-  ChunkGraph, ChunkUkey, Compilation, CompilationParams, CompilationRenderManifest,
-  CompilerCompilation, DependencyType, ManifestAssetType, ModuleType, ParserAndGenerator, PathData,
-  Plugin, RenderManifestEntry, SourceType,
+  AsyncModulesArtifact, ChunkGraph, ChunkUkey, Compilation, CompilationFinishModules,
+  CompilationParams, CompilationRenderManifest, CompilerCompilation, Dependency, DependencyType,
+  ManifestAssetType, ModuleDependency, ModuleType, NormalModuleFactoryParser, ParserAndGenerator,
+  ParserOptions, PathData, Plugin, RenderManifestEntry, SourceType,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{Diagnostic, Error, Result, Severity};
 use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_javascript::{
+  BoxJavascriptParserPlugin, dependency::ESMImportSpecifierDependency,
+  parser_and_generator::JavaScriptParserAndGenerator,
+};
 
-use crate::parser_and_generator::AsyncWasmParserAndGenerator;
+use crate::{
+  dependency::{WasmExportType, WasmExportsDependency, WasmSourcePhaseImportDependencyTemplate},
+  parser_and_generator::AsyncWasmParserAndGenerator,
+  parser_plugin::WasmSourcePhaseImportParserPlugin,
+};
 
 #[plugin]
 #[derive(Debug, Default)]
@@ -28,6 +37,42 @@ async fn compilation(
     DependencyType::WasmExportImported,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::WasmSourcePhaseImport,
+    params.normal_module_factory.clone(),
+  );
+  compilation.set_dependency_template(
+    WasmSourcePhaseImportDependencyTemplate::template_type(),
+    Arc::new(WasmSourcePhaseImportDependencyTemplate::default()),
+  );
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryParser for AsyncWasmPlugin)]
+async fn nmf_parser(
+  &self,
+  module_type: &ModuleType,
+  parser: &mut Box<dyn ParserAndGenerator>,
+  _parser_options: Option<&ParserOptions>,
+) -> Result<()> {
+  if module_type.is_js_like()
+    && let Some(parser) = parser.downcast_mut::<JavaScriptParserAndGenerator>()
+  {
+    parser.add_parser_plugin(
+      Box::<WasmSourcePhaseImportParserPlugin>::default() as BoxJavascriptParserPlugin
+    );
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationFinishModules for AsyncWasmPlugin)]
+async fn finish_modules(
+  &self,
+  compilation: &mut Compilation,
+  _async_modules_artifact: &mut AsyncModulesArtifact,
+) -> Result<()> {
+  let diagnostics = wasm_export_call_diagnostics(compilation, &ModuleType::WasmAsync);
+  compilation.extend_diagnostics(diagnostics);
   Ok(())
 }
 
@@ -38,6 +83,16 @@ async fn render_manifest(
   chunk_ukey: &ChunkUkey,
   manifest: &mut Vec<RenderManifestEntry>,
   _diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  render_wasm_manifest(compilation, chunk_ukey, manifest, &ModuleType::WasmAsync).await
+}
+
+/// Emits the wasm files of the wasm modules with `module_type` in the chunk.
+pub(crate) async fn render_wasm_manifest(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  module_type: &ModuleType,
 ) -> Result<()> {
   let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
@@ -48,7 +103,7 @@ async fn render_manifest(
     .get_chunk_modules(chunk_ukey, module_graph);
 
   for m in ordered_modules {
-    if m.module_type() != &ModuleType::WasmAsync {
+    if m.module_type() != module_type {
       continue;
     }
     let Some(source) = compilation
@@ -83,6 +138,94 @@ async fn render_manifest(
   Ok(())
 }
 
+/// Warns when a JavaScript module calls an export of the wasm modules with `module_type`
+/// that isn't a function, or calls a function export with a different number of arguments
+/// from its params. The missing exports are already reported as linking errors.
+pub(crate) fn wasm_export_call_diagnostics(
+  compilation: &Compilation,
+  module_type: &ModuleType,
+) -> Vec<Diagnostic> {
+  let module_graph = compilation.get_module_graph();
+  let mut diagnostics = vec![];
+
+  for (module_identifier, module) in module_graph.modules() {
+    if module.module_type() != module_type {
+      continue;
+    }
+    let Some(exports_dep) = module.get_dependencies().iter().find_map(|dep_id| {
+      module_graph
+        .dependency_by_id(dep_id)
+        .downcast_ref::<WasmExportsDependency>()
+    }) else {
+      continue;
+    };
+
+    for connection in module_graph.get_incoming_connections(&module_identifier) {
+      let Some(dep) = module_graph
+        .dependency_by_id(&connection.dependency_id)
+        .downcast_ref::<ESMImportSpecifierDependency>()
+      else {
+        continue;
+      };
+      if !dep.is_call() {
+        continue;
+      }
+      // `foo.bar()` calls a property of the export rather than the export itself
+      let [name] = dep.get_ids(module_graph) else {
+        continue;
+      };
+      let Some(export) = exports_dep.get_export(name) else {
+        continue;
+      };
+      let message = match &export.ty {
+        WasmExportType::Function { params, .. } => {
+          // the arguments of a spread call can't be counted
+          let Some(arguments) = dep.call_arguments else {
+            continue;
+          };
+          if arguments == params.len() {
+            continue;
+          }
+          format!(
+            "export '{name}' of WebAssembly module '{}' is a {}, but it's called with {arguments} argument(s)",
+            dep.user_request(),
+            export.ty
+          )
+        }
+        ty => format!(
+          "export '{name}' of WebAssembly module '{}' is a {ty}, but it's called as a function",
+          dep.user_request(),
+        ),
+      };
+      let parent_module = connection
+        .original_module_identifier
+        .and_then(|id| module_graph.module_by_identifier(&id));
+      let mut error = if let Some(range) = dep.range()
+        && let Some(source) = parent_module.and_then(|m| m.source())
+      {
+        Error::from_string(
+          Some(source.source().into_string_lossy().into_owned()),
+          range.start as usize,
+          range.end as usize,
+          "WebAssemblyExportTypeWarning".to_string(),
+          message,
+        )
+      } else {
+        let mut error = rspack_error::error!(message);
+        error.code = Some("WebAssemblyExportTypeWarning".into());
+        error
+      };
+      error.severity = Severity::Warning;
+      error.hide_stack = Some(true);
+      let mut diagnostic = Diagnostic::from(error);
+      diagnostic.module_identifier = connection.original_module_identifier;
+      diagnostics.push(diagnostic);
+    }
+  }
+
+  diagnostics
+}
+
 impl Plugin for AsyncWasmPlugin {
   fn name(&self) -> &'static str {
     "rspack.AsyncWebAssemblyModulesPlugin"
@@ -90,6 +233,14 @@ impl Plugin for AsyncWasmPlugin {
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
     ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx
+      .normal_module_factory_hooks
+      .parser
+      .tap(nmf_parser::new(self));
+    ctx
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
     ctx
       .compilation_hooks
       .render_manifest
@@ -1,12 +1,18 @@
 mod error;
 mod fs;
 mod pack;
+mod remote;
 
 use std::sync::Arc;
 
 pub use error::Result;
 pub use fs::{BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
-pub use pack::{PackStorage, PackStorageOptions};
+pub use pack::{
+  PackCompression, PackInfo, PackStorage, PackStorageOptions, SaveReport, ScopeInfo, VersionInfo,
+};
+#[cfg(not(target_family = "wasm"))]
+pub use remote::HttpRemoteClient;
+pub use remote::{RemoteClient, RemoteStorage, RemoteStorageOptions};
 use tokio::sync::oneshot::Receiver;
 
 type ItemKey = Vec<u8>;
@@ -18,7 +24,8 @@ pub trait Storage: std::fmt::Debug + Sync + Send {
   async fn load(&self, scope: &'static str) -> Result<Vec<(Arc<Vec<u8>>, Arc<Vec<u8>>)>>;
   fn set(&self, scope: &'static str, key: Vec<u8>, value: Vec<u8>);
   fn remove(&self, scope: &'static str, key: &[u8]);
-  fn trigger_save(&self) -> Result<Receiver<Result<()>>>;
+  /// Save the updates in background, the receiver gets the report when saving is finished
+  fn trigger_save(&self) -> Result<Receiver<Result<SaveReport>>>;
   async fn reset(&self);
   /// Get list of all available scopes in the storage
   async fn scopes(&self) -> Result<Vec<String>>;
@@ -13,6 +13,51 @@ pub struct PackFileMeta {
   pub generation: usize,
 }
 
+/// Summary of a pack file, used to inspect the storage
+#[derive(Debug, Clone)]
+pub struct PackInfo {
+  pub bucket: usize,
+  pub name: String,
+  pub size: usize,
+  pub generation: usize,
+}
+
+/// Summary of a scope, used to inspect the storage
+#[derive(Debug, Clone)]
+pub struct ScopeInfo {
+  pub bucket_size: usize,
+  pub pack_size: usize,
+  pub generation: usize,
+  pub packs: Vec<PackInfo>,
+}
+
+/// Disk usage of a cache version under the cache root
+#[derive(Debug, Clone)]
+pub struct VersionInfo {
+  pub version: String,
+  pub size: usize,
+  /// The time of the last save of this version
+  pub last_used: u64,
+}
+
+/// Summary of a finished save
+#[derive(Debug, Default, Clone)]
+pub struct SaveReport {
+  /// Versions evicted in least-recently-used order
+  pub evicted: Vec<VersionInfo>,
+  /// The size of the cache directory after eviction, `None` if there is no size budget
+  pub total_size: Option<usize>,
+  pub max_size: Option<usize>,
+}
+
+impl SaveReport {
+  /// Whether the cache directory is still larger than the size budget,
+  /// which happens when the current version alone exceeds it.
+  pub fn is_over_budget(&self) -> bool {
+    matches!((self.total_size, self.max_size), (Some(total), Some(max)) if total > max)
+  }
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum RootMetaFrom {
   #[default]
@@ -3,8 +3,10 @@ mod options;
 mod pack;
 mod scope;
 
-pub use meta::{PackFileMeta, RootMeta, RootMetaFrom, ScopeMeta};
-pub use options::{PackOptions, RootOptions};
+pub use meta::{
+  PackFileMeta, PackInfo, RootMeta, RootMetaFrom, SaveReport, ScopeInfo, ScopeMeta, VersionInfo,
+};
+pub use options::{PackCompression, PackOptions, RootOptions};
 pub use pack::{Pack, PackContents, PackGenerations, PackKeys};
 pub use rspack_util::current_time;
 pub use scope::{PackScope, RootMetaState};
@@ -1,9 +1,40 @@
 use rspack_paths::Utf8PathBuf;
 
+/// The codec used to compress pack files
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackCompression {
+  #[default]
+  None,
+  Zstd,
+  Lz4,
+}
+
+impl PackCompression {
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::None => "none",
+      Self::Zstd => "zstd",
+      Self::Lz4 => "lz4",
+    }
+  }
+
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "none" => Some(Self::None),
+      "zstd" => Some(Self::Zstd),
+      "lz4" => Some(Self::Lz4),
+      _ => None,
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct PackOptions {
   pub bucket_size: usize,
   pub pack_size: usize,
+  /// Packs written with different compression can be read by each other,
+  /// so changing it will not invalidate the saved scopes.
+  pub compression: PackCompression,
 }
 
 #[derive(Debug)]
@@ -11,4 +42,7 @@ pub struct RootOptions {
   pub root: Utf8PathBuf,
   pub expire: u64,
   pub clean: bool,
+  /// The maximum size in bytes of all versions under `root`,
+  /// the least recently used versions will be evicted when exceeded.
+  pub max_size: Option<usize>,
 }
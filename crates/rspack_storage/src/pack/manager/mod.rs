@@ -9,7 +9,9 @@ use tokio::sync::{Mutex, oneshot, oneshot::Receiver};
 
 use super::{
   ScopeUpdates,
-  data::{PackOptions, PackScope, RootMeta, RootMetaState, RootOptions},
+  data::{
+    PackInfo, PackOptions, PackScope, RootMeta, RootMetaState, RootOptions, SaveReport, ScopeInfo,
+  },
   strategy::{ScopeStrategy, WriteScopeResult},
 };
 use crate::{
@@ -45,7 +47,7 @@ impl ScopeManager {
     }
   }
 
-  pub fn save(&self, updates: ScopeUpdates) -> Result<Receiver<Result<()>>> {
+  pub fn save(&self, updates: ScopeUpdates) -> Result<Receiver<Result<SaveReport>>> {
     let strategy = self.strategy.clone();
     let scopes = self.scopes.clone();
     let root_meta = self.root_meta.clone();
@@ -87,9 +89,9 @@ impl ScopeManager {
       let old_scopes = std::mem::take(&mut *scopes_lock);
       let res = save_scopes(old_scopes, &root_meta, strategy.as_ref(), &root_options).await;
       let _ = match res {
-        Ok(new_scopes) => {
+        Ok((new_scopes, report)) => {
           let _ = std::mem::replace(&mut *scopes_lock, new_scopes);
-          tx.send(Ok(()))
+          tx.send(Ok(report))
         }
         Err(e) => tx.send(Err(e)),
       };
@@ -211,8 +213,8 @@ impl ScopeManager {
     *self.root_meta.lock().await = RootMetaState::Pending;
   }
 
-  /// Get list of all available scopes in the storage
-  pub async fn scopes(&self) -> Result<Vec<String>> {
+  /// Read root meta from disk if it has not been read
+  async fn ensure_root_meta(&self) -> Result<()> {
     // only check lock file and root meta for the first time
     if matches!(*self.root_meta.lock().await, RootMetaState::Pending) {
       match self.strategy.before_load().await {
@@ -222,10 +224,15 @@ impl ScopeManager {
         }
         Err(_) => {
           *self.root_meta.lock().await = RootMetaState::Value(None);
-          return Ok(vec![]);
         }
       }
     }
+    Ok(())
+  }
+
+  /// Get list of all available scopes in the storage
+  pub async fn scopes(&self) -> Result<Vec<String>> {
+    self.ensure_root_meta().await?;
 
     let root_meta_guard = self.root_meta.lock().await;
     let Some(root_meta) = root_meta_guard.expect_value() else {
@@ -234,6 +241,61 @@ impl ScopeManager {
 
     Ok(root_meta.scopes.iter().cloned().collect())
   }
+
+  /// Get the expire time of the storage
+  pub async fn expire_time(&self) -> Result<Option<u64>> {
+    self.ensure_root_meta().await?;
+
+    let root_meta_guard = self.root_meta.lock().await;
+    Ok(
+      root_meta_guard
+        .expect_value()
+        .as_ref()
+        .map(|root_meta| root_meta.expire_time),
+    )
+  }
+
+  /// Get the summary of scope by reading the scope meta only
+  pub async fn scope_info(&self, name: &'static str) -> Result<Option<ScopeInfo>> {
+    self.ensure_root_meta().await?;
+
+    if !self
+      .root_meta
+      .lock()
+      .await
+      .expect_value()
+      .as_ref()
+      .is_some_and(|root_meta| root_meta.scopes.contains(name))
+    {
+      return Ok(None);
+    }
+
+    let mut scope = PackScope::new(
+      name,
+      self.strategy.get_path(name),
+      self.pack_options.clone(),
+    );
+    self.strategy.ensure_meta(&mut scope).await?;
+    let meta = scope.meta.expect_value();
+    Ok(Some(ScopeInfo {
+      bucket_size: meta.bucket_size,
+      pack_size: meta.pack_size,
+      generation: meta.generation,
+      packs: meta
+        .packs
+        .iter()
+        .enumerate()
+        .flat_map(|(bucket, packs)| {
+          packs.iter().map(move |pack| PackInfo {
+            bucket,
+            name: pack.name.clone(),
+            size: pack.size,
+            generation: pack.generation,
+          })
+        })
+        .collect(),
+    }))
+  }
 }
 
 #[tracing::instrument("Cache::Storage::update_scopes", skip_all)]
@@ -282,7 +344,7 @@ async fn save_scopes(
   root_meta: &RootMeta,
   strategy: &dyn ScopeStrategy,
   root_options: &RootOptions,
-) -> Result<ScopeMap> {
+) -> Result<(ScopeMap, SaveReport)> {
   scopes.retain(|_, scope| scope.loaded());
 
   strategy.before_all(&mut scopes).await?;
@@ -317,9 +379,9 @@ async fn save_scopes(
   strategy.write_root_meta(root_meta).await?;
   strategy.merge_changed(changed).await?;
   strategy.after_all(&mut scopes).await?;
-  strategy.clean(root_meta, &scopes, root_options).await?;
+  let report = strategy.clean(root_meta, &scopes, root_options).await?;
 
-  Ok(scopes.into_iter().collect())
+  Ok((scopes.into_iter().collect(), report))
 }
 
 #[cfg(test)]
@@ -371,10 +433,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -425,10 +489,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -498,11 +564,13 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       // different bucket size
       bucket_size: 100,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -545,10 +613,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 100,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -7,6 +7,7 @@ use std::{
   sync::{Arc, Mutex},
 };
 
+pub use data::{PackCompression, PackInfo, SaveReport, ScopeInfo, VersionInfo};
 use data::{PackOptions, RootOptions};
 use manager::ScopeManager;
 use rspack_paths::AssertUtf8;
@@ -29,7 +30,11 @@ pub struct PackStorageOptions {
   pub fs: Arc<dyn FileSystem>,
   pub bucket_size: usize,
   pub pack_size: usize,
+  /// The codec used to compress pack files, `PackCompression::None` writes them as is.
+  pub compression: PackCompression,
   pub expire: u64,
+  /// The maximum size in bytes of the cache root, `None` means no limit.
+  pub max_size: Option<usize>,
   pub version: String,
   pub clean: bool,
   pub fresh_generation: Option<usize>,
@@ -44,10 +49,12 @@ impl PackStorage {
           root: options.root.clone().assert_utf8(),
           expire: options.expire,
           clean: options.clean,
+          max_size: options.max_size,
         }),
         Arc::new(PackOptions {
           bucket_size: options.bucket_size,
           pack_size: options.pack_size,
+          compression: options.compression,
         }),
         Arc::new(SplitPackStrategy::new(
           options.root.join(&options.version).assert_utf8(),
@@ -60,6 +67,16 @@ impl PackStorage {
       updates: Default::default(),
     }
   }
+
+  /// Get the expire time of the saved storage, `None` means the storage has not been saved.
+  pub async fn expire_time(&self) -> Result<Option<u64>> {
+    self.manager.expire_time().await
+  }
+
+  /// Get the summary of a saved scope without reading its packs.
+  pub async fn scope_info(&self, scope: &'static str) -> Result<Option<ScopeInfo>> {
+    self.manager.scope_info(scope).await
+  }
 }
 
 #[async_trait::async_trait]
@@ -77,7 +94,7 @@ impl Storage for PackStorage {
     let scope_update = updates.entry(scope).or_default();
     scope_update.insert(key.to_vec(), None);
   }
-  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+  fn trigger_save(&self) -> Result<Receiver<Result<SaveReport>>> {
     self.manager.save(std::mem::take(
       &mut *self.updates.lock().expect("should get lock"),
     ))
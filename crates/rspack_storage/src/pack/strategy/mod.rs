@@ -7,7 +7,7 @@ pub use split::SplitPackStrategy;
 
 use super::data::{
   Pack, PackContents, PackFileMeta, PackGenerations, PackKeys, PackOptions, PackScope, RootMeta,
-  RootOptions,
+  RootOptions, SaveReport,
 };
 use crate::{
   ItemKey, ItemValue,
@@ -43,7 +43,7 @@ pub trait RootStrategy {
     root_meta: &RootMeta,
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
-  ) -> Result<()>;
+  ) -> Result<SaveReport>;
   async fn reset(&self);
 }
 
@@ -75,7 +75,7 @@ pub trait PackWriteStrategy {
     options: &PackOptions,
     packs: Vec<(PackFileMeta, Pack)>,
   ) -> Result<UpdatePacksResult>;
-  async fn write_pack(&self, pack: &Pack) -> Result<()>;
+  async fn write_pack(&self, pack: &Pack, options: &PackOptions) -> Result<()>;
 }
 
 #[async_trait]
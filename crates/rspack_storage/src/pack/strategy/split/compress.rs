@@ -0,0 +1,225 @@
+use std::io::{BufRead, Cursor, Read, Seek};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+use crate::{FSError, FSOperation, FileSystem, Reader, error::Result, pack::data::PackCompression};
+
+/// The first line of a compressed pack file, followed by the codec name.
+///
+/// Raw packs start with the key lengths line which only contains digits and spaces,
+/// so the header can not be confused with it.
+const COMPRESSED_HEADER: &str = "#compressed";
+
+#[cfg(not(target_family = "wasm"))]
+const ZSTD_LEVEL: i32 = 3;
+
+pub fn compress_pack(
+  path: &Utf8Path,
+  compression: PackCompression,
+  body: Vec<u8>,
+) -> Result<Vec<u8>> {
+  let compressed = match compression {
+    PackCompression::None => return Ok(body),
+    #[cfg(not(target_family = "wasm"))]
+    PackCompression::Zstd => zstd::encode_all(body.as_slice(), ZSTD_LEVEL).map_err(|e| {
+      FSError::from_message(
+        path,
+        FSOperation::Write,
+        format!("compress pack with zstd failed: {e}"),
+      )
+    })?,
+    #[cfg(target_family = "wasm")]
+    PackCompression::Zstd => {
+      return Err(
+        FSError::from_message(
+          path,
+          FSOperation::Write,
+          "compress pack with zstd is not supported in wasm".to_string(),
+        )
+        .into(),
+      );
+    }
+    PackCompression::Lz4 => lz4_flex::compress_prepend_size(&body),
+  };
+
+  let mut res = format!("{} {}\n", COMPRESSED_HEADER, compression.name()).into_bytes();
+  res.extend(compressed);
+  Ok(res)
+}
+
+fn decompress_pack(
+  path: &Utf8Path,
+  compression: PackCompression,
+  data: Vec<u8>,
+) -> Result<Vec<u8>> {
+  let res = match compression {
+    PackCompression::None => Ok(data),
+    #[cfg(not(target_family = "wasm"))]
+    PackCompression::Zstd => zstd::decode_all(data.as_slice()).map_err(|e| e.to_string()),
+    #[cfg(target_family = "wasm")]
+    PackCompression::Zstd => Err("zstd is not supported in wasm".to_string()),
+    PackCompression::Lz4 => lz4_flex::decompress_size_prepended(&data).map_err(|e| e.to_string()),
+  };
+  Ok(res.map_err(|e| {
+    FSError::from_message(
+      path,
+      FSOperation::Read,
+      format!("decompress pack with {} failed: {e}", compression.name()),
+    )
+  })?)
+}
+
+/// Pack reader which detects the codec by the pack header
+///
+/// Raw packs are streamed from the file system,
+/// compressed packs are decompressed into memory at once.
+#[derive(Debug)]
+pub enum PackReader {
+  File {
+    reader: Reader,
+    first_line: Option<String>,
+  },
+  Memory {
+    path: Utf8PathBuf,
+    cursor: Cursor<Vec<u8>>,
+  },
+}
+
+impl PackReader {
+  pub async fn open(path: &Utf8Path, fs: &dyn FileSystem) -> Result<Self> {
+    let mut reader = fs.read_file(path).await?;
+    let first_line = reader.read_line().await?;
+    let Some(name) = first_line
+      .strip_prefix(COMPRESSED_HEADER)
+      .map(|name| name.trim())
+    else {
+      return Ok(Self::File {
+        reader,
+        first_line: Some(first_line),
+      });
+    };
+
+    let compression = PackCompression::from_name(name).ok_or_else(|| {
+      FSError::from_message(
+        path,
+        FSOperation::Read,
+        format!("unknown pack compression `{name}`"),
+      )
+    })?;
+    let data = decompress_pack(path, compression, reader.read_to_end().await?)?;
+    Ok(Self::Memory {
+      path: path.to_path_buf(),
+      cursor: Cursor::new(data),
+    })
+  }
+
+  pub async fn read_line(&mut self) -> Result<String> {
+    match self {
+      Self::File { reader, first_line } => match first_line.take() {
+        Some(line) => Ok(line),
+        None => Ok(reader.read_line().await?),
+      },
+      Self::Memory { path, cursor } => {
+        let mut buf = vec![];
+        cursor
+          .read_until(b'\n', &mut buf)
+          .map_err(|e| FSError::from_message(path, FSOperation::Read, e.to_string()))?;
+        if buf.last().is_some_and(|b| b == &b'\n') {
+          buf.pop();
+        }
+        Ok(String::from_utf8(buf).map_err(|_| {
+          FSError::from_message(path, FSOperation::Read, "invalid utf8 line".to_string())
+        })?)
+      }
+    }
+  }
+
+  pub async fn read(&mut self, length: usize) -> Result<Vec<u8>> {
+    match self {
+      Self::File { reader, .. } => Ok(reader.read(length).await?),
+      Self::Memory { path, cursor } => {
+        let mut buf = vec![0; length];
+        cursor
+          .read_exact(&mut buf)
+          .map_err(|e| FSError::from_message(path, FSOperation::Read, e.to_string()))?;
+        Ok(buf)
+      }
+    }
+  }
+
+  pub async fn skip(&mut self, offset: usize) -> Result<()> {
+    match self {
+      Self::File { reader, .. } => Ok(reader.skip(offset).await?),
+      Self::Memory { path, cursor } => {
+        cursor
+          .seek_relative(offset as i64)
+          .map_err(|e| FSError::from_message(path, FSOperation::Read, e.to_string()))?;
+        Ok(())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_paths::Utf8PathBuf;
+
+  use super::{PackReader, compress_pack};
+  use crate::{
+    error::Result,
+    pack::{
+      data::PackCompression,
+      strategy::{
+        SplitPackStrategy,
+        split::util::test_pack_utils::{clean_strategy, create_strategies},
+      },
+    },
+  };
+
+  async fn test_roundtrip(
+    dir: &Utf8PathBuf,
+    compression: PackCompression,
+    strategy: &SplitPackStrategy,
+  ) -> Result<()> {
+    let path = dir.join(compression.name());
+    let body = "5 5\n1 2\nkey_1val_1".as_bytes().to_vec();
+    let data = compress_pack(&path, compression, body.clone())?;
+    if matches!(compression, PackCompression::None) {
+      assert_eq!(data, body);
+    } else {
+      assert!(data.starts_with(format!("#compressed {}\n", compression.name()).as_bytes()));
+    }
+
+    strategy
+      .fs
+      .ensure_dir(path.parent().expect("should have parent"))
+      .await?;
+    let mut writer = strategy.fs.write_file(&path).await?;
+    writer.write_all(&data).await?;
+    writer.flush().await?;
+
+    let mut reader = PackReader::open(&path, strategy.fs.as_ref()).await?;
+    assert_eq!(reader.read_line().await?, "5 5");
+    assert_eq!(reader.read_line().await?, "1 2");
+    reader.skip(5).await?;
+    assert_eq!(reader.read(5).await?, "val_1".as_bytes());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn should_read_compressed_pack() -> Result<()> {
+    for strategy in create_strategies("compress") {
+      clean_strategy(&strategy).await;
+      let dir = strategy.root.join("compress");
+      for compression in [
+        PackCompression::None,
+        PackCompression::Zstd,
+        PackCompression::Lz4,
+      ] {
+        test_roundtrip(&dir, compression, &strategy).await?;
+      }
+    }
+    Ok(())
+  }
+}
@@ -8,7 +8,9 @@ use tokio::task::JoinError;
 use crate::{
   FSResult, FileSystem,
   fs::{BatchFSError, BatchFSResult, FSError, FSOperation},
-  pack::data::{PackScope, RootMeta, RootOptions, ScopeMeta, current_time},
+  pack::data::{
+    PackScope, RootMeta, RootOptions, SaveReport, ScopeMeta, VersionInfo, current_time,
+  },
 };
 
 pub async fn prepare_scope(
@@ -395,3 +397,107 @@ pub async fn remove_expired_versions(
   )
   .map(|_| ())
 }
+
+async fn get_version_info(
+  version: String,
+  dir: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<Option<VersionInfo>> {
+  // do not count hidden dirs and lock files
+  if version.starts_with(".") || version.contains(".lock") {
+    return Ok(None);
+  }
+
+  // do not count files
+  if !(fs.metadata(&dir).await?.is_directory) {
+    return Ok(None);
+  }
+
+  let mut size = 0;
+  for file in walk_dir(&dir, fs.clone()).await? {
+    size += fs.metadata(&file).await?.size as usize;
+  }
+
+  // root meta is rewritten on every save, so its mtime is the last used time of the version
+  let meta = RootMeta::get_path(&dir);
+  let last_used = if fs.exists(&meta).await? {
+    fs.metadata(&meta).await?.mtime_ms
+  } else {
+    0
+  };
+
+  Ok(Some(VersionInfo {
+    version,
+    size,
+    last_used,
+  }))
+}
+
+/// Whether the version is being written by another compiler.
+async fn is_version_locked(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> FSResult<bool> {
+  for lock in ["move.lock", "remove.lock"] {
+    if fs.exists(&dir.join(lock)).await? {
+      return Ok(true);
+    }
+  }
+  Ok(false)
+}
+
+/// Evicts the least recently used versions until the total size fits `max_size`.
+///
+/// Versions which are locked or used after `since` may be in use by other compilers,
+/// so they are counted in the total size but never evicted.
+pub async fn evict_versions(
+  root: &Utf8Path,
+  root_options: &RootOptions,
+  since: u64,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<SaveReport> {
+  let Some(max_size) = root_options.max_size else {
+    return Ok(SaveReport::default());
+  };
+
+  let dirs = fs.read_dir(&root_options.root).await?;
+  let infos = join_all(dirs.into_iter().map(|version| {
+    let version_dir = root_options.root.join(&version);
+    get_version_info(version, version_dir, fs.clone())
+  }))
+  .await;
+
+  let mut total_size = 0;
+  let mut candidates = vec![];
+  for info in infos {
+    let Some(info) = info? else {
+      continue;
+    };
+    total_size += info.size;
+    let version_dir = root_options.root.join(&info.version);
+    // the current version is in use, never evict it
+    if version_dir == root || info.last_used >= since {
+      continue;
+    }
+    if is_version_locked(&version_dir, fs.clone()).await? {
+      continue;
+    }
+    candidates.push(info);
+  }
+
+  // evict the least recently used versions first
+  candidates.sort_by_key(|info| info.last_used);
+  let mut evicted = vec![];
+  for info in candidates {
+    if total_size <= max_size {
+      break;
+    }
+    fs.remove_dir(&root_options.root.join(&info.version))
+      .await?;
+    total_size -= info.size;
+    evicted.push(info);
+  }
+
+  Ok(SaveReport {
+    evicted,
+    total_size: Some(total_size),
+    max_size: Some(max_size),
+  })
+}
@@ -1,3 +1,4 @@
+mod compress;
 mod handle_file;
 mod read_pack;
 mod read_scope;
@@ -12,8 +13,8 @@ use std::{
 };
 
 use handle_file::{
-  recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_unused_scope_files,
-  remove_unused_scopes,
+  evict_versions, recovery_move_lock, recovery_remove_lock, remove_expired_versions,
+  remove_unused_scope_files, remove_unused_scopes,
 };
 use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
@@ -26,7 +27,8 @@ use crate::{
   error::{Result, ValidateResult},
   fs::{FSError, FSOperation},
   pack::data::{
-    PackContents, PackKeys, PackScope, RootMeta, RootMetaFrom, RootOptions, current_time,
+    PackContents, PackKeys, PackScope, RootMeta, RootMetaFrom, RootOptions, SaveReport,
+    current_time,
   },
 };
 
@@ -37,6 +39,8 @@ pub struct SplitPackStrategy {
   pub temp_root: Arc<Utf8PathBuf>,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  /// The time this strategy is created, versions used after it are considered in use.
+  pub start_time: u64,
 }
 
 impl SplitPackStrategy {
@@ -53,6 +57,7 @@ impl SplitPackStrategy {
       temp_root: Arc::new(temp_root),
       fresh_generation,
       release_generation,
+      start_time: current_time(),
     }
   }
 
@@ -143,9 +148,9 @@ impl RootStrategy for SplitPackStrategy {
     root_meta: &RootMeta,
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
-  ) -> Result<()> {
+  ) -> Result<SaveReport> {
     if !root_options.clean {
-      return Ok(());
+      return Ok(SaveReport::default());
     }
 
     let _ = tokio::try_join!(
@@ -154,7 +159,9 @@ impl RootStrategy for SplitPackStrategy {
       remove_expired_versions(&self.root, root_options, self.fs.clone())
     );
 
-    Ok(())
+    // evict after the expired versions are removed, so they are not counted in the size budget
+    let report = evict_versions(&self.root, root_options, self.start_time, self.fs.clone()).await?;
+    Ok(report)
   }
 
   async fn reset(&self) {
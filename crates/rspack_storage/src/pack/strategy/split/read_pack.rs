@@ -3,7 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use rspack_paths::Utf8Path;
 
-use super::SplitPackStrategy;
+use super::{SplitPackStrategy, compress::PackReader};
 use crate::{
   FSError, FSOperation,
   error::Result,
@@ -20,7 +20,7 @@ impl PackReadStrategy for SplitPackStrategy {
       return Ok(None);
     }
 
-    let mut reader = self.fs.read_file(path).await?;
+    let mut reader = PackReader::open(path, self.fs.as_ref()).await?;
     // read key lengths
     let key_lengths = reader
       .read_line()
@@ -55,7 +55,7 @@ impl PackReadStrategy for SplitPackStrategy {
       return Ok(None);
     }
 
-    let mut reader = self.fs.read_file(path).await?;
+    let mut reader = PackReader::open(path, self.fs.as_ref()).await?;
     let total_key_length = reader
       .read_line()
       .await?
@@ -375,6 +375,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 16,
+        compression: Default::default(),
       });
       let mut scope = PackScope::new(
         "scope_name",
@@ -85,7 +85,7 @@ mod tests {
     FileSystem,
     error::{Error, ErrorType, Result, ValidateResult},
     pack::{
-      data::{PackOptions, PackScope, RootMeta, ScopeMeta},
+      data::{PackCompression, PackOptions, PackScope, RootMeta, ScopeMeta},
       strategy::{
         ScopeReadStrategy, ScopeValidateStrategy, ScopeWriteStrategy, SplitPackStrategy,
         split::{
@@ -106,6 +106,7 @@ mod tests {
     let same_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 100,
+      compression: Default::default(),
     });
     let mut scope = PackScope::new("scope_name", scope_path, same_options);
     strategy.ensure_meta(&mut scope).await?;
@@ -122,6 +123,7 @@ mod tests {
     let bucket_changed_options = Arc::new(PackOptions {
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
     });
     let mut scope = PackScope::new(
       "scope_name",
@@ -142,6 +144,7 @@ mod tests {
     let max_size_changed_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 99,
+      compression: Default::default(),
     });
     let mut scope = PackScope::new(
       "scope_name",
@@ -212,6 +215,7 @@ mod tests {
       let pack_options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 100,
+        compression: Default::default(),
       });
       mock_scope_meta_file(
         &ScopeMeta::get_path(&scope_path),
@@ -240,46 +244,55 @@ mod tests {
       .await
       .expect("should mock root meta file");
 
-      let scope_path = strategy.get_path("scope_packs");
-      let pack_options = Arc::new(PackOptions {
-        bucket_size: 10,
-        pack_size: 100,
-      });
-      let mut mock_scope = PackScope::empty("scope_name", scope_path.clone(), pack_options.clone());
-      let updates = mock_updates(0, 100, 30, UpdateVal::Value("val".to_string()));
-      strategy
-        .update_scope(&mut mock_scope, updates)
-        .await
-        .expect("should update scope");
+      // packs should be validated no matter whether they are compressed
+      for compression in [
+        PackCompression::None,
+        PackCompression::Zstd,
+        PackCompression::Lz4,
+      ] {
+        let scope_path = strategy.get_path(&format!("scope_packs_{}", compression.name()));
+        let pack_options = Arc::new(PackOptions {
+          bucket_size: 10,
+          pack_size: 100,
+          compression,
+        });
+        let mut mock_scope =
+          PackScope::empty("scope_name", scope_path.clone(), pack_options.clone());
+        let updates = mock_updates(0, 100, 30, UpdateVal::Value("val".to_string()));
+        strategy
+          .update_scope(&mut mock_scope, updates)
+          .await
+          .expect("should update scope");
 
-      prepare_scope(
-        &mock_scope.path,
-        &strategy.root,
-        &strategy.temp_root,
-        strategy.fs.clone(),
-      )
-      .await
-      .expect("should prepare dirs");
-      let changed = save_scope(&mut mock_scope, &strategy)
-        .await
-        .expect("should write scope");
-      strategy
-        .merge_changed(changed.clone())
+        prepare_scope(
+          &mock_scope.path,
+          &strategy.root,
+          &strategy.temp_root,
+          strategy.fs.clone(),
+        )
         .await
-        .expect("should merge changed");
+        .expect("should prepare dirs");
+        let changed = save_scope(&mut mock_scope, &strategy)
+          .await
+          .expect("should write scope");
+        strategy
+          .merge_changed(changed.clone())
+          .await
+          .expect("should merge changed");
 
-      flag_scope_wrote(&mut mock_scope);
+        flag_scope_wrote(&mut mock_scope);
 
-      test_valid_packs(scope_path.clone(), &strategy, pack_options.clone()).await?;
+        test_valid_packs(scope_path.clone(), &strategy, pack_options.clone()).await?;
 
-      test_flush_packs_mtime(
-        scope_path.clone(),
-        &strategy,
-        strategy.fs.clone(),
-        pack_options.clone(),
-        changed.wrote_files,
-      )
-      .await?;
+        test_flush_packs_mtime(
+          scope_path.clone(),
+          &strategy,
+          strategy.fs.clone(),
+          pack_options.clone(),
+          changed.wrote_files,
+        )
+        .await?;
+      }
     }
     Ok(())
   }
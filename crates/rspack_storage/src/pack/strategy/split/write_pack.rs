@@ -6,7 +6,7 @@ use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use super::{SplitPackStrategy, handle_file::redirect_to_path};
+use super::{SplitPackStrategy, compress::compress_pack, handle_file::redirect_to_path};
 use crate::{
   FSError, FSOperation, ItemKey, ItemValue,
   error::Result,
@@ -172,7 +172,7 @@ impl PackWriteStrategy for SplitPackStrategy {
     })
   }
 
-  async fn write_pack(&self, pack: &Pack) -> Result<()> {
+  async fn write_pack(&self, pack: &Pack, options: &PackOptions) -> Result<()> {
     let path = redirect_to_path(&pack.path, &self.root, &self.temp_root)?;
     let keys = pack.keys.expect_value();
     let contents = pack.contents.expect_value();
@@ -181,45 +181,51 @@ impl PackWriteStrategy for SplitPackStrategy {
       panic!("pack keys and contents length not match");
     }
 
-    let mut writer = self.fs.write_file(&path).await?;
+    let mut body = vec![];
 
     // key meta line
-    writer
-      .write_line(
-        keys
-          .iter()
-          .map(|key| key.len().to_string())
-          .join(" ")
-          .as_str(),
-      )
-      .await?;
+    body.extend(
+      keys
+        .iter()
+        .map(|key| key.len().to_string())
+        .join(" ")
+        .as_bytes(),
+    );
+    body.push(b'\n');
 
     // content meta line
-    writer
-      .write_line(
-        contents
-          .iter()
-          .map(|content| content.len().to_string())
-          .join(" ")
-          .as_str(),
-      )
-      .await?;
+    body.extend(
+      contents
+        .iter()
+        .map(|content| content.len().to_string())
+        .join(" ")
+        .as_bytes(),
+    );
+    body.push(b'\n');
 
     // content generation line
-    writer
-      .write_line(generations.iter().map(|g| g.to_string()).join(" ").as_str())
-      .await?;
+    body.extend(
+      generations
+        .iter()
+        .map(|g| g.to_string())
+        .join(" ")
+        .as_bytes(),
+    );
+    body.push(b'\n');
 
     // keys blob
     for key in keys {
-      writer.write(key).await?;
+      body.extend(key.as_slice());
     }
 
     // contents blob
     for content in contents {
-      writer.write(content).await?;
+      body.extend(content.as_slice());
     }
 
+    let data = compress_pack(&path, options.compression, body)?;
+    let mut writer = self.fs.write_file(&path).await?;
+    writer.write_all(&data).await?;
     writer.flush().await?;
 
     Ok(())
@@ -391,9 +397,9 @@ mod tests {
   use crate::{
     error::Result,
     pack::{
-      data::{Pack, PackFileMeta, PackOptions},
+      data::{Pack, PackCompression, PackFileMeta, PackOptions},
       strategy::{
-        PackWriteStrategy, SplitPackStrategy, UpdatePacksResult,
+        PackReadStrategy, PackWriteStrategy, SplitPackStrategy, UpdatePacksResult,
         split::{
           handle_file::redirect_to_path,
           util::test_pack_utils::{UpdateVal, clean_strategy, create_strategies, mock_updates},
@@ -414,7 +420,16 @@ mod tests {
       Arc::new("val_2".as_bytes().to_vec()),
     ]);
     pack.generations = vec![1_usize, 2_usize];
-    strategy.write_pack(&pack).await?;
+    strategy
+      .write_pack(
+        &pack,
+        &PackOptions {
+          bucket_size: 1,
+          pack_size: 100,
+          compression: PackCompression::None,
+        },
+      )
+      .await?;
 
     let mut reader = strategy
       .fs
@@ -434,6 +449,63 @@ mod tests {
     Ok(())
   }
 
+  async fn test_write_compressed_pack(
+    strategy: &SplitPackStrategy,
+    compression: PackCompression,
+  ) -> Result<()> {
+    let dir = strategy.root.join("write_compressed");
+    let mut pack = Pack::new(dir.join(compression.name()));
+    pack.keys.set_value(vec![
+      Arc::new("key_1".as_bytes().to_vec()),
+      Arc::new("key_2".as_bytes().to_vec()),
+    ]);
+    pack.contents.set_value(vec![
+      Arc::new("val_1".as_bytes().to_vec()),
+      Arc::new("val_2".as_bytes().to_vec()),
+    ]);
+    pack.generations = vec![1_usize, 2_usize];
+    strategy
+      .write_pack(
+        &pack,
+        &PackOptions {
+          bucket_size: 1,
+          pack_size: 100,
+          compression,
+        },
+      )
+      .await?;
+
+    let path = redirect_to_path(&pack.path, &strategy.root, &strategy.temp_root)?;
+    let mut reader = strategy.fs.read_file(&path).await?;
+    assert_eq!(
+      reader.read_line().await?,
+      format!("#compressed {}", compression.name())
+    );
+
+    let keys = strategy
+      .read_pack_keys(&path)
+      .await?
+      .expect("should have keys");
+    assert_eq!(
+      keys.iter().map(|key| key.as_slice()).collect_vec(),
+      vec!["key_1".as_bytes(), "key_2".as_bytes()]
+    );
+    let contents = strategy
+      .read_pack_contents(&path)
+      .await?
+      .expect("should have contents");
+    assert_eq!(
+      contents
+        .contents
+        .iter()
+        .map(|content| content.as_slice())
+        .collect_vec(),
+      vec!["val_1".as_bytes(), "val_2".as_bytes()]
+    );
+    assert_eq!(contents.generations, vec![1_usize, 2_usize]);
+    Ok(())
+  }
+
   fn update_packs(update_res: UpdatePacksResult) -> HashMap<PackFileMeta, Pack> {
     update_res
       .remain_packs
@@ -458,6 +530,7 @@ mod tests {
     let options = PackOptions {
       bucket_size: 1,
       pack_size: 2100,
+      compression: Default::default(),
     };
 
     // half pack
@@ -581,6 +654,8 @@ mod tests {
       clean_strategy(&strategy).await;
 
       test_write_pack(&strategy).await?;
+      test_write_compressed_pack(&strategy, PackCompression::Zstd).await?;
+      test_write_compressed_pack(&strategy, PackCompression::Lz4).await?;
     }
     Ok(())
   }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use futures::future::join_all;
 use itertools::Itertools;
@@ -16,7 +18,7 @@ use crate::{
   error::Result,
   fs::BatchFSError,
   pack::{
-    data::{Pack, PackScope},
+    data::{Pack, PackOptions, PackScope},
     strategy::{PackWriteStrategy, ScopeUpdate, ScopeWriteStrategy, WriteScopeResult},
   },
 };
@@ -251,7 +253,7 @@ impl ScopeWriteStrategy for SplitPackStrategy {
       .partition(|x| x.1.wrote);
 
     let (new_packs, new_pack_metas): (Vec<_>, Vec<_>) = new_pack_infos.into_iter().unzip();
-    let write_results = batch_write_packs(new_packs, self).await?;
+    let write_results = batch_write_packs(new_packs, scope.options.clone(), self).await?;
 
     let mut wrote_packs = wrote_pack_infos
       .into_iter()
@@ -334,13 +336,17 @@ impl ScopeWriteStrategy for SplitPackStrategy {
   }
 }
 
-async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String> {
+async fn save_pack(
+  pack: &Pack,
+  options: &PackOptions,
+  strategy: &SplitPackStrategy,
+) -> Result<String> {
   let keys = pack.keys.expect_value();
   let contents = pack.contents.expect_value();
   if keys.len() != contents.len() {
     panic!("pack keys and contents length not match");
   }
-  strategy.write_pack(pack).await?;
+  strategy.write_pack(pack, options).await?;
   let hash = strategy
     .get_pack_hash(
       &redirect_to_path(&pack.path, &strategy.root, &strategy.temp_root)?,
@@ -353,11 +359,17 @@ async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String>
 
 async fn batch_write_packs(
   packs: Vec<Pack>,
+  options: Arc<PackOptions>,
   strategy: &SplitPackStrategy,
 ) -> Result<Vec<(String, Pack)>> {
   let tasks = packs.into_iter().map(|pack| {
     let strategy = strategy.to_owned();
-    tokio::spawn(async move { save_pack(&pack, &strategy).await.map(|hash| (hash, pack)) })
+    let options = options.clone();
+    tokio::spawn(async move {
+      save_pack(&pack, &options, &strategy)
+        .await
+        .map(|hash| (hash, pack))
+    })
   });
 
   let res = BatchFSError::try_from_joined_result(
@@ -584,6 +596,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 36,
+        compression: Default::default(),
       });
       let mut scope = PackScope::empty(
         "scope_name",
@@ -604,6 +617,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 36,
+        compression: Default::default(),
       });
       let mut scope = PackScope::empty(
         "scope_name",
@@ -624,6 +638,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 2100,
+        compression: Default::default(),
       });
       let mut scope = PackScope::empty(
         "scope_name",
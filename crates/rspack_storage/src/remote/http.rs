@@ -0,0 +1,227 @@
+use std::time::Duration;
+
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::TcpStream,
+  time::timeout,
+};
+use url::Url;
+
+use super::RemoteClient;
+use crate::error::{Error, Result};
+
+/// A `RemoteClient` speaking a minimal HTTP/1.1 blob protocol.
+///
+/// `HEAD`, `GET` and `PUT` requests are sent to `<url>/<key>` and a `404` response means
+/// the key does not exist, which is what most HTTP cache servers and S3-compatible gateways
+/// accept. Only plain `http://` urls are supported, TLS should be terminated by a proxy.
+///
+/// Connecting and each request are bounded by timeouts, so a stalled remote fails the
+/// cache operation instead of hanging the build.
+#[derive(Debug, Clone)]
+pub struct HttpRemoteClient {
+  url: String,
+  headers: Vec<(String, String)>,
+  connect_timeout: Duration,
+  request_timeout: Duration,
+}
+
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+struct HttpResponse {
+  status: u16,
+  body: Vec<u8>,
+}
+
+impl HttpRemoteClient {
+  pub fn new(url: String) -> Self {
+    Self {
+      url,
+      headers: vec![],
+      connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+      request_timeout: DEFAULT_REQUEST_TIMEOUT,
+    }
+  }
+
+  /// Set the timeout of establishing the connection.
+  pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+    self.connect_timeout = connect_timeout;
+    self
+  }
+
+  /// Set the timeout of sending the request and reading the whole response.
+  pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+    self.request_timeout = request_timeout;
+    self
+  }
+
+  /// Add a header sent with every request, e.g. `Authorization`.
+  pub fn with_header(mut self, name: String, value: String) -> Self {
+    self.headers.push((name, value));
+    self
+  }
+
+  async fn request(&self, method: &str, key: &str, body: Option<Vec<u8>>) -> Result<HttpResponse> {
+    let url = Url::parse(&self.url)
+      .map_err(|e| request_error(method, key, format!("invalid url `{}`: {e}", self.url)))?;
+    if url.scheme() != "http" {
+      return Err(request_error(
+        method,
+        key,
+        format!("unsupported url scheme `{}`", url.scheme()),
+      ));
+    }
+    let host = url
+      .host_str()
+      .ok_or_else(|| request_error(method, key, format!("no host in url `{}`", self.url)))?;
+    let port = url.port_or_known_default().unwrap_or(80);
+    let path = format!("{}/{key}", url.path().trim_end_matches('/'));
+    let body = body.unwrap_or_default();
+
+    let mut head = format!(
+      "{method} {path} HTTP/1.1\r\nHost: {host}:{port}\r\nConnection: close\r\nContent-Length: {}\r\n",
+      body.len()
+    );
+    for (name, value) in &self.headers {
+      head.push_str(name);
+      head.push_str(": ");
+      head.push_str(value);
+      head.push_str("\r\n");
+    }
+    head.push_str("\r\n");
+
+    let io_error = |e: std::io::Error| request_error(method, key, e.to_string());
+    let mut stream = timeout(self.connect_timeout, TcpStream::connect((host, port)))
+      .await
+      .map_err(|_| {
+        request_error(
+          method,
+          key,
+          format!("connect timeout after {:?}", self.connect_timeout),
+        )
+      })?
+      .map_err(io_error)?;
+
+    let raw = timeout(self.request_timeout, async {
+      stream.write_all(head.as_bytes()).await?;
+      stream.write_all(&body).await?;
+      stream.flush().await?;
+      let mut raw = vec![];
+      stream.read_to_end(&mut raw).await?;
+      Ok::<_, std::io::Error>(raw)
+    })
+    .await
+    .map_err(|_| {
+      request_error(
+        method,
+        key,
+        format!("request timeout after {:?}", self.request_timeout),
+      )
+    })?
+    .map_err(io_error)?;
+    parse_response(&raw, method == "HEAD").map_err(|e| request_error(method, key, e))
+  }
+}
+
+#[async_trait::async_trait]
+impl RemoteClient for HttpRemoteClient {
+  async fn has(&self, key: &str) -> Result<bool> {
+    let res = self.request("HEAD", key, None).await?;
+    match res.status {
+      200..=299 => Ok(true),
+      404 => Ok(false),
+      status => Err(status_error("HEAD", key, status)),
+    }
+  }
+
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    let res = self.request("GET", key, None).await?;
+    match res.status {
+      200..=299 => Ok(Some(res.body)),
+      404 => Ok(None),
+      status => Err(status_error("GET", key, status)),
+    }
+  }
+
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()> {
+    let res = self.request("PUT", key, Some(value)).await?;
+    match res.status {
+      200..=299 => Ok(()),
+      status => Err(status_error("PUT", key, status)),
+    }
+  }
+}
+
+fn parse_response(raw: &[u8], is_head: bool) -> std::result::Result<HttpResponse, String> {
+  let head_end = find(raw, b"\r\n\r\n").ok_or("incomplete response")?;
+  let head = std::str::from_utf8(&raw[..head_end]).map_err(|e| e.to_string())?;
+  let mut lines = head.split("\r\n");
+  let status = lines
+    .next()
+    .and_then(|line| line.split(' ').nth(1))
+    .and_then(|status| status.parse::<u16>().ok())
+    .ok_or("illegal status line")?;
+
+  let mut chunked = false;
+  let mut content_length = None;
+  for (name, value) in lines.filter_map(|line| line.split_once(':')) {
+    let (name, value) = (name.trim(), value.trim());
+    if name.eq_ignore_ascii_case("transfer-encoding") {
+      chunked = value.eq_ignore_ascii_case("chunked");
+    } else if name.eq_ignore_ascii_case("content-length") {
+      content_length = value.parse::<usize>().ok();
+    }
+  }
+
+  let rest = &raw[head_end + 4..];
+  let body = if is_head {
+    vec![]
+  } else if chunked {
+    decode_chunked(rest)?
+  } else if let Some(length) = content_length {
+    rest
+      .get(..length)
+      .ok_or("truncated response body")?
+      .to_vec()
+  } else {
+    rest.to_vec()
+  };
+  Ok(HttpResponse { status, body })
+}
+
+fn decode_chunked(mut raw: &[u8]) -> std::result::Result<Vec<u8>, String> {
+  let mut body = vec![];
+  loop {
+    let line_end = find(raw, b"\r\n").ok_or("illegal chunked body")?;
+    let size = std::str::from_utf8(&raw[..line_end])
+      .ok()
+      .and_then(|line| line.split(';').next())
+      .and_then(|size| usize::from_str_radix(size.trim(), 16).ok())
+      .ok_or("illegal chunk size")?;
+    raw = &raw[line_end + 2..];
+    if size == 0 {
+      return Ok(body);
+    }
+    body.extend_from_slice(raw.get(..size).ok_or("truncated chunk")?);
+    raw = raw.get(size + 2..).ok_or("truncated chunk")?;
+  }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+  haystack
+    .windows(needle.len())
+    .position(|window| window == needle)
+}
+
+fn request_error(method: &str, key: &str, reason: String) -> Error {
+  Error::from_reason(
+    None,
+    None,
+    format!("{method} `{key}` failed due to {reason}"),
+  )
+}
+
+fn status_error(method: &str, key: &str, status: u16) -> Error {
+  request_error(method, key, format!("unexpected status {status}"))
+}
@@ -0,0 +1,299 @@
+#[cfg(not(target_family = "wasm"))]
+mod http;
+
+use std::sync::Arc;
+
+use futures::future::join_all;
+#[cfg(not(target_family = "wasm"))]
+pub use http::HttpRemoteClient;
+use itertools::Itertools;
+use rspack_paths::{AssertUtf8, Utf8Component, Utf8Path, Utf8PathBuf};
+use sha2::{Digest, Sha256};
+use tokio::sync::{Mutex, oneshot, oneshot::Receiver};
+
+use crate::{
+  FileSystem, ItemKey, ItemPairs, ItemValue, PackStorage, PackStorageOptions, SaveReport, Storage,
+  error::{Error, ErrorType, Result},
+};
+
+const ROOT_META_FILE: &str = "storage_meta";
+const MANIFEST_FILE: &str = "manifest";
+
+/// A content-addressed blob store shared between machines.
+///
+/// Keys are `/` separated strings and values are opaque bytes,
+/// a missing key is reported as `Ok(false)` / `Ok(None)` rather than an error.
+#[async_trait::async_trait]
+pub trait RemoteClient: std::fmt::Debug + Sync + Send {
+  async fn has(&self, key: &str) -> Result<bool>;
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()>;
+}
+
+pub struct RemoteStorageOptions {
+  pub local: PackStorageOptions,
+  pub client: Arc<dyn RemoteClient>,
+}
+
+/// Remote Storage
+///
+/// Packs are always written to a local `PackStorage` first, and then uploaded to the remote
+/// as content-addressed blobs (`cas/<sha256>`) together with a manifest under the version
+/// namespace (`<version>/manifest`). When there is no local cache for the version,
+/// the manifest and its packs are downloaded before the first load.
+#[derive(Debug)]
+pub struct RemoteStorage {
+  local: PackStorage,
+  client: Arc<dyn RemoteClient>,
+  fs: Arc<dyn FileSystem>,
+  root: Arc<Utf8PathBuf>,
+  namespace: Arc<String>,
+  restored: Mutex<bool>,
+  uploading: Arc<Mutex<()>>,
+}
+
+impl RemoteStorage {
+  pub fn new(options: RemoteStorageOptions) -> Self {
+    let RemoteStorageOptions { local, client } = options;
+    let root = local.root.join(&local.version).assert_utf8();
+    let namespace = local.version.clone();
+    let fs = local.fs.clone();
+    Self {
+      local: PackStorage::new(local),
+      client,
+      fs,
+      root: Arc::new(root),
+      namespace: Arc::new(namespace),
+      restored: Mutex::new(false),
+      uploading: Default::default(),
+    }
+  }
+
+  async fn ensure_restored(&self) -> Result<()> {
+    let mut restored = self.restored.lock().await;
+    if *restored {
+      return Ok(());
+    }
+    *restored = true;
+
+    if let Err(e) = self.restore().await {
+      // never leave a partially downloaded version behind
+      let _ = self.fs.remove_dir(&self.root).await;
+      return Err(e);
+    }
+    Ok(())
+  }
+
+  #[tracing::instrument("Cache::Storage::remote_restore", skip_all)]
+  async fn restore(&self) -> Result<()> {
+    // the local tier is preferred once it exists
+    if self.fs.exists(&self.root.join(ROOT_META_FILE)).await? {
+      return Ok(());
+    }
+    let Some(manifest) = self.client.get(&manifest_key(&self.namespace)).await? else {
+      return Ok(());
+    };
+    let entries = parse_manifest(&manifest)?;
+
+    let blobs = join_all(entries.iter().map(|(path, hash)| async move {
+      let Some(content) = self.client.get(&blob_key(hash)).await? else {
+        return Err(load_error(format!("blob of `{path}` not found")));
+      };
+      if content_hash(&content) != *hash {
+        return Err(load_error(format!("blob of `{path}` is corrupted")));
+      }
+      Ok((path, content))
+    }))
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+
+    // write root meta at last so that an interrupted restore is never treated as valid
+    let mut root_meta = None;
+    for (path, content) in blobs {
+      if path == ROOT_META_FILE {
+        root_meta = Some(content);
+        continue;
+      }
+      write_file(self.fs.as_ref(), &self.root.join(path), &content).await?;
+    }
+    if let Some(content) = root_meta {
+      write_file(self.fs.as_ref(), &self.root.join(ROOT_META_FILE), &content).await?;
+    }
+    Ok(())
+  }
+}
+
+#[async_trait::async_trait]
+impl Storage for RemoteStorage {
+  async fn load(&self, scope: &'static str) -> Result<ItemPairs> {
+    self.ensure_restored().await?;
+    self.local.load(scope).await
+  }
+  fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
+    self.local.set(scope, key, value);
+  }
+  fn remove(&self, scope: &'static str, key: &[u8]) {
+    self.local.remove(scope, key);
+  }
+  fn trigger_save(&self) -> Result<Receiver<Result<SaveReport>>> {
+    let local_rx = self.local.trigger_save()?;
+    let (tx, rx) = oneshot::channel();
+    let client = self.client.clone();
+    let fs = self.fs.clone();
+    let root = self.root.clone();
+    let namespace = self.namespace.clone();
+    let uploading = self.uploading.clone();
+    tokio::spawn(async move {
+      let res = match local_rx.await {
+        Ok(Ok(report)) => {
+          // uploads are serialized so that manifests are published in saving order
+          let _guard = uploading.lock().await;
+          upload(client.as_ref(), fs.as_ref(), &root, &namespace)
+            .await
+            .map(|_| report)
+        }
+        Ok(Err(e)) => Err(e),
+        Err(e) => Err(Error::from_error(Some(ErrorType::Save), None, Box::new(e))),
+      };
+      let _ = tx.send(res);
+    });
+    Ok(rx)
+  }
+  async fn reset(&self) {
+    // the remote cache of this version is outdated as well, do not download it again
+    *self.restored.lock().await = true;
+    self.local.reset().await;
+  }
+  async fn scopes(&self) -> Result<Vec<String>> {
+    self.ensure_restored().await?;
+    self.local.scopes().await
+  }
+}
+
+#[tracing::instrument("Cache::Storage::remote_upload", skip_all)]
+async fn upload(
+  client: &dyn RemoteClient,
+  fs: &dyn FileSystem,
+  root: &Utf8Path,
+  namespace: &str,
+) -> Result<()> {
+  let files = walk_files(root, fs).await?;
+  let entries = join_all(files.iter().filter_map(|path| {
+    let relative = path
+      .strip_prefix(root)
+      .ok()?
+      .components()
+      .map(|c| c.as_str())
+      .join("/");
+    // lock files only make sense for the local process
+    if relative.ends_with(".lock") {
+      return None;
+    }
+    Some(async move {
+      let content = fs.read_file(path).await?.read_to_end().await?;
+      let hash = content_hash(&content);
+      let key = blob_key(&hash);
+      if !client.has(&key).await? {
+        client.put(&key, content).await?;
+      }
+      Ok((relative, hash))
+    })
+  }))
+  .await
+  .into_iter()
+  .collect::<Result<Vec<_>>>()
+  .map_err(|e| save_error(e.to_string()))?;
+
+  let manifest = entries
+    .iter()
+    .map(|(path, hash)| format!("{hash} {path}\n"))
+    .collect::<String>();
+  client
+    .put(&manifest_key(namespace), manifest.into_bytes())
+    .await
+    .map_err(|e| save_error(e.to_string()))
+}
+
+async fn walk_files(root: &Utf8Path, fs: &dyn FileSystem) -> Result<Vec<Utf8PathBuf>> {
+  let mut files = vec![];
+  if !fs.exists(root).await? {
+    return Ok(files);
+  }
+  let mut stack = vec![root.to_path_buf()];
+  while let Some(path) = stack.pop() {
+    if fs.metadata(&path).await?.is_directory {
+      stack.extend(
+        fs.read_dir(&path)
+          .await?
+          .into_iter()
+          .filter(|name| !name.starts_with('.'))
+          .map(|name| path.join(name)),
+      );
+    } else {
+      files.push(path);
+    }
+  }
+  files.sort();
+  Ok(files)
+}
+
+async fn write_file(fs: &dyn FileSystem, path: &Utf8Path, content: &[u8]) -> Result<()> {
+  let mut writer = fs.write_file(path).await?;
+  writer.write_all(content).await?;
+  writer.flush().await?;
+  Ok(())
+}
+
+fn parse_manifest(manifest: &[u8]) -> Result<Vec<(String, String)>> {
+  let manifest =
+    std::str::from_utf8(manifest).map_err(|e| load_error(format!("illegal manifest: {e}")))?;
+  manifest
+    .lines()
+    .filter(|line| !line.is_empty())
+    .map(|line| {
+      let (hash, path) = line
+        .split_once(' ')
+        .ok_or_else(|| load_error(format!("illegal manifest line `{line}`")))?;
+      // only plain relative paths are allowed so that a downloaded manifest
+      // can never write outside of the cache root
+      let relative = Utf8Path::new(path);
+      if relative.as_str().is_empty()
+        || !relative
+          .components()
+          .all(|c| matches!(c, Utf8Component::Normal(_)))
+      {
+        return Err(load_error(format!("illegal manifest path `{path}`")));
+      }
+      Ok((path.to_string(), hash.to_string()))
+    })
+    .collect()
+}
+
+fn content_hash(content: &[u8]) -> String {
+  hex::encode(Sha256::digest(content))
+}
+
+fn blob_key(hash: &str) -> String {
+  format!("cas/{hash}")
+}
+
+fn manifest_key(namespace: &str) -> String {
+  format!("{namespace}/{MANIFEST_FILE}")
+}
+
+fn load_error(reason: String) -> Error {
+  Error::from_reason(
+    Some(ErrorType::Load),
+    None,
+    format!("remote cache {reason}"),
+  )
+}
+
+fn save_error(reason: String) -> Error {
+  Error::from_reason(
+    Some(ErrorType::Save),
+    None,
+    format!("remote cache {reason}"),
+  )
+}
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test_storage_evict {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageOptions, Result, SaveReport, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_evict")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_evict/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(
+    version: &str,
+    max_size: Option<usize>,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorage {
+    PackStorage::new(PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 2,
+      pack_size: 200,
+      compression: Default::default(),
+      expire: 7 * 24 * 60 * 60 * 1000,
+      max_size,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    })
+  }
+
+  async fn save_storage(
+    storage: PackStorage,
+    version: &str,
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<SaveReport> {
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    let report = rx.await.expect("should save")?;
+    assert!(
+      fs.exists(&root.join(version).join("test_scope/scope_meta"))
+        .await?
+    );
+    Ok(report)
+  }
+
+  async fn save_version(
+    version: &str,
+    max_size: Option<usize>,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<SaveReport> {
+    let storage = create_storage(version, max_size, root, temp_root, fs.clone());
+    save_storage(storage, version, root, fs).await
+  }
+
+  async fn test_no_budget(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    for version in ["v1", "v2"] {
+      let report = save_version(version, None, root, temp_root, fs.clone()).await?;
+      assert!(report.evicted.is_empty());
+      assert!(report.total_size.is_none());
+      assert!(!report.is_over_budget());
+      std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    Ok(())
+  }
+
+  async fn test_large_budget(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let report = save_version("v3", Some(usize::MAX), root, temp_root, fs.clone()).await?;
+    assert!(report.evicted.is_empty());
+    assert!(report.total_size.is_some_and(|size| size > 0));
+    assert!(!report.is_over_budget());
+    assert!(fs.exists(&root.join("v1/test_scope/scope_meta")).await?);
+    assert!(fs.exists(&root.join("v2/test_scope/scope_meta")).await?);
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    Ok(())
+  }
+
+  async fn test_small_budget(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let report = save_version("v4", Some(1), root, temp_root, fs.clone()).await?;
+    // least recently used versions are evicted first, the current version is kept
+    assert_eq!(
+      report
+        .evicted
+        .iter()
+        .map(|info| info.version.as_str())
+        .collect::<Vec<_>>(),
+      vec!["v1", "v2", "v3"]
+    );
+    assert!(report.evicted.iter().all(|info| info.size > 0));
+    assert!(report.is_over_budget());
+    assert!(!(fs.exists(&root.join("v1")).await?));
+    assert!(!(fs.exists(&root.join("v2")).await?));
+    assert!(!(fs.exists(&root.join("v3")).await?));
+    assert!(fs.exists(&root.join("v4/test_scope/scope_meta")).await?);
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    Ok(())
+  }
+
+  async fn test_skip_in_use(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    // v4 is being written by another compiler
+    let mut writer = fs.write_file(&root.join("v4/move.lock")).await?;
+    writer.write_all(root.as_str().as_bytes()).await?;
+    writer.flush().await?;
+
+    // v5 is used by another compiler after v6 is created
+    let storage = create_storage("v6", Some(1), root, temp_root, fs.clone());
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    save_version("v5", None, root, temp_root, fs.clone()).await?;
+
+    let report = save_storage(storage, "v6", root, fs.clone()).await?;
+    assert!(report.evicted.is_empty());
+    assert!(report.is_over_budget());
+    assert!(fs.exists(&root.join("v4/test_scope/scope_meta")).await?);
+    assert!(fs.exists(&root.join("v5/test_scope/scope_meta")).await?);
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_version_evict() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_evict_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_evict_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      test_no_budget(&root, &temp_root, fs.clone()).await?;
+      test_large_budget(&root, &temp_root, fs.clone()).await?;
+      test_small_budget(&root, &temp_root, fs.clone()).await?;
+      test_skip_in_use(&root, &temp_root, fs.clone()).await?;
+    }
+    Ok(())
+  }
+}
@@ -33,7 +33,9 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: Default::default(),
       expire: 0,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
@@ -70,7 +72,9 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: Default::default(),
       expire: 0,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
@@ -97,7 +101,9 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
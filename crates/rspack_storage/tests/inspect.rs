@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod test_storage_inspect {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageOptions, Result, Storage,
+  };
+
+  fn create_storage(root: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> PackStorage {
+    PackStorage::new(PackStorageOptions {
+      version: "xxx".to_string(),
+      root: root.join("cache").into(),
+      temp_root: root.join("temp").into(),
+      fs,
+      bucket_size: 10,
+      pack_size: 200,
+      compression: Default::default(),
+      expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    })
+  }
+
+  async fn test_inspect_empty(root: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_storage(root, fs);
+    assert!(storage.expire_time().await?.is_none());
+    assert!(storage.scope_info("test_scope").await?.is_none());
+    Ok(())
+  }
+
+  async fn test_save(root: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_storage(root, fs);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_inspect_saved(root: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_storage(root, fs);
+    assert!(storage.expire_time().await?.is_some());
+    let info = storage
+      .scope_info("test_scope")
+      .await?
+      .expect("should have scope info");
+    assert_eq!(info.bucket_size, 10);
+    assert_eq!(info.pack_size, 200);
+    assert_eq!(info.generation, 1);
+    assert!(!info.packs.is_empty());
+    assert!(info.packs.iter().all(|pack| pack.size > 0));
+    assert!(info.packs.iter().all(|pack| pack.bucket < 10));
+    assert!(storage.scope_info("other_scope").await?.is_none());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_inspect() -> Result<()> {
+    let root = PathBuf::from("/rspack_test/storage/test_storage_inspect").assert_utf8();
+    let fs: Arc<dyn FileSystem> = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+
+    test_inspect_empty(&root, fs.clone()).await?;
+    test_save(&root, fs.clone()).await?;
+    test_inspect_saved(&root, fs).await?;
+    Ok(())
+  }
+}
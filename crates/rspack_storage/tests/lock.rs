@@ -96,7 +96,9 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
@@ -133,7 +135,9 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
@@ -155,7 +159,9 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
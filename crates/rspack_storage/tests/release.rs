@@ -33,7 +33,9 @@ mod test_storage_release {
       fs,
       bucket_size: 1,
       pack_size: 1000,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod test_storage_remote {
+  use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+  };
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, HttpRemoteClient, PackStorageOptions, RemoteClient,
+    RemoteStorage, RemoteStorageOptions, Result, Storage,
+  };
+  use sha2::{Digest, Sha256};
+  use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+  };
+
+  type Blobs = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+  /// A stand-in for a remote cache server which keeps blobs in memory
+  async fn start_server() -> (String, Blobs) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+      .await
+      .expect("should bind server");
+    let addr = listener.local_addr().expect("should have local addr");
+    let blobs: Blobs = Default::default();
+    let server_blobs = blobs.clone();
+    tokio::spawn(async move {
+      while let Ok((stream, _)) = listener.accept().await {
+        let blobs = server_blobs.clone();
+        tokio::spawn(handle_connection(stream, blobs));
+      }
+    });
+    (format!("http://{addr}/cache"), blobs)
+  }
+
+  async fn handle_connection(stream: TcpStream, blobs: Blobs) {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader
+      .read_line(&mut request_line)
+      .await
+      .expect("should read request line");
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().expect("should have method").to_string();
+    let path = parts.next().expect("should have path").to_string();
+
+    let mut content_length = 0;
+    loop {
+      let mut line = String::new();
+      reader
+        .read_line(&mut line)
+        .await
+        .expect("should read header");
+      let line = line.trim_end();
+      if line.is_empty() {
+        break;
+      }
+      if let Some((name, value)) = line.split_once(':')
+        && name.eq_ignore_ascii_case("content-length")
+      {
+        content_length = value.trim().parse().expect("should parse content length");
+      }
+    }
+    let mut body = vec![0; content_length];
+    reader
+      .read_exact(&mut body)
+      .await
+      .expect("should read body");
+
+    let (status, body) = {
+      let mut blobs = blobs.lock().expect("should get lock");
+      match method.as_str() {
+        "PUT" => {
+          blobs.insert(path, body);
+          ("200 OK", vec![])
+        }
+        "HEAD" => match blobs.get(&path) {
+          Some(_) => ("200 OK", vec![]),
+          None => ("404 Not Found", vec![]),
+        },
+        _ => match blobs.get(&path) {
+          Some(blob) => ("200 OK", blob.clone()),
+          None => ("404 Not Found", vec![]),
+        },
+      }
+    };
+
+    let mut stream = reader.into_inner();
+    let head = format!(
+      "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+      body.len()
+    );
+    stream
+      .write_all(head.as_bytes())
+      .await
+      .expect("should write head");
+    stream.write_all(&body).await.expect("should write body");
+    stream.shutdown().await.expect("should shutdown");
+  }
+
+  fn create_options(
+    root: &Utf8PathBuf,
+    version: &str,
+    fs: Arc<dyn FileSystem>,
+    client: Arc<dyn RemoteClient>,
+  ) -> RemoteStorageOptions {
+    RemoteStorageOptions {
+      local: PackStorageOptions {
+        version: version.to_string(),
+        root: root.join("cache").into(),
+        temp_root: root.join("temp").into(),
+        fs,
+        bucket_size: 10,
+        pack_size: 200,
+        compression: Default::default(),
+        expire: 7 * 24 * 60 * 60 * 1000,
+        max_size: None,
+        clean: true,
+        fresh_generation: Some(1),
+        release_generation: Some(2),
+      },
+      client,
+    }
+  }
+
+  async fn test_upload(options: RemoteStorageOptions, blobs: &Blobs) -> Result<()> {
+    let storage = RemoteStorage::new(options);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+
+    let blobs = blobs.lock().expect("should get lock");
+    assert!(blobs.contains_key("/cache/xxx/manifest"));
+    assert!(blobs.keys().any(|key| key.starts_with("/cache/cas/")));
+    Ok(())
+  }
+
+  async fn test_download(options: RemoteStorageOptions) -> Result<()> {
+    let storage = RemoteStorage::new(options);
+    let data = storage
+      .load("test_scope")
+      .await?
+      .into_iter()
+      .map(|(k, v)| {
+        (
+          String::from_utf8(k.to_vec()).expect("should be utf8"),
+          String::from_utf8(v.to_vec()).expect("should be utf8"),
+        )
+      })
+      .collect::<HashMap<_, _>>();
+    assert_eq!(data.len(), 100);
+    assert_eq!(
+      *data
+        .get(&format!("key_{:0>3}", 42))
+        .expect("should get value"),
+      format!("val_{:0>3}", 42)
+    );
+    assert_eq!(storage.scopes().await?, vec!["test_scope".to_string()]);
+    Ok(())
+  }
+
+  async fn test_missing_namespace(options: RemoteStorageOptions) -> Result<()> {
+    let storage = RemoteStorage::new(options);
+    assert!(storage.load("test_scope").await?.is_empty());
+    Ok(())
+  }
+
+  /// A remote which serves a manifest written by someone else
+  #[derive(Debug, Default)]
+  struct MapRemoteClient(Mutex<HashMap<String, Vec<u8>>>);
+
+  #[async_trait::async_trait]
+  impl RemoteClient for MapRemoteClient {
+    async fn has(&self, key: &str) -> Result<bool> {
+      Ok(self.0.lock().expect("should get lock").contains_key(key))
+    }
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+      Ok(self.0.lock().expect("should get lock").get(key).cloned())
+    }
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<()> {
+      self
+        .0
+        .lock()
+        .expect("should get lock")
+        .insert(key.to_string(), value);
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_illegal_manifest_path() -> Result<()> {
+    let root = PathBuf::from("/rspack_test/storage/test_storage_remote_illegal").assert_utf8();
+    let content = b"evil".to_vec();
+    let hash = hex::encode(Sha256::digest(&content));
+
+    for path in ["/etc/evil", "../evil", "a/../../evil", "./evil", ""] {
+      let client = MapRemoteClient::default();
+      client.put(&format!("cas/{hash}"), content.clone()).await?;
+      client
+        .put("xxx/manifest", format!("{hash} {path}\n").into_bytes())
+        .await?;
+      let fs: Arc<dyn FileSystem> =
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+      let storage = RemoteStorage::new(create_options(&root, "xxx", fs.clone(), Arc::new(client)));
+      assert!(
+        storage.load("test_scope").await.is_err(),
+        "manifest path `{path}` should be rejected"
+      );
+      assert!(!fs.exists(&Utf8PathBuf::from("/etc/evil")).await?);
+    }
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_remote() -> Result<()> {
+    let (url, blobs) = start_server().await;
+    let client: Arc<dyn RemoteClient> = Arc::new(HttpRemoteClient::new(url));
+    let root = PathBuf::from("/rspack_test/storage/test_storage_remote").assert_utf8();
+
+    // every machine has its own local tier
+    let machine_a: Arc<dyn FileSystem> =
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let machine_b: Arc<dyn FileSystem> =
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+
+    test_upload(
+      create_options(&root, "xxx", machine_a, client.clone()),
+      &blobs,
+    )
+    .await?;
+    test_download(create_options(
+      &root,
+      "xxx",
+      machine_b.clone(),
+      client.clone(),
+    ))
+    .await?;
+    assert!(
+      machine_b
+        .exists(&root.join("cache/xxx/storage_meta"))
+        .await?
+    );
+    test_missing_namespace(create_options(&root, "yyy", machine_b, client)).await?;
+    Ok(())
+  }
+}
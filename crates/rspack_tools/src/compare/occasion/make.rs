@@ -29,7 +29,7 @@ pub async fn compare(
   ensure_iter_equal("Make module key", map1.keys(), map2.keys(), &debug_info)?;
 
   // Convert stored data to BuildModuleGraphArtifact using MakeOccasion's recovery ability
-  let context = Arc::new(CacheableContext);
+  let context = Arc::new(CacheableContext::default());
   let occasion1 = MakeOccasion::new(storage1.clone(), context.clone());
   let occasion2 = MakeOccasion::new(storage2.clone(), context.clone());
 
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use rspack_core::cache::persistent::{
+  CacheableContext, build_dependencies, occasion::MakeOccasion, snapshot::Snapshot,
+  storage::Storage,
+};
+use rspack_error::Result;
+use rspack_fs::NativeFileSystem;
+use rspack_paths::Utf8PathBuf;
+
+use crate::{compare::find_relative_cache_path, utils::load_pack_storages_from_path};
+
+/// Explain why the cache entries of modules were invalidated by replaying snapshot validation
+///
+/// Modules are matched by the identifier containing `module`, and `context` should be the
+/// project root used by the compiler to resolve the portable paths in cache.
+pub async fn explain_module(path: Utf8PathBuf, context: Utf8PathBuf, module: String) -> Result<()> {
+  let fs = Arc::new(NativeFileSystem::new(false));
+  let cache_context = Arc::new(CacheableContext::new(Some(context.into_std_path_buf())));
+
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+
+    for (version, storage) in load_pack_storages_from_path(&cache_path) {
+      println!("{cache_path} version {version}:");
+      let storage: Arc<dyn Storage> = Arc::new(storage);
+
+      // any changed build dependency invalidates the whole cache
+      let build_deps_snapshot = Snapshot::new_with_scope(
+        build_dependencies::SCOPE,
+        Default::default(),
+        fs.clone(),
+        storage.clone(),
+        cache_context.clone(),
+      );
+      let (_, modified_paths, deleted_paths, _) = build_deps_snapshot.calc_modified_paths().await?;
+      if !modified_paths.is_empty() || !deleted_paths.is_empty() {
+        for path in modified_paths.iter().sorted_by_key(|p| p.to_path_buf()) {
+          println!("  build dependency modified: {}", path.display());
+        }
+        for path in deleted_paths.iter().sorted_by_key(|p| p.to_path_buf()) {
+          println!("  build dependency deleted: {}", path.display());
+        }
+        println!("  the whole cache is invalidated");
+        continue;
+      }
+
+      let snapshot = Snapshot::new(
+        Default::default(),
+        fs.clone(),
+        storage.clone(),
+        cache_context.clone(),
+      );
+      let (_, modified_paths, deleted_paths, _) = snapshot.calc_modified_paths().await?;
+
+      let artifact = MakeOccasion::new(storage.clone(), cache_context.clone())
+        .recovery()
+        .await?;
+      let modules = artifact.module_graph.modules();
+      let matched = modules
+        .iter()
+        .filter(|(identifier, _)| identifier.contains(module.as_str()))
+        .sorted_by_key(|(identifier, _)| identifier.as_str())
+        .collect_vec();
+      if matched.is_empty() {
+        println!("  no cached module matches `{module}`");
+        continue;
+      }
+
+      for (identifier, m) in matched {
+        println!("  module {identifier}:");
+        let build_info = m.build_info();
+        if !build_info.cacheable {
+          println!("    not cacheable, always rebuilt");
+          continue;
+        }
+        let mut reasons = vec![];
+        for (kind, deps) in [
+          ("file", &build_info.file_dependencies),
+          ("context", &build_info.context_dependencies),
+          ("missing", &build_info.missing_dependencies),
+          ("build", &build_info.build_dependencies),
+        ] {
+          for dep in deps.iter().sorted_by_key(|p| p.to_path_buf()) {
+            if modified_paths.contains(dep) {
+              reasons.push(format!("{kind} dependency modified: {}", dep.display()));
+            } else if deleted_paths.contains(dep) {
+              reasons.push(format!("{kind} dependency deleted: {}", dep.display()));
+            }
+          }
+        }
+        if reasons.is_empty() {
+          println!("    valid, no dependency changed");
+        }
+        for reason in reasons {
+          println!("    invalidated, {reason}");
+        }
+      }
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,52 @@
+use itertools::Itertools;
+use rspack_error::Result;
+use rspack_fs::{NativeFileSystem, ReadableFileSystem, WritableFileSystem};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_util::current_time;
+
+use crate::{compare::find_relative_cache_path, utils::load_pack_storages_from_path};
+
+/// Whether the version is being written by a running compiler, e.g. an incomplete version
+/// which is saving, or a version which is moving or removing its files.
+async fn is_version_locked(fs: &NativeFileSystem, version_path: &Utf8Path) -> bool {
+  for lock in ["move.lock", "remove.lock"] {
+    if fs.metadata(&version_path.join(lock)).await.is_ok() {
+      return true;
+    }
+  }
+  false
+}
+
+/// Remove the expired and incomplete versions of the cache in directory
+///
+/// This is the same as the cleaning after the storage saved, but covers all versions.
+pub async fn gc_cache_dir(path: Utf8PathBuf, dry_run: bool) -> Result<()> {
+  let fs = NativeFileSystem::new(false);
+  let now = current_time();
+  let mut removed = 0;
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+
+    for (version, storage) in load_pack_storages_from_path(&cache_path) {
+      let version_path = cache_path.join(&version);
+      if is_version_locked(&fs, &version_path).await {
+        println!("Skipped {version_path} (locked)");
+        continue;
+      }
+      let reason = match storage.expire_time().await? {
+        Some(expire_time) if expire_time <= now => "expired",
+        Some(_) => continue,
+        None => "incomplete",
+      };
+      if dry_run {
+        println!("Would remove {version_path} ({reason})");
+      } else {
+        fs.remove_dir_all(&version_path).await?;
+        println!("Removed {version_path} ({reason})");
+      }
+      removed += 1;
+    }
+  }
+  println!("{removed} versions collected");
+  Ok(())
+}
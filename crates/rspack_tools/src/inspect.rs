@@ -0,0 +1,51 @@
+use itertools::Itertools;
+use rspack_error::Result;
+use rspack_paths::Utf8PathBuf;
+use rspack_storage::Storage;
+
+use crate::{
+  compare::find_relative_cache_path,
+  utils::{format_expire_time, known_scope, load_pack_storages_from_path},
+};
+
+/// Print the versions, scopes and packs of the cache in directory
+pub async fn inspect_cache_dir(path: Utf8PathBuf, show_packs: bool) -> Result<()> {
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+    println!("{cache_path}");
+
+    for (version, storage) in load_pack_storages_from_path(&cache_path) {
+      let Some(expire_time) = storage.expire_time().await? else {
+        println!("  version {version}: incomplete, no storage meta");
+        continue;
+      };
+      println!("  version {version}: {}", format_expire_time(expire_time));
+
+      for scope in storage.scopes().await?.iter().sorted() {
+        let info = match known_scope(scope) {
+          Some(scope) => storage.scope_info(scope).await?,
+          None => None,
+        };
+        let Some(info) = info else {
+          println!("    scope {scope}: unknown scope");
+          continue;
+        };
+        let size: usize = info.packs.iter().map(|pack| pack.size).sum();
+        println!(
+          "    scope {scope}: {} packs, {size} bytes, generation {}",
+          info.packs.len(),
+          info.generation
+        );
+        if show_packs {
+          for pack in &info.packs {
+            println!(
+              "      {}/{}: {} bytes, generation {}",
+              pack.bucket, pack.name, pack.size, pack.generation
+            );
+          }
+        }
+      }
+    }
+  }
+  Ok(())
+}
@@ -1,5 +1,13 @@
 mod compare;
 mod debug_info;
+mod explain;
+mod gc;
+mod inspect;
+mod stats;
 mod utils;
 
 pub use compare::compare_cache_dir;
+pub use explain::explain_module;
+pub use gc::gc_cache_dir;
+pub use inspect::inspect_cache_dir;
+pub use stats::stats_cache_dir;
@@ -1,7 +1,9 @@
 use clap::{Parser, Subcommand};
-use rspack_error::Diagnostic;
+use rspack_error::{Diagnostic, Error};
 use rspack_paths::Utf8PathBuf;
-use rspack_tools::compare_cache_dir;
+use rspack_tools::{
+  compare_cache_dir, explain_module, gc_cache_dir, inspect_cache_dir, stats_cache_dir,
+};
 
 /// Toolkit for debugging and testing rspack internals
 #[derive(Parser, Debug)]
@@ -25,6 +27,56 @@ enum Commands {
     #[arg(value_name = "CACHE2")]
     cache2: String,
   },
+  /// List the versions, scopes and packs of a cache directory
+  Inspect {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Print every pack file
+    #[arg(long)]
+    packs: bool,
+  },
+  /// Print the key and value byte totals of each scope in a cache directory
+  Stats {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+  },
+  /// Remove expired and incomplete versions from a cache directory
+  Gc {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Only print the versions which would be removed
+    #[arg(long)]
+    dry_run: bool,
+  },
+  /// Explain why the cache entry of a module was invalidated
+  Explain {
+    /// Path to the cache directory
+    #[arg(value_name = "CACHE")]
+    cache: String,
+
+    /// Part of the module identifier
+    #[arg(value_name = "MODULE")]
+    module: String,
+
+    /// The compiler context used to resolve paths in cache, defaults to current directory
+    #[arg(long)]
+    context: Option<String>,
+  },
+}
+
+fn exit_with_error(err: Error) -> ! {
+  eprintln!(
+    "{}",
+    Diagnostic::from(err)
+      .render_report(true)
+      .expect("render error failed")
+  );
+  std::process::exit(1);
 }
 
 #[tokio::main]
@@ -42,16 +94,41 @@ async fn main() {
       let path2 = Utf8PathBuf::from(&cache2);
 
       if let Err(err) = compare_cache_dir(path1, path2).await {
-        eprintln!(
-          "{}",
-          Diagnostic::from(err)
-            .render_report(true)
-            .expect("render error failed")
-        );
-        std::process::exit(1);
+        exit_with_error(err);
       }
 
       println!("✓ Cache directories are identical");
     }
+    Commands::Inspect { cache, packs } => {
+      if let Err(err) = inspect_cache_dir(Utf8PathBuf::from(&cache), packs).await {
+        exit_with_error(err);
+      }
+    }
+    Commands::Stats { cache } => {
+      if let Err(err) = stats_cache_dir(Utf8PathBuf::from(&cache)).await {
+        exit_with_error(err);
+      }
+    }
+    Commands::Gc { cache, dry_run } => {
+      if let Err(err) = gc_cache_dir(Utf8PathBuf::from(&cache), dry_run).await {
+        exit_with_error(err);
+      }
+    }
+    Commands::Explain {
+      cache,
+      module,
+      context,
+    } => {
+      let context = match context {
+        Some(context) => Utf8PathBuf::from(context),
+        None => {
+          Utf8PathBuf::from_path_buf(std::env::current_dir().expect("should get current directory"))
+            .expect("should be utf8 path")
+        }
+      };
+      if let Err(err) = explain_module(Utf8PathBuf::from(&cache), context, module).await {
+        exit_with_error(err);
+      }
+    }
   }
 }
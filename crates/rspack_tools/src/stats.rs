@@ -0,0 +1,44 @@
+use itertools::Itertools;
+use rspack_error::Result;
+use rspack_paths::Utf8PathBuf;
+use rspack_storage::Storage;
+
+use crate::{
+  compare::find_relative_cache_path,
+  utils::{known_scope, load_pack_storages_from_path},
+};
+
+/// Print the key and value byte totals of each scope of the cache in directory
+pub async fn stats_cache_dir(path: Utf8PathBuf) -> Result<()> {
+  for cache_relative_path in find_relative_cache_path(&path).iter().sorted() {
+    let cache_path = path.join(cache_relative_path);
+    println!("{cache_path}");
+
+    for (version, storage) in load_pack_storages_from_path(&cache_path) {
+      println!("  version {version}:");
+      let mut total = 0;
+      for scope in storage.scopes().await?.iter().sorted() {
+        let Some(static_scope) = known_scope(scope) else {
+          println!("    scope {scope}: unknown scope");
+          continue;
+        };
+        let data = match storage.load(static_scope).await {
+          Ok(data) => data,
+          Err(err) => {
+            println!("    scope {scope}: load failed, {err}");
+            continue;
+          }
+        };
+        let key_size: usize = data.iter().map(|(key, _)| key.len()).sum();
+        let value_size: usize = data.iter().map(|(_, value)| value.len()).sum();
+        total += key_size + value_size;
+        println!(
+          "    scope {scope}: {} items, keys {key_size} bytes, values {value_size} bytes",
+          data.len()
+        );
+      }
+      println!("    total: {total} bytes");
+    }
+  }
+  Ok(())
+}
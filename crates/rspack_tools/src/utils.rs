@@ -1,4 +1,16 @@
+use std::sync::Arc;
+
+use rspack_core::cache::persistent::{
+  build_dependencies,
+  occasion::{chunk_render, code_generate, make, meta, minify},
+  snapshot,
+  storage::create_pack_options,
+};
 use rspack_error::{Result, error};
+use rspack_fs::{NativeFileSystem, ReadableFileSystem};
+use rspack_paths::Utf8PathBuf;
+use rspack_storage::PackStorage;
+use rspack_util::current_time;
 use rustc_hash::FxHashSet as HashSet;
 
 use super::debug_info::DebugInfo;
@@ -38,3 +50,59 @@ where
 
   Ok(())
 }
+
+/// Find the static scope name used by rspack core, return None for unknown scopes
+pub fn known_scope(name: &str) -> Option<&'static str> {
+  [
+    snapshot::SCOPE,
+    build_dependencies::SCOPE,
+    meta::SCOPE,
+    make::SCOPE,
+    code_generate::SCOPE,
+    chunk_render::SCOPE,
+    minify::SCOPE,
+  ]
+  .into_iter()
+  .find(|scope| *scope == name)
+}
+
+/// Load all version pack storages from a directory path, sorted by version name
+pub fn load_pack_storages_from_path(path: &Utf8PathBuf) -> Vec<(String, PackStorage)> {
+  let fs = Arc::new(NativeFileSystem::new(false));
+
+  let Ok(mut versions) = fs.read_dir_sync(path.as_path()) else {
+    return vec![];
+  };
+  versions.sort();
+
+  versions
+    .into_iter()
+    // skip hidden dirs and lock files
+    .filter(|v| !v.starts_with('.') && !v.contains(".lock"))
+    .filter(|v| {
+      fs.metadata_sync(&path.join(v))
+        .is_ok_and(|meta| meta.is_directory)
+    })
+    .map(|v| {
+      let storage = PackStorage::new(create_pack_options(
+        path.clone().into(),
+        v.clone(),
+        None,
+        fs.clone(),
+      ));
+      (v, storage)
+    })
+    .collect()
+}
+
+/// Describe the storage expire time relative to now
+pub fn format_expire_time(expire_time: u64) -> String {
+  let now = current_time();
+  if expire_time <= now {
+    let hours = (now - expire_time) / 1000 / 60 / 60;
+    format!("expired {hours}h ago")
+  } else {
+    let hours = (expire_time - now) / 1000 / 60 / 60;
+    format!("expires in {hours}h")
+  }
+}
@@ -10,7 +10,7 @@ use tokio::sync::{
 };
 
 use super::{EventAggregateHandler, EventHandler, FsEventKind};
-use crate::EventBatch;
+use crate::{EventBatch, journal::Journal};
 
 type ThreadSafetyReceiver<T> = ThreadSafety<UnboundedReceiver<T>>;
 type ThreadSafety<T> = Arc<Mutex<T>>;
@@ -44,6 +44,7 @@ pub struct Executor {
   start_waiting: bool,
   execute_handle: Option<tokio::task::JoinHandle<()>>,
   execute_aggregate_handle: Option<tokio::task::JoinHandle<()>>,
+  journal: Option<Arc<Journal>>,
 }
 
 const DEFAULT_AGGREGATE_TIMEOUT: u32 = 50; // Default timeout in milliseconds
@@ -66,7 +67,11 @@ enum ExecEvent {
 
 impl Executor {
   /// Create a new `WatcherExecutor` with the given receiver and optional aggregate timeout.
-  pub fn new(rx: UnboundedReceiver<EventBatch>, aggregate_timeout: Option<u32>) -> Self {
+  pub fn new(
+    rx: UnboundedReceiver<EventBatch>,
+    aggregate_timeout: Option<u32>,
+    journal: Option<Arc<Journal>>,
+  ) -> Self {
     let (exec_aggregate_tx, exec_aggregate_rx) = mpsc::unbounded_channel::<ExecAggregateEvent>();
     let (exec_tx, exec_rx) = mpsc::unbounded_channel::<ExecEvent>();
 
@@ -83,6 +88,7 @@ impl Executor {
       execute_aggregate_handle: None,
       execute_handle: None,
       aggregate_timeout: aggregate_timeout.unwrap_or(DEFAULT_AGGREGATE_TIMEOUT),
+      journal,
     }
   }
 
@@ -184,6 +190,7 @@ impl Executor {
       Arc::clone(&self.files_data),
       self.aggregate_timeout as u64,
       Arc::clone(&self.aggregate_running),
+      self.journal.clone(),
     ));
 
     self.execute_handle = Some(create_execute_task(
@@ -233,6 +240,7 @@ fn create_execute_aggregate_task(
   files: ThreadSafety<FilesData>,
   aggregate_timeout: u64,
   running: Arc<AtomicBool>,
+  journal: Option<Arc<Journal>>,
 ) -> tokio::task::JoinHandle<()> {
   let future = async move {
     loop {
@@ -261,6 +269,10 @@ fn create_execute_aggregate_task(
           std::mem::take(&mut *files)
         };
 
+        if let Some(journal) = &journal {
+          journal.record_aggregated(&files.changed, &files.deleted);
+        }
+
         // Call the event handler with the changed and deleted files
         event_handler.on_event_handle(files.changed, files.deleted);
         running.store(false, Ordering::Relaxed);
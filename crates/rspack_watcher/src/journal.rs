@@ -0,0 +1,292 @@
+use std::{
+  fs::File,
+  io::{BufRead, BufReader, BufWriter, Write},
+  path::{Path, PathBuf},
+  sync::{
+    Mutex,
+    atomic::{AtomicBool, Ordering},
+  },
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use rspack_error::Result;
+use rspack_paths::ArcPath;
+use rspack_util::fx_hash::FxHashSet as HashSet;
+
+use crate::{FsEvent, FsEventKind};
+
+/// An entry recorded in the watcher journal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalEntry {
+  /// A raw event received by the watcher, from native events or polling.
+  Event { kind: FsEventKind, path: ArcPath },
+  /// A batch of events sent by the scanner for the paths changed or removed before watching.
+  Scanned { events: Vec<(FsEventKind, ArcPath)> },
+  /// A path which is not watched because it matches `FsWatcherIgnored`.
+  Ignored { path: String },
+  /// A batch of events aggregated by the executor and passed to the `EventAggregateHandler`.
+  Aggregated {
+    changed: Vec<String>,
+    deleted: Vec<String>,
+  },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalRecord {
+  /// Milliseconds since unix epoch when the entry is recorded.
+  pub timestamp: u128,
+  pub entry: JournalEntry,
+}
+
+fn kind_to_str(kind: FsEventKind) -> &'static str {
+  match kind {
+    FsEventKind::Change => "change",
+    FsEventKind::Remove => "remove",
+    FsEventKind::Create => "create",
+  }
+}
+
+fn kind_from_str(kind: &str) -> Option<FsEventKind> {
+  match kind {
+    "change" => Some(FsEventKind::Change),
+    "remove" => Some(FsEventKind::Remove),
+    "create" => Some(FsEventKind::Create),
+    _ => None,
+  }
+}
+
+/// `Journal` records the events of the watcher into a file line by line.
+///
+/// The format of each line is `<timestamp> <tag> [fields...]`, the path is always the last field
+/// so it can contain spaces:
+/// - `<timestamp> event <kind> <path>`
+/// - `<timestamp> scan <count>`, followed by `<timestamp> scanned <kind> <path>` lines.
+/// - `<timestamp> ignore <path>`
+/// - `<timestamp> aggregate <changed count> <deleted count>`,
+///   followed by `<timestamp> changed <path>` and `<timestamp> deleted <path>` lines.
+#[derive(Debug)]
+pub struct Journal {
+  /// The absolute path of the journal file.
+  path: PathBuf,
+  writer: Mutex<BufWriter<File>>,
+  /// Whether the journal stops recording, see [`Journal::stop`].
+  stopped: AtomicBool,
+}
+
+impl Journal {
+  /// Creates the journal file, the existing file will be truncated.
+  pub fn create(path: &Path) -> Result<Self> {
+    let file = File::create(path).map_err(|e| {
+      rspack_error::error!("failed to create watcher journal {}: {e}", path.display())
+    })?;
+    Ok(Self {
+      path: std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+      writer: Mutex::new(BufWriter::new(file)),
+      stopped: AtomicBool::new(false),
+    })
+  }
+
+  pub fn record_event(&self, path: &ArcPath, kind: FsEventKind) {
+    self.record_events(std::iter::once((path, kind)));
+  }
+
+  pub fn record_events<'a>(&self, events: impl Iterator<Item = (&'a ArcPath, FsEventKind)>) {
+    self.write_lines(
+      events
+        // the journal file may be located in a watched directory,
+        // skip its own events to avoid recording endlessly.
+        .filter(|(path, _)| path.as_os_str() != self.path.as_os_str())
+        .map(|(path, kind)| format!("event {} {}", kind_to_str(kind), path.to_string_lossy()))
+        .collect(),
+    );
+  }
+
+  pub fn record_scanned(&self, events: &[FsEvent]) {
+    let mut lines = vec![format!("scan {}", events.len())];
+    lines.extend(events.iter().map(|event| {
+      format!(
+        "scanned {} {}",
+        kind_to_str(event.kind),
+        event.path.to_string_lossy()
+      )
+    }));
+    self.write_lines(lines);
+  }
+
+  pub fn record_ignored(&self, path: &str) {
+    self.write_lines(vec![format!("ignore {path}")]);
+  }
+
+  pub fn record_aggregated(&self, changed: &HashSet<String>, deleted: &HashSet<String>) {
+    let mut lines = vec![format!("aggregate {} {}", changed.len(), deleted.len())];
+    lines.extend(changed.iter().map(|path| format!("changed {path}")));
+    lines.extend(deleted.iter().map(|path| format!("deleted {path}")));
+    self.write_lines(lines);
+  }
+
+  /// Stops recording, the following records are dropped.
+  ///
+  /// It is used by replaying, so the replayed events are not recorded again.
+  pub fn stop(&self) {
+    self.stopped.store(true, Ordering::Relaxed);
+  }
+
+  fn write_lines(&self, lines: Vec<String>) {
+    if lines.is_empty() || self.stopped.load(Ordering::Relaxed) {
+      return;
+    }
+    let timestamp = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_millis())
+      .unwrap_or_default();
+    let mut writer = self.writer.lock().expect("should lock journal writer");
+    let res = lines
+      .iter()
+      .try_for_each(|line| writeln!(writer, "{timestamp} {line}"))
+      .and_then(|_| writer.flush());
+    if let Err(e) = res {
+      eprintln!("Error in writing watcher journal: {e:?}");
+    }
+  }
+}
+
+/// Splits a journal line into timestamp, tag and the rest fields.
+fn split_line(line: &str) -> Option<(u128, &str, &str)> {
+  let mut fields = line.splitn(3, ' ');
+  let timestamp = fields.next()?.parse::<u128>().ok()?;
+  let tag = fields.next()?;
+  let rest = fields.next()?;
+  Some((timestamp, tag, rest))
+}
+
+/// Reads the records from a journal file written by the watcher.
+pub fn read_journal(path: &Path) -> Result<Vec<JournalRecord>> {
+  let file = File::open(path)
+    .map_err(|e| rspack_error::error!("failed to open watcher journal {}: {e}", path.display()))?;
+  let lines = BufReader::new(file)
+    .lines()
+    .collect::<std::io::Result<Vec<_>>>()
+    .map_err(|e| rspack_error::error!("failed to read watcher journal: {e}"))?;
+  let invalid_line = |line: &str| rspack_error::error!("invalid watcher journal line `{line}`");
+
+  let mut records = vec![];
+  let mut lines = lines.iter().filter(|line| !line.is_empty());
+  while let Some(line) = lines.next() {
+    let (timestamp, tag, rest) = split_line(line).ok_or_else(|| invalid_line(line))?;
+    let entry = match tag {
+      "event" => {
+        let (kind, path) = rest.split_once(' ').ok_or_else(|| invalid_line(line))?;
+        JournalEntry::Event {
+          kind: kind_from_str(kind).ok_or_else(|| invalid_line(line))?,
+          path: ArcPath::from(path),
+        }
+      }
+      "scan" => {
+        let count = rest.parse::<usize>().map_err(|_| invalid_line(line))?;
+        let mut events = Vec::with_capacity(count);
+        for _ in 0..count {
+          let path_line = lines.next().ok_or_else(|| invalid_line(line))?;
+          let event = match split_line(path_line) {
+            Some((_, "scanned", rest)) => rest
+              .split_once(' ')
+              .and_then(|(kind, path)| Some((kind_from_str(kind)?, ArcPath::from(path)))),
+            _ => None,
+          };
+          events.push(event.ok_or_else(|| invalid_line(path_line))?);
+        }
+        JournalEntry::Scanned { events }
+      }
+      "ignore" => JournalEntry::Ignored {
+        path: rest.to_string(),
+      },
+      "aggregate" => {
+        let (changed_count, deleted_count) = rest
+          .split_once(' ')
+          .and_then(|(changed, deleted)| {
+            Some((
+              changed.parse::<usize>().ok()?,
+              deleted.parse::<usize>().ok()?,
+            ))
+          })
+          .ok_or_else(|| invalid_line(line))?;
+        let mut changed = Vec::with_capacity(changed_count);
+        let mut deleted = Vec::with_capacity(deleted_count);
+        for index in 0..changed_count + deleted_count {
+          let (expected_tag, paths) = if index < changed_count {
+            ("changed", &mut changed)
+          } else {
+            ("deleted", &mut deleted)
+          };
+          let path_line = lines.next().ok_or_else(|| invalid_line(line))?;
+          match split_line(path_line) {
+            Some((_, tag, path)) if tag == expected_tag => paths.push(path.to_string()),
+            _ => return Err(invalid_line(path_line)),
+          }
+        }
+        JournalEntry::Aggregated { changed, deleted }
+      }
+      _ => return Err(invalid_line(line)),
+    };
+    records.push(JournalRecord { timestamp, entry });
+  }
+
+  Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_journal_roundtrip() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let path = dir.path().join("journal.log");
+    let journal = Journal::create(&path).expect("should create journal");
+
+    let file = ArcPath::from(dir.path().join("src/index with space.js"));
+    journal.record_event(&file, FsEventKind::Change);
+    // the events of the journal itself are skipped
+    journal.record_event(&ArcPath::from(path.clone()), FsEventKind::Change);
+    let removed = ArcPath::from(dir.path().join("src/removed.js"));
+    journal.record_scanned(&[FsEvent {
+      path: removed.clone(),
+      kind: FsEventKind::Remove,
+    }]);
+    journal.record_ignored("/project/node_modules/a.js");
+    let mut changed = HashSet::default();
+    changed.insert(file.to_string_lossy().to_string());
+    let mut deleted = HashSet::default();
+    deleted.insert("/project/src/b.js".to_string());
+    journal.record_aggregated(&changed, &deleted);
+
+    let entries = read_journal(&path)
+      .expect("should read journal")
+      .into_iter()
+      .map(|record| record.entry)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      entries,
+      vec![
+        JournalEntry::Event {
+          kind: FsEventKind::Change,
+          path: file.clone(),
+        },
+        JournalEntry::Scanned {
+          events: vec![(FsEventKind::Remove, removed)],
+        },
+        JournalEntry::Ignored {
+          path: "/project/node_modules/a.js".to_string(),
+        },
+        JournalEntry::Aggregated {
+          changed: vec![file.to_string_lossy().to_string()],
+          deleted: vec!["/project/src/b.js".to_string()],
+        },
+      ]
+    );
+
+    // nothing is recorded after the journal stops
+    journal.stop();
+    journal.record_event(&file, FsEventKind::Remove);
+    assert_eq!(read_journal(&path).expect("should read journal").len(), 4);
+  }
+}
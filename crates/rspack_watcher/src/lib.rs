@@ -2,17 +2,27 @@ mod analyzer;
 mod disk_watcher;
 mod executor;
 mod ignored;
+mod journal;
 mod paths;
+mod poller;
 mod scanner;
 mod trigger;
 
-use std::{sync::Arc, time::SystemTime};
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
 
 use analyzer::{Analyzer, RecommendedAnalyzer};
 use disk_watcher::DiskWatcher;
 use executor::Executor;
 pub use ignored::FsWatcherIgnored;
+use journal::Journal;
+pub use journal::{JournalEntry, JournalRecord, read_journal};
 use paths::PathManager;
+pub use poller::FsWatcherPolling;
+use poller::Poller;
 use rspack_error::Result;
 use rspack_paths::ArcPath;
 use rspack_util::fx_hash::FxHashSet as HashSet;
@@ -80,13 +90,21 @@ pub struct FsWatcherOptions {
   /// The interval in milliseconds to poll for changes.
   pub poll_interval: Option<u32>,
 
+  /// Which watched paths are checked by polling their stats instead of native events.
+  pub polling: FsWatcherPolling,
+
   /// The timeout in milliseconds to aggregate events.
   pub aggregate_timeout: Option<u32>,
+
+  /// The file to record the raw events, ignore decisions and aggregated event batches,
+  /// it can be read by [`read_journal`] and replayed by [`FsWatcher::replay`].
+  pub journal: Option<PathBuf>,
 }
 
 pub struct FsWatcher {
   path_manager: Arc<PathManager>,
   disk_watcher: DiskWatcher,
+  poller: Poller,
   executor: Executor,
   scanner: Scanner,
   analyzer: RecommendedAnalyzer,
@@ -98,18 +116,31 @@ impl FsWatcher {
   pub fn new(options: FsWatcherOptions, ignored: FsWatcherIgnored) -> Self {
     let (tx, rx) = mpsc::unbounded_channel();
 
-    let path_manager = Arc::new(PathManager::new(ignored));
+    let journal = options.journal.and_then(|path| {
+      Journal::create(&path)
+        .inspect_err(|e| eprintln!("Error in creating watcher journal: {e:?}"))
+        .ok()
+        .map(Arc::new)
+    });
+    let path_manager = Arc::new(PathManager::new(ignored, journal.clone()));
     let trigger = Arc::new(Trigger::new(Arc::clone(&path_manager), tx.clone()));
     let disk_watcher = DiskWatcher::new(
       options.follow_symlinks,
       options.poll_interval,
       trigger.clone(),
     );
-    let executor = Executor::new(rx, options.aggregate_timeout);
+    let poller = Poller::new(
+      options.polling,
+      options.poll_interval,
+      Arc::clone(&path_manager),
+      trigger.clone(),
+    );
+    let executor = Executor::new(rx, options.aggregate_timeout, journal);
     let scanner = Scanner::new(tx, Arc::clone(&path_manager));
 
     Self {
       disk_watcher,
+      poller,
       executor,
       path_manager,
       scanner,
@@ -151,6 +182,7 @@ impl FsWatcher {
   /// Closes the file system watcher, stopping all background tasks and releasing resources.
   pub async fn close(&mut self) -> Result<()> {
     self.disk_watcher.close();
+    self.poller.close();
     self.scanner.close();
     self.executor.close().await;
     self.trigger.take();
@@ -164,6 +196,43 @@ impl FsWatcher {
     }
   }
 
+  /// Replays the events recorded in the journal in order, so the watch-mode issues can be
+  /// reproduced deterministically. The raw events pass through the trigger again, while the
+  /// batches of the scanner are resent as they were.
+  ///
+  /// The watched paths should be registered by [`FsWatcher::watch`] before replaying.
+  /// The journal of this watcher stops recording, so the replayed events are not recorded again.
+  /// Returns the number of replayed events.
+  pub fn replay(&self, journal: &Path) -> Result<usize> {
+    let records = read_journal(journal)?;
+    if let Some(journal) = &self.path_manager.journal {
+      journal.stop();
+    }
+    let Some(trigger) = &self.trigger else {
+      return Ok(0);
+    };
+    let mut count = 0;
+    for record in records {
+      match record.entry {
+        JournalEntry::Event { kind, path } => {
+          trigger.on_event(&path, kind);
+          count += 1;
+        }
+        JournalEntry::Scanned { events } => {
+          count += events.len();
+          trigger.send_batch(
+            events
+              .into_iter()
+              .map(|(kind, path)| FsEvent { path, kind })
+              .collect(),
+          );
+        }
+        JournalEntry::Ignored { .. } | JournalEntry::Aggregated { .. } => {}
+      }
+    }
+    Ok(count)
+  }
+
   /// Pauses the file system watcher, stopping the execution of the event loop.
   pub fn pause(&self) -> Result<()> {
     self.executor.pause();
@@ -181,8 +250,15 @@ impl FsWatcher {
     self.path_manager.update(files, directories, missing)?;
     self.scanner.scan(start_time);
 
+    // the paths which are polled do not need native events
+    let polling = self.poller.polling();
     let watch_patterns = self.analyzer.analyze(self.path_manager.access());
-    self.disk_watcher.watch(watch_patterns.into_iter())?;
+    self.disk_watcher.watch(
+      watch_patterns
+        .into_iter()
+        .filter(|pattern| !polling.should_poll(&pattern.path)),
+    )?;
+    self.poller.watch();
 
     Ok(())
   }
@@ -1,10 +1,11 @@
-use std::{fmt::Debug, ops::Deref, path::PathBuf};
+use std::{fmt::Debug, ops::Deref, path::PathBuf, sync::Arc};
 
 use dashmap::setref::multiple::RefMulti;
 use rspack_error::Result;
 use rspack_paths::{ArcPath, ArcPathDashSet};
 
 use super::FsWatcherIgnored;
+use crate::journal::Journal;
 
 /// An iterator that chains together references to all files, directories, and missing paths
 /// stored in the [`PathTracker`]. This allows iteration over all registered paths as a single sequence.
@@ -111,12 +112,21 @@ where
 
 impl PathUpdater {
   /// Update the paths in the given set.
-  fn update(self, watch_tracker: &PathTracker, ignored: &FsWatcherIgnored) -> Result<()> {
+  fn update(
+    self,
+    watch_tracker: &PathTracker,
+    ignored: &FsWatcherIgnored,
+    journal: Option<&Journal>,
+  ) -> Result<()> {
     let added_paths = self.added;
     let removed_paths = self.removed;
 
     for added in added_paths {
-      if ignored.should_be_ignored(added.to_str().expect("Path should be valid UTF-8")) {
+      let added_str = added.to_str().expect("Path should be valid UTF-8");
+      if ignored.should_be_ignored(added_str) {
+        if let Some(journal) = journal {
+          journal.record_ignored(added_str);
+        }
         continue; // Skip ignored paths
       }
 
@@ -186,16 +196,19 @@ pub(crate) struct PathManager {
   directories: PathTracker,
   missing: PathTracker,
   pub ignored: FsWatcherIgnored,
+  /// The journal to record the events and ignore decisions, if enabled.
+  pub journal: Option<Arc<Journal>>,
 }
 
 impl PathManager {
-  /// Create a new `PathManager` with an optional ignored paths filter.
-  pub fn new(ignored: FsWatcherIgnored) -> Self {
+  /// Create a new `PathManager` with an optional ignored paths filter and an optional journal.
+  pub fn new(ignored: FsWatcherIgnored, journal: Option<Arc<Journal>>) -> Self {
     Self {
       files: PathTracker::default(),
       directories: PathTracker::default(),
       missing: PathTracker::default(),
       ignored,
+      journal,
     }
   }
 
@@ -213,9 +226,10 @@ impl PathManager {
     directories: (impl Iterator<Item = ArcPath>, impl Iterator<Item = ArcPath>),
     missing: (impl Iterator<Item = ArcPath>, impl Iterator<Item = ArcPath>),
   ) -> Result<()> {
-    PathUpdater::from(files).update(&self.files, &self.ignored)?;
-    PathUpdater::from(directories).update(&self.directories, &self.ignored)?;
-    PathUpdater::from(missing).update(&self.missing, &self.ignored)?;
+    let journal = self.journal.as_deref();
+    PathUpdater::from(files).update(&self.files, &self.ignored, journal)?;
+    PathUpdater::from(directories).update(&self.directories, &self.ignored, journal)?;
+    PathUpdater::from(missing).update(&self.missing, &self.ignored, journal)?;
 
     Ok(())
   }
@@ -250,7 +264,7 @@ mod tests {
 
     let path_tracker = PathTracker::default();
 
-    updater.update(&path_tracker, &ignored).unwrap();
+    updater.update(&path_tracker, &ignored, None).unwrap();
 
     let all = path_tracker.all;
 
@@ -305,7 +319,7 @@ mod tests {
       "**/node_modules/**".to_string(),
       "**/.git/**".to_string(),
     ]);
-    let path_manager = PathManager::new(ignored);
+    let path_manager = PathManager::new(ignored, None);
     let files = (
       vec![ArcPath::from(Utf8Path::new("src/index.js"))].into_iter(),
       vec![].into_iter(),
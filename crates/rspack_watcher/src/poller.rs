@@ -0,0 +1,175 @@
+use std::{path::Path, sync::Arc, time::Duration};
+
+use dashmap::DashMap;
+use rspack_paths::ArcPath;
+use rspack_util::fx_hash::FxHashSet as HashSet;
+
+use crate::{paths::PathManager, scanner::PathStat, trigger::Trigger};
+
+/// Default poll interval in milliseconds, same as watchpack.
+const DEFAULT_POLL_INTERVAL: u32 = 5007;
+
+/// `FsWatcherPolling` decides which watched paths are checked by polling their stats
+/// instead of relying on the native file system events.
+///
+/// Polling is useful for network mounts, docker bind mounts and some virtual machines
+/// where the native events never arrive.
+#[derive(Debug, Default, Clone)]
+pub enum FsWatcherPolling {
+  /// Use native file system events for all paths.
+  #[default]
+  Disabled,
+  /// Poll all watched paths, same as `watchOptions.poll` in webpack.
+  All,
+  /// Only poll the watched paths under these roots, other paths still use native events.
+  Roots(Vec<ArcPath>),
+}
+
+impl FsWatcherPolling {
+  pub fn is_enabled(&self) -> bool {
+    match self {
+      Self::Disabled => false,
+      Self::All => true,
+      Self::Roots(roots) => !roots.is_empty(),
+    }
+  }
+
+  /// Whether the given path should be watched by polling.
+  pub fn should_poll(&self, path: &Path) -> bool {
+    match self {
+      Self::Disabled => false,
+      Self::All => true,
+      Self::Roots(roots) => roots.iter().any(|root| path.starts_with(root)),
+    }
+  }
+}
+
+type StatMap = DashMap<ArcPath, Option<PathStat>>;
+
+/// `Poller` periodically reads the stats of the watched paths which should be polled,
+/// and reports the differences to the [`Trigger`] as if they were native events.
+pub struct Poller {
+  polling: FsWatcherPolling,
+  interval: Duration,
+  path_manager: Arc<PathManager>,
+  trigger: Arc<Trigger>,
+  /// The stats of the polled paths at the last poll.
+  stats: Arc<StatMap>,
+  handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Poller {
+  /// Creates a new `Poller`, the poll task is not started until [`Poller::watch`] is called.
+  pub fn new(
+    polling: FsWatcherPolling,
+    interval: Option<u32>,
+    path_manager: Arc<PathManager>,
+    trigger: Arc<Trigger>,
+  ) -> Self {
+    let interval = interval
+      .filter(|interval| *interval > 0)
+      .unwrap_or(DEFAULT_POLL_INTERVAL);
+    Self {
+      polling,
+      interval: Duration::from_millis(u64::from(interval)),
+      path_manager,
+      trigger,
+      stats: Default::default(),
+      handle: None,
+    }
+  }
+
+  pub fn polling(&self) -> &FsWatcherPolling {
+    &self.polling
+  }
+
+  /// Syncs the polled paths with the [`PathManager`] and starts the poll task if needed.
+  ///
+  /// The stats of the paths which are already polled are kept,
+  /// so the changes happened between two watches will not be lost.
+  pub fn watch(&mut self) {
+    if !self.polling.is_enabled() {
+      return;
+    }
+
+    let accessor = self.path_manager.access();
+    let paths = accessor
+      .all()
+      .filter(|path| self.polling.should_poll(path))
+      .collect::<HashSet<_>>();
+    self.stats.retain(|path, _| paths.contains(path));
+    for path in paths {
+      if !self.stats.contains_key(&path) {
+        let stat = PathStat::read(&path);
+        self.stats.insert(path, stat);
+      }
+    }
+
+    if self.handle.is_none() {
+      let stats = Arc::clone(&self.stats);
+      let trigger = Arc::clone(&self.trigger);
+      let interval = self.interval;
+      self.handle = Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // the first tick completes immediately
+        ticker.tick().await;
+        loop {
+          ticker.tick().await;
+          let stats = Arc::clone(&stats);
+          let trigger = Arc::clone(&trigger);
+          // reading the stats is blocking, keep it away from the async workers
+          if tokio::task::spawn_blocking(move || poll(&stats, &trigger))
+            .await
+            .is_err()
+          {
+            break;
+          }
+        }
+      }));
+    }
+  }
+
+  pub fn close(&mut self) {
+    // the poll task holds the trigger, abort it to release the event sender
+    if let Some(handle) = self.handle.take() {
+      handle.abort();
+    }
+    self.stats.clear();
+  }
+}
+
+/// Reads the stats of all polled paths and triggers events for the changed ones.
+fn poll(stats: &StatMap, trigger: &Trigger) {
+  let mut events = vec![];
+  for mut item in stats.iter_mut() {
+    let next = PathStat::read(item.key());
+    if let Some(kind) = PathStat::diff(item.value().as_ref(), next.as_ref()) {
+      events.push((item.key().clone(), kind));
+    }
+    *item.value_mut() = next;
+  }
+  for (path, kind) in events {
+    trigger.on_event(&path, kind);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_should_poll() {
+    assert!(!FsWatcherPolling::Disabled.should_poll(Path::new("/a/b")));
+    assert!(FsWatcherPolling::All.should_poll(Path::new("/a/b")));
+
+    let polling = FsWatcherPolling::Roots(vec![ArcPath::from(Path::new("/mnt/share"))]);
+    assert!(polling.is_enabled());
+    assert!(polling.should_poll(Path::new("/mnt/share")));
+    assert!(polling.should_poll(Path::new("/mnt/share/src/index.js")));
+    assert!(!polling.should_poll(Path::new("/mnt/shared/index.js")));
+    assert!(!polling.should_poll(Path::new("/home/src/index.js")));
+
+    assert!(!FsWatcherPolling::Roots(vec![]).is_enabled());
+  }
+}
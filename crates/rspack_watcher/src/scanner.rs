@@ -1,10 +1,10 @@
-use std::{ops::Deref, sync::Arc, time::SystemTime};
+use std::{ffi::OsString, ops::Deref, sync::Arc, time::SystemTime};
 
 use rspack_paths::{ArcPath, ArcPathDashSet};
 use tokio::sync::mpsc::UnboundedSender;
 
 use super::{FsEvent, FsEventKind, PathManager};
-use crate::EventBatch;
+use crate::{EventBatch, journal::Journal};
 
 // Scanner will scann the path whether it is exist or not in disk on initialization
 pub struct Scanner {
@@ -34,10 +34,11 @@ impl Scanner {
         .map(|file| file.deref().clone())
         .collect::<Vec<_>>();
       let missing = accessor.missing().0.clone();
+      let journal = self.path_manager.journal.clone();
       let _tx = tx.clone();
       tokio::spawn(async move {
-        _ = scan_path_missing(&files, &missing, &_tx);
-        _ = scan_path_changed(&files, &start_time, &_tx);
+        _ = scan_path_missing(&files, &missing, &_tx, journal.as_deref());
+        _ = scan_path_changed(&files, &start_time, &_tx, journal.as_deref());
       });
 
       let directories = accessor
@@ -47,10 +48,11 @@ impl Scanner {
         .map(|file| file.deref().clone())
         .collect::<Vec<_>>();
       let missing = accessor.missing().0.clone();
+      let journal = self.path_manager.journal.clone();
       let _tx = self.tx.clone();
       tokio::spawn(async move {
-        _ = scan_path_missing(&directories, &missing, &tx);
-        _ = scan_path_changed(&directories, &start_time, &tx);
+        _ = scan_path_missing(&directories, &missing, &tx, journal.as_deref());
+        _ = scan_path_changed(&directories, &start_time, &tx, journal.as_deref());
       });
     }
   }
@@ -65,6 +67,7 @@ fn scan_path_missing(
   paths: &[ArcPath],
   missing: &ArcPathDashSet,
   tx: &UnboundedSender<EventBatch>,
+  journal: Option<&Journal>,
 ) -> bool {
   let remove_event = paths
     .iter()
@@ -78,6 +81,9 @@ fn scan_path_missing(
   if remove_event.is_empty() {
     return true;
   }
+  if let Some(journal) = journal {
+    journal.record_scanned(&remove_event);
+  }
   tx.send(remove_event).is_ok()
 }
 
@@ -85,6 +91,7 @@ fn scan_path_changed(
   paths: &[ArcPath],
   start_time: &SystemTime,
   tx: &UnboundedSender<EventBatch>,
+  journal: Option<&Journal>,
 ) -> bool {
   let changed_event = paths
     .iter()
@@ -99,6 +106,9 @@ fn scan_path_changed(
   if changed_event.is_empty() {
     return true;
   }
+  if let Some(journal) = journal {
+    journal.record_scanned(&changed_event);
+  }
   tx.send(changed_event).is_ok()
 }
 
@@ -113,6 +123,48 @@ fn check_path_metadata(filepath: &ArcPath, start_time: &SystemTime) -> bool {
   }
 }
 
+/// `PathStat` is a snapshot of the stats of a path, used to diff the path between polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PathStat {
+  modified: Option<SystemTime>,
+  len: u64,
+  /// The sorted entry names of a directory, `None` for files.
+  entries: Option<Vec<OsString>>,
+}
+
+impl PathStat {
+  /// Reads the stats of the path, returns `None` if the path does not exist.
+  pub fn read(path: &ArcPath) -> Option<Self> {
+    let metadata = path.metadata().ok()?;
+    let entries = metadata.is_dir().then(|| {
+      let mut entries = std::fs::read_dir(path)
+        .map(|dir| {
+          dir
+            .filter_map(|entry| entry.ok().map(|entry| entry.file_name()))
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      entries.sort();
+      entries
+    });
+    Some(Self {
+      modified: metadata.modified().or(metadata.created()).ok(),
+      len: metadata.len(),
+      entries,
+    })
+  }
+
+  /// Compares the stats of two polls and returns the kind of event happened in between.
+  pub fn diff(prev: Option<&Self>, next: Option<&Self>) -> Option<FsEventKind> {
+    match (prev, next) {
+      (None, Some(_)) => Some(FsEventKind::Create),
+      (Some(_), None) => Some(FsEventKind::Remove),
+      (Some(prev), Some(next)) if prev != next => Some(FsEventKind::Change),
+      _ => None,
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use rspack_paths::ArcPath;
@@ -113,11 +113,20 @@ impl Trigger {
   /// - `/path`
   /// - `/path/to`
   pub fn on_event(&self, path: &ArcPath, kind: FsEventKind) {
+    if let Some(journal) = &self.path_manager.journal {
+      journal.record_event(path, kind);
+    }
     let finder = self.finder();
     let associated_event = finder.find_associated_event(path, kind);
     self.trigger_events(associated_event);
   }
 
+  /// Sends a batch of events as is, without finding their associated events.
+  /// It is used to replay the batches of the scanner, which do not pass through the trigger.
+  pub fn send_batch(&self, batch: EventBatch) -> bool {
+    self.tx.send(batch).is_ok()
+  }
+
   /// Helper to construct a `DependencyFinder` for the current path register state.
   fn finder(&self) -> DependencyFinder<'_> {
     let accessor = self.path_manager.access();
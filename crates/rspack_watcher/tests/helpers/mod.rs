@@ -2,7 +2,7 @@
 
 use std::{
   mem::ManuallyDrop,
-  path::PathBuf,
+  path::{Path, PathBuf},
   sync::{
     Arc, Condvar, Mutex,
     mpsc::{Receiver, Sender},
@@ -194,6 +194,13 @@ impl TestHelper {
     f();
   }
 
+  /// Replays the raw events recorded in the journal through the watcher.
+  pub fn replay(&self, journal: &Path) -> usize {
+    TOKIO_RUNTIME
+      .handle()
+      .block_on(async { self.watcher.read().await.replay(journal).unwrap() })
+  }
+
   /// Watches the specified files, directories, and missing paths.
   ///
   /// All paths are relative to the temporary directory.
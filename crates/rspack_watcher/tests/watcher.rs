@@ -3,7 +3,7 @@
 use std::sync::atomic::AtomicU8;
 
 use rspack_paths::ArcPath;
-use rspack_watcher::{FsWatcher, FsWatcherOptions};
+use rspack_watcher::{FsWatcher, FsWatcherOptions, FsWatcherPolling, JournalEntry, read_journal};
 
 mod helpers;
 
@@ -95,3 +95,110 @@ fn should_watch_a_single_file() {
     },
   );
 }
+
+#[test]
+fn should_watch_a_single_file_by_polling() {
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(1000),
+    poll_interval: Some(100),
+    polling: FsWatcherPolling::All,
+    ..Default::default()
+  });
+
+  let rx = watch!(helper, "a");
+
+  helper.tick(|| {
+    helper.file("a");
+  });
+
+  let change_events = c!();
+  helper.collect_events(
+    rx,
+    |file, _| {
+      file.assert_path(helper.join("a"));
+      add!(change_events);
+    },
+    |changes, abort| {
+      changes.assert_changed(helper.join("a"));
+      assert!(load!(change_events) > 0);
+      *abort = true;
+    },
+  );
+}
+
+#[test]
+fn should_record_and_replay_journal() {
+  let journal_dir = tempfile::tempdir().expect("should create temp dir");
+  let journal = journal_dir.path().join("journal.log");
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(1000),
+    journal: Some(journal.clone()),
+    ..Default::default()
+  });
+
+  let rx = watch!(helper, "a");
+
+  helper.tick(|| {
+    helper.file("a");
+  });
+
+  helper.collect_events(
+    rx,
+    |_, _| {},
+    |changes, abort| {
+      changes.assert_changed(helper.join("a"));
+      *abort = true;
+    },
+  );
+
+  let records = read_journal(&journal).expect("should read journal");
+  let records_len = records.len();
+  assert!(records.iter().any(|record| matches!(
+    &record.entry,
+    JournalEntry::Event { path, .. } if path.to_str() == Some(helper.join("a").as_str())
+  )));
+  assert!(records.iter().any(|record| matches!(
+    &record.entry,
+    JournalEntry::Aggregated { changed, .. } if changed.contains(&helper.join("a").to_string())
+  )));
+
+  let recorded = records
+    .into_iter()
+    .filter_map(|record| match record.entry {
+      JournalEntry::Aggregated {
+        mut changed,
+        mut deleted,
+      } => {
+        changed.sort();
+        deleted.sort();
+        Some((changed, deleted))
+      }
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  // replay the recorded events without touching the file
+  let rx = watch!(helper, "a");
+  assert!(helper.replay(&journal) > 0);
+
+  let mut replayed = vec![];
+  helper.collect_events(
+    rx,
+    |_, _| {},
+    |changes, abort| {
+      let mut changed = changes.changed_files.iter().cloned().collect::<Vec<_>>();
+      changed.sort();
+      let mut deleted = changes.deleted_files.iter().cloned().collect::<Vec<_>>();
+      deleted.sort();
+      replayed.push((changed, deleted));
+      *abort = replayed.len() == recorded.len();
+    },
+  );
+  assert_eq!(replayed, recorded);
+
+  // the replayed events are not recorded again
+  assert_eq!(
+    read_journal(&journal).expect("should read journal").len(),
+    records_len
+  );
+}
@@ -1,5 +1,6 @@
 import binding from '@rspack/binding';
 import type Watchpack from 'watchpack';
+import type { WatchOptions } from './config';
 import type {
   FileSystemInfoEntry,
   InputFileSystem,
@@ -176,6 +177,8 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
       followSymlinks: options.followSymlinks,
       aggregateTimeout: options.aggregateTimeout,
       pollInterval: typeof options.poll === 'boolean' ? 0 : options.poll,
+      poll: Boolean(options.poll),
+      pollRoots: (options as WatchOptions).pollRoots,
       ignored: toJsWatcherIgnored(options.ignored),
     };
     const nativeWatcher = new binding.NativeWatcher(nativeWatcherOptions);
@@ -454,6 +454,11 @@ enum RuntimeGlobals {
   makeDeferredNamespaceObject,
 
   makeDeferredNamespaceObjectSymbol,
+
+  /**
+   * compile a WebAssembly.Module from id and hash, for source phase imports
+   */
+  compileWasm,
 }
 
 export const isReservedRuntimeGlobal = (
@@ -661,6 +666,8 @@ function renderRuntimeGlobals(
       return `${scope_name}.z`;
     case RuntimeGlobals.makeDeferredNamespaceObjectSymbol:
       return `${scope_name}.zS`;
+    case RuntimeGlobals.compileWasm:
+      return `${scope_name}.vs`;
     default:
       return '';
   }
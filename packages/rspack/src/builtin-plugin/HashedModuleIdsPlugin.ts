@@ -0,0 +1,39 @@
+import {
+  BuiltinPluginName,
+  type RawHashedModuleIdsPluginOptions,
+} from '@rspack/binding';
+
+import type { HashDigest, HashFunction } from '../config';
+import { create } from './base';
+
+export type HashedModuleIdsPluginOptions = {
+  /**
+   * The context directory the module identifiers are relative to.
+   * Defaults to the `context` of the compiler.
+   */
+  context?: string;
+  /**
+   * The hash function to use.
+   * @default 'md4'
+   */
+  hashFunction?: HashFunction;
+  /**
+   * The encoding to use when generating the hash.
+   * @default 'base64'
+   */
+  hashDigest?: HashDigest;
+  /**
+   * The prefix length of the hash digest to use as the module id,
+   * longer ids are used when the hashes of modules conflict.
+   * @default 4
+   */
+  hashDigestLength?: number;
+};
+
+export const HashedModuleIdsPlugin = create(
+  BuiltinPluginName.HashedModuleIdsPlugin,
+  (
+    options?: HashedModuleIdsPluginOptions,
+  ): RawHashedModuleIdsPluginOptions => ({ ...options }),
+  'compilation',
+);
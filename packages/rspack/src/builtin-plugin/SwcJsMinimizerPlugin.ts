@@ -31,6 +31,21 @@ export type SwcJsMinimizerRspackPluginOptions = {
     format?: JsFormatOptions & ToSnakeCaseProperties<JsFormatOptions>;
     module?: boolean;
   };
+  /**
+   * Share the mangled names across all the assets, so that the same names are mangled the same way.
+   * A path to a JSON file can be provided to keep the mangled names stable across builds.
+   * @default false
+   */
+  nameCache?: boolean | string;
+  /**
+   * How to handle the legal comments, which start with `//!` or `/*!`, or contain `@license` or `@preserve`.
+   * - `'inline'`: preserve the legal comments where they are
+   * - `'eof'`: move the legal comments to the end of the file
+   * - `'linked'`: move the legal comments to a `.LEGAL.txt` file and link to it with a comment
+   * - `'external'`: move the legal comments to a `.LEGAL.txt` file without linking to it
+   * It can not be used with `extractComments`.
+   */
+  legalComments?: 'inline' | 'eof' | 'linked' | 'external';
 };
 
 /**
@@ -307,6 +322,8 @@ export const SwcJsMinimizerRspackPlugin = create(
         minify: options?.minimizerOptions?.minify,
         module: options?.minimizerOptions?.module,
       },
+      nameCache: options?.nameCache,
+      legalComments: options?.legalComments,
     };
   },
   'compilation',
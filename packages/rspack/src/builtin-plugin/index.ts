@@ -35,6 +35,7 @@ export * from './FetchCompileAsyncWasmPlugin';
 export * from './FileUriPlugin';
 export * from './FlagDependencyExportsPlugin';
 export * from './FlagDependencyUsagePlugin';
+export * from './HashedModuleIdsPlugin';
 export * from './HotModuleReplacementPlugin';
 export * from './HttpExternalsRspackPlugin';
 export * from './HttpUriPlugin';
@@ -80,5 +81,6 @@ export * from './SplitChunksPlugin';
 export * from './SubresourceIntegrityPlugin';
 export * from './SwcJsMinimizerPlugin';
 export * from './URLPlugin';
+export * from './WebAssemblyModulesPlugin';
 export * from './WebWorkerTemplatePlugin';
 export * from './WorkerPlugin';
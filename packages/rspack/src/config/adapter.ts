@@ -541,7 +541,8 @@ function getRawParserOptions(
     };
   }
 
-  if (type === 'json') {
+  // the yaml, toml and json5 modules are lowered into json and share its options
+  if (['json', 'json5', 'yaml', 'toml'].includes(type)) {
     return {
       type: 'json',
       json: getRawJsonParserOptions(parser),
@@ -688,7 +689,7 @@ function getRawGeneratorOptions(
       cssModule: getRawCssAutoOrModuleGeneratorOptions(generator),
     };
   }
-  if (type === 'json') {
+  if (['json', 'json5', 'yaml', 'toml'].includes(type)) {
     return {
       type: 'json',
       json: getRawJsonGeneratorOptions(generator),
@@ -114,6 +114,7 @@ export const applyRspackOptionsDefaults = (
   applyModuleDefaults(options.module, {
     cache: !!options.cache,
     asyncWebAssembly: options.experiments.asyncWebAssembly!,
+    syncWebAssembly: options.experiments.syncWebAssembly,
     css: options.experiments.css,
     targetProperties,
     mode: options.mode,
@@ -248,6 +249,7 @@ const applyExperimentsDefaults = (
   D(experiments, 'incremental', {});
   if (typeof experiments.incremental === 'object') {
     D(experiments.incremental, 'silent', true);
+    D(experiments.incremental, 'check', false);
     D(experiments.incremental, 'make', true);
     D(experiments.incremental, 'inferAsyncModules', true);
     D(experiments.incremental, 'providedExports', true);
@@ -357,6 +359,7 @@ const applyModuleDefaults = (
   {
     cache,
     asyncWebAssembly,
+    syncWebAssembly,
     css,
     targetProperties,
     mode,
@@ -365,6 +368,7 @@ const applyModuleDefaults = (
   }: {
     cache: boolean;
     asyncWebAssembly: boolean;
+    syncWebAssembly?: boolean;
     css?: boolean;
     targetProperties: any;
     mode?: Mode;
@@ -535,6 +539,28 @@ const applyModuleDefaults = (
         mimetype: 'application/wasm',
         ...wasm,
       });
+    } else if (syncWebAssembly) {
+      const wasm = {
+        type: 'webassembly/sync',
+        rules: [
+          {
+            descriptionData: {
+              type: 'module',
+            },
+            resolve: {
+              fullySpecified: true,
+            },
+          },
+        ],
+      };
+      rules.push({
+        test: /\.wasm$/i,
+        ...wasm,
+      });
+      rules.push({
+        mimetype: 'application/wasm',
+        ...wasm,
+      });
     }
 
     if (css) {
@@ -557,6 +583,11 @@ const applyModuleDefaults = (
         type: 'css',
         resolve,
       });
+      rules.push({
+        with: { type: 'css' },
+        type: 'css',
+        resolve,
+      });
     }
 
     rules.push(
@@ -403,13 +403,21 @@ export const getNormalizedRspackOptions = (
               managedPaths: optionalNestedArray(snapshot.managedPaths, (p) => [
                 ...p,
               ]) || [/[\\/]node_modules[\\/][^.]/],
+              contentHashPaths: nestedArray(snapshot.contentHashPaths, (p) => [
+                ...p,
+              ]),
+              gitIndex: snapshot.gitIndex ?? false,
             },
             storage: {
-              type: 'filesystem',
+              type: cache.storage?.type || 'filesystem',
               directory: path.resolve(
                 config.context || process.cwd(),
                 cache.storage?.directory || 'node_modules/.cache/rspack',
               ),
+              url:
+                cache.storage?.type === 'remote' ? cache.storage.url : undefined,
+              maxSize: cache.storage?.maxSize,
+              compression: cache.storage?.compression,
             },
           };
         }),
@@ -676,10 +684,15 @@ export type ExperimentCacheNormalized =
         immutablePaths: (string | RegExp)[];
         unmanagedPaths: (string | RegExp)[];
         managedPaths: (string | RegExp)[];
+        contentHashPaths: (string | RegExp)[];
+        gitIndex: boolean;
       };
       storage: {
-        type: 'filesystem';
+        type: 'filesystem' | 'remote';
         directory: string;
+        url?: string;
+        maxSize?: number;
+        compression?: 'none' | 'zstd' | 'lz4';
       };
     };
 
@@ -692,6 +705,7 @@ export interface ExperimentsNormalized {
    */
   lazyCompilation?: false | LazyCompilationOptions;
   asyncWebAssembly?: boolean;
+  syncWebAssembly?: boolean;
   outputModule?: boolean;
   topLevelAwait?: boolean;
   css?: boolean;
@@ -344,7 +344,13 @@ export type HashDigest = string;
 export type HashDigestLength = number;
 
 /** The hashing algorithm to use. */
-export type HashFunction = 'md4' | 'xxhash64' | 'sha256';
+export type HashFunction =
+  | 'md4'
+  | 'xxhash64'
+  | 'xxhash3-128'
+  | 'sha256'
+  | 'sha512'
+  | 'blake3';
 
 /** An optional salt to update the hash. */
 export type HashSalt = string;
@@ -2437,7 +2443,7 @@ export type Optimization = {
   /**
    * Which algorithm to use when choosing module ids.
    */
-  moduleIds?: 'named' | 'natural' | 'deterministic';
+  moduleIds?: 'named' | 'natural' | 'deterministic' | 'hashed';
 
   /**
    * Which algorithm to use when choosing chunk ids.
@@ -2596,11 +2602,51 @@ export type ExperimentCacheOptions =
         immutablePaths?: (string | RegExp)[];
         unmanagedPaths?: (string | RegExp)[];
         managedPaths?: (string | RegExp)[];
+        /**
+         * Paths validated only by content hash, ignoring the file modified time.
+         */
+        contentHashPaths?: (string | RegExp)[];
+        /**
+         * Trust the object ids in git index for tracked files in `contentHashPaths`.
+         */
+        gitIndex?: boolean;
       };
-      storage?: {
-        type: 'filesystem';
-        directory?: string;
-      };
+      storage?:
+        | {
+            type: 'filesystem';
+            directory?: string;
+            /**
+             * The maximum size in bytes of the cache directory.
+             * The least recently used cache versions are removed when exceeded.
+             */
+            maxSize?: number;
+            /**
+             * The codec used to compress pack files.
+             * @default 'none'
+             */
+            compression?: 'none' | 'zstd' | 'lz4';
+          }
+        | {
+            /**
+             * Local filesystem storage shared with other machines through a remote blob server.
+             */
+            type: 'remote';
+            directory?: string;
+            /**
+             * The `http://` url of the remote blob server.
+             */
+            url: string;
+            /**
+             * The maximum size in bytes of the local cache directory.
+             * The least recently used cache versions are removed when exceeded.
+             */
+            maxSize?: number;
+            /**
+             * The codec used to compress pack files.
+             * @default 'none'
+             */
+            compression?: 'none' | 'zstd' | 'lz4';
+          };
     };
 
 /**
@@ -2672,6 +2718,11 @@ export type Incremental = {
    * Warning if there are cases that not friendly for incremental
    */
   silent?: boolean;
+  /**
+   * Compare the result of a persistent cache hot start with a full rebuild,
+   * and warn about the differences. Only for debugging incremental, it doubles the build time of hot start.
+   */
+  check?: boolean;
   /**
    * Enable incremental make.
    */
@@ -2788,6 +2839,12 @@ export type Experiments = {
    * @default false
    */
   asyncWebAssembly?: boolean;
+  /**
+   * Enable sync WebAssembly.
+   * Support the old WebAssembly like in webpack 4, the WebAssembly module is instantiated when its chunk is loaded.
+   * @default false
+   */
+  syncWebAssembly?: boolean;
   /**
    * Enable output as ES module.
    * @default false
@@ -2915,6 +2972,12 @@ export type WatchOptions = {
    */
   poll?: number | boolean;
 
+  /**
+   * Only poll the watched paths under these roots, other paths still use native file system events.
+   * It is ignored when `poll` is `true`, and only works with `experiments.nativeWatcher`.
+   */
+  pollRoots?: string[];
+
   /**
    * Stop watching when stdin stream has ended.
    */
@@ -218,6 +218,16 @@ interface Webworker {
 
 export const webworker: Webworker = { WebWorkerTemplatePlugin };
 
+import { HashedModuleIdsPlugin } from './builtin-plugin';
+
+export type { HashedModuleIdsPluginOptions } from './builtin-plugin';
+
+interface Ids {
+  HashedModuleIdsPlugin: typeof HashedModuleIdsPlugin;
+}
+
+export const ids: Ids = { HashedModuleIdsPlugin };
+
 import {
   CssChunkingPlugin,
   LimitChunkCountPlugin,
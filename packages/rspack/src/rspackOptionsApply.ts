@@ -39,6 +39,7 @@ import {
   FileUriPlugin,
   FlagDependencyExportsPlugin,
   FlagDependencyUsagePlugin,
+  HashedModuleIdsPlugin,
   HttpExternalsRspackPlugin,
   HttpUriPlugin,
   InferAsyncModulesPlugin,
@@ -66,6 +67,7 @@ import {
   SourceMapDevToolPlugin,
   SplitChunksPlugin,
   URLPlugin,
+  WebAssemblyModulesPlugin,
   WorkerPlugin,
 } from './builtin-plugin';
 import MemoryCachePlugin from './lib/cache/MemoryCachePlugin';
@@ -229,6 +231,9 @@ export class RspackOptionsApply {
     new URLPlugin().apply(compiler);
     new JsonModulesPlugin().apply(compiler);
     new AssetModulesPlugin().apply(compiler);
+    if (options.experiments.syncWebAssembly) {
+      new WebAssemblyModulesPlugin().apply(compiler);
+    }
     if (options.experiments.asyncWebAssembly) {
       new AsyncWebAssemblyModulesPlugin().apply(compiler);
     }
@@ -321,6 +326,10 @@ export class RspackOptionsApply {
           new DeterministicModuleIdsPlugin().apply(compiler);
           break;
         }
+        case 'hashed': {
+          new HashedModuleIdsPlugin().apply(compiler);
+          break;
+        }
         default:
           throw new Error(`moduleIds: ${moduleIds} is not implemented`);
       }
@@ -8,7 +8,9 @@
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
 
+import binding from '@rspack/binding';
 import Hash from './hash';
+import { encodeDigest, isBaseNEncoding } from './hash/digest';
 import createMd4 from './hash/md4';
 import createXXHash64 from './hash/xxhash64';
 
@@ -110,7 +112,11 @@ class BulkUpdateDecorator extends Hash {
     if (buffer.length > 0) {
       this.hash.update(Buffer.from(buffer));
     }
-    const result = encoding ? this.hash.digest(encoding) : this.hash.digest();
+    const result = encoding
+      ? isBaseNEncoding(encoding)
+        ? encodeDigest(this.hash.digest(), encoding)
+        : this.hash.digest(encoding)
+      : this.hash.digest();
     if (digestCache !== undefined && typeof result === 'string') {
       digestCache.set(buffer, result);
     }
@@ -221,6 +227,55 @@ class WasmHashAdapter extends Hash {
   }
 }
 
+class NativeHashAdapter extends Hash {
+  private nativeHash: binding.NativeHash;
+
+  constructor(algorithm: string) {
+    super();
+    this.nativeHash = new binding.NativeHash(algorithm);
+  }
+
+  /**
+   * Update hash {@link https://nodejs.org/api/crypto.html#crypto_hash_update_data_inputencoding}
+   * @param data data
+   * @param inputEncoding data encoding
+   * @returns updated hash
+   */
+  update(data: string, inputEncoding: string): this;
+  update(data: Buffer): this;
+  update(data: string | Buffer, inputEncoding?: string): this {
+    if (typeof data === 'string' && inputEncoding !== undefined) {
+      this.nativeHash.update(
+        Buffer.from(data, inputEncoding as BufferEncoding),
+      );
+    } else {
+      this.nativeHash.update(data);
+    }
+    return this;
+  }
+
+  /**
+   * Calculates the digest without encoding
+   * @returns {Buffer} digest
+   */
+  digest(): Buffer;
+  /**
+   * Calculates the digest with encoding
+   * @param encoding encoding of the return value
+   * @returns {string} digest
+   */
+  digest(encoding: string): string;
+  /**
+   * Calculates the digest {@link https://nodejs.org/api/crypto.html#crypto_hash_digest_encoding}
+   * @param {string=} encoding encoding of the return value
+   * @returns {string|Buffer} digest
+   */
+  digest(encoding?: string): string | Buffer {
+    const result = Buffer.from(this.nativeHash.digest(), 'hex');
+    return encoding ? encodeDigest(result, encoding) : result;
+  }
+}
+
 /**
  * Creates a hash by name or function
  * @param algorithm the algorithm name or a constructor creating a hash
@@ -230,8 +285,10 @@ export const createHash = (
   algorithm:
     | 'debug'
     | 'xxhash64'
+    | 'xxhash3-128'
     | 'md4'
     | 'native-md4'
+    | 'blake3'
     | (string & {})
     | (new () => Hash),
 ): Hash => {
@@ -250,6 +307,13 @@ export const createHash = (
       const hash = createMd4();
       return new WasmHashAdapter(hash);
     }
+    // not available in node:crypto, use the same implementations as the compiler
+    case 'xxhash3-128':
+    case 'blake3':
+      return new BulkUpdateDecorator(
+        () => new NativeHashAdapter(algorithm),
+        algorithm,
+      );
     case 'native-md4':
       return new BulkUpdateDecorator(() => {
         const { createHash } = require('node:crypto');
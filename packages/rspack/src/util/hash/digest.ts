@@ -0,0 +1,46 @@
+const BASE62_TABLE =
+  '0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ';
+
+/**
+ * Encode the digest as a big-endian unsigned integer in the base of `base`,
+ * left padded with `0` to the max length of the digest, the same as the compiler.
+ * @param buffer digest
+ * @param base 62 or 36
+ * @returns encoded digest
+ */
+const encodeBaseN = (buffer: Buffer, base: number): string => {
+  const length = Math.ceil((buffer.length * 8) / Math.log2(base));
+  let number = buffer.length > 0 ? BigInt(`0x${buffer.toString('hex')}`) : 0n;
+  const bigBase = BigInt(base);
+  let result = '';
+  for (let i = 0; i < length; i++) {
+    result = BASE62_TABLE[Number(number % bigBase)] + result;
+    number /= bigBase;
+  }
+  return result;
+};
+
+/**
+ * Whether the digest encoding is not supported by `Buffer`, and should be encoded by {@link encodeDigest}
+ * @param encoding encoding of the digest
+ * @returns true for `base62` and `base36`
+ */
+export const isBaseNEncoding = (encoding: string): boolean =>
+  encoding === 'base62' || encoding === 'base36';
+
+/**
+ * Encode the digest with the `output.hashDigest`, which can be a `Buffer` encoding, `base62` or `base36`
+ * @param buffer digest
+ * @param encoding encoding of the return value
+ * @returns encoded digest
+ */
+export const encodeDigest = (buffer: Buffer, encoding: string): string => {
+  switch (encoding) {
+    case 'base62':
+      return encodeBaseN(buffer, 62);
+    case 'base36':
+      return encodeBaseN(buffer, 36);
+    default:
+      return buffer.toString(encoding as BufferEncoding);
+  }
+};
@@ -8,6 +8,8 @@
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
 
+import { encodeDigest } from './digest';
+
 // 65536 is the size of a wasm memory page
 // 64 is the maximum chunk size for every possible wasm hash implementation
 // 4 is the maximum number of bytes per char for string encoding (max is utf-8)
@@ -160,14 +162,14 @@ export class WasmHash {
     }
   }
 
-  digest(type: BufferEncoding) {
+  digest(type?: string) {
     const { exports, buffered, mem, digestSize } = this;
     exports.final(buffered);
     this.instancesPool.push(this);
     const hex = mem.toString('latin1', 0, digestSize);
     if (type === 'hex') return hex;
     if (type === 'binary' || !type) return Buffer.from(hex, 'hex');
-    return Buffer.from(hex, 'hex').toString(type);
+    return encodeDigest(Buffer.from(hex, 'hex'), type);
   }
 }
 
@@ -0,0 +1,40 @@
+let compilerIndex = 0;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	optimization: {
+		minimize: true
+	},
+	experiments: {
+		cache: {
+			type: "persistent"
+		}
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				const index = compilerIndex++;
+				let buildIndex = 0;
+				compiler.hooks.done.tap("Test", function (stats) {
+					const { logging } = stats.toJson({
+						all: false,
+						logging: "verbose"
+					});
+					const messages = logging["rspack.SwcJsMinimizerRspackPlugin"].entries.map(
+						entry => entry.message
+					);
+					// the unchanged lazy chunk is restored from the persistent minify cache after restart
+					if (index === 1 && buildIndex === 0) {
+						expect(
+							messages.some(message =>
+								/^lazy.*: restored from minify cache/.test(message)
+							)
+						).toBeTruthy();
+					}
+					buildIndex++;
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Import attribute `type: "text"` conflicts with the module type 'asset\/resource' of '\.\/conflict\.txt' resolved from module rules/
+	]
+];
@@ -0,0 +1,19 @@
+import text from "./file.txt" with { type: "text" };
+import bytes from "./file.bin" with { type: "bytes" };
+
+it("should map the type import attribute to the module type", async () => {
+	expect(text).toBe("hello text\n");
+	expect(bytes).toBeInstanceOf(Uint8Array);
+	expect(Array.from(bytes)).toEqual([65, 66]);
+
+	const { default: dynamicText } = await import("./file.txt", {
+		with: { type: "text" }
+	});
+	expect(dynamicText).toBe("hello text\n");
+});
+
+it("should error when the type import attribute conflicts with module rules", async () => {
+	await expect(
+		import("./conflict.txt", { with: { type: "text" } })
+	).rejects.toThrow();
+});
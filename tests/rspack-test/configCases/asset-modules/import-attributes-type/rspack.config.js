@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /conflict\.txt$/,
+				type: "asset/resource"
+			}
+		]
+	}
+};
@@ -0,0 +1,28 @@
+import * as yaml from "./data.yaml";
+import { title as tomlTitle, date, owner } from "./data.toml";
+import { title as json5Title, hex, trailing } from "./data.json5";
+
+it("should import yaml as json", () => {
+	expect(yaml.title).toBe("Rspack");
+	expect(yaml.anchors.derived.name).toBe("base");
+	expect(yaml.list).toEqual([1, 2.5, true, null]);
+});
+
+it("should import toml as json", () => {
+	expect(tomlTitle).toBe("Rspack");
+	expect(date).toBe("2024-01-01T00:00:00Z");
+	expect(owner).toEqual({ name: "rspack", tags: ["a", "b"] });
+});
+
+it("should import json5 as json", () => {
+	expect(json5Title).toBe("Rspack");
+	expect(hex).toBe(255);
+	expect(trailing).toEqual([1, 2]);
+});
+
+it("should remove the unused keys", () => {
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("yaml_" + "unused_value");
+	expect(content).not.toContain("toml_" + "unused_value");
+	expect(content).not.toContain("json5_" + "unused_value");
+});
@@ -0,0 +1,24 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	devtool: false,
+	module: {
+		rules: [
+			{
+				test: /\.yaml$/,
+				type: "yaml"
+			},
+			{
+				test: /\.toml$/,
+				type: "toml"
+			},
+			{
+				test: /\.json5$/,
+				type: "json5"
+			}
+		]
+	},
+	optimization: {
+		minimize: false
+	}
+};
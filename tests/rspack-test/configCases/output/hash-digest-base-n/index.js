@@ -0,0 +1,16 @@
+const EXPECTED = {
+	base62: "Iduk5MBJe0Q8KXkUR8D0ACWB3MLmy7NZh5I6wPr5BAV",
+	base36: "4nb7oofka9ml8nasnokxxc1unhmtpr1wxsuwhpd9km3vt5as31"
+};
+
+it("should encode the digest in js the same as the compiler", () => {
+	expect(SHA256_DIGEST).toBe(EXPECTED[HASH_DIGEST]);
+});
+
+it("should use the digest for the hashes", () => {
+	const pattern = HASH_DIGEST === "base36" ? /^[0-9a-z]+$/ : /^[0-9a-zA-Z]+$/;
+	expect(__STATS__.hash).toMatch(pattern);
+	for (const chunk of __STATS__.chunks) {
+		expect(chunk.hash).toMatch(pattern);
+	}
+});
@@ -0,0 +1,35 @@
+const { DefinePlugin, util } = require("@rspack/core");
+
+/**
+ * @param {import("@rspack/core").HashFunction} hashFunction
+ * @param {"base62" | "base36"} hashDigest
+ * @returns {import("@rspack/core").Configuration}
+ */
+const config = (hashFunction, hashDigest) => ({
+	output: {
+		hashFunction,
+		hashDigest
+	},
+	plugins: [
+		new DefinePlugin({
+			HASH_DIGEST: JSON.stringify(hashDigest),
+			SHA256_DIGEST: JSON.stringify(
+				util.createHash("sha256").update("abc").digest(hashDigest)
+			)
+		}),
+		{
+			apply(compiler) {
+				compiler.hooks.compilation.tap("Test", compilation => {
+					// the js chunk hash is digested with `output.hashDigest`
+					compilation.hooks.chunkHash.tap("Test", (_chunk, hash) => {
+						hash.update("test");
+					});
+				});
+			}
+		}
+	]
+});
+
+module.exports = ["xxhash64", "md4", "sha256", "xxhash3-128"].flatMap(
+	hashFunction => [config(hashFunction, "base62"), config(hashFunction, "base36")]
+);
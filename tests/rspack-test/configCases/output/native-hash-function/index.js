@@ -0,0 +1,14 @@
+const EXPECTED = {
+	"xxhash3-128": "99aa06d3014798d86001c324468d497f",
+	blake3: "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+};
+
+it("should create the native hash in js", () => {
+	expect(EMPTY_DIGEST).toBe(EXPECTED[HASH_FUNCTION]);
+	expect(BULK_DIGEST).toBe(ONCE_DIGEST);
+	expect(Buffer.from(BASE64_DIGEST, "base64").toString("hex")).toBe(ONCE_DIGEST);
+});
+
+it("should use the hash function for the compilation hash", () => {
+	expect(__STATS__.hash).toMatch(/^[0-9a-f]+$/);
+});
@@ -0,0 +1,34 @@
+const { DefinePlugin, util } = require("@rspack/core");
+
+/**
+ * @param {import("@rspack/core").HashFunction} hashFunction
+ * @returns {import("@rspack/core").Configuration}
+ */
+const config = hashFunction => {
+	const bulk = util
+		.createHash(hashFunction)
+		.update("a")
+		.update(Buffer.from("b"))
+		.digest("hex");
+	const once = util.createHash(hashFunction).update("ab").digest("hex");
+	return {
+		output: {
+			hashFunction
+		},
+		plugins: [
+			new DefinePlugin({
+				HASH_FUNCTION: JSON.stringify(hashFunction),
+				EMPTY_DIGEST: JSON.stringify(
+					util.createHash(hashFunction).digest("hex")
+				),
+				BASE64_DIGEST: JSON.stringify(
+					util.createHash(hashFunction).update("ab").digest("base64")
+				),
+				BULK_DIGEST: JSON.stringify(bulk),
+				ONCE_DIGEST: JSON.stringify(once)
+			})
+		]
+	};
+};
+
+module.exports = [config("xxhash3-128"), config("blake3")];
@@ -0,0 +1,90 @@
+import value from "./module";
+
+const fs = require("fs");
+const path = require("path");
+/*! Legal Comment */
+
+// @preserve Legal Line Comment
+
+// Foo
+
+// the comments are matched by the escaped regexps,
+// so the code of the tests itself is never matched
+const LEGAL_COMMENT = /\/\*! Legal Comment \*\//g;
+const LEGAL_LINE_COMMENT = /\/\/ @preserve Legal Line Comment/;
+const LICENSE_COMMENT = /@license Apache-2\.0/;
+const FOO_COMMENT = /\/\/ Foo/;
+const LINK_COMMENT = /\/\*! For license information please see \S+ \*\/$/;
+
+const legalFile = `${__filename}.LEGAL.txt`;
+const readContent = () => fs.readFileSync(__filename, "utf-8");
+const readLegal = () => fs.readFileSync(legalFile, "utf-8");
+const countLegalComments = content =>
+	(content.match(LEGAL_COMMENT) || []).length;
+
+it("should remove the other comments", () => {
+	expect(value).toBe("module");
+	expect(readContent()).not.toMatch(FOO_COMMENT);
+});
+
+if (LEGAL_COMMENTS === "inline") {
+	it("should preserve the legal comments where they are", () => {
+		const content = readContent();
+		expect(countLegalComments(content)).toBeGreaterThanOrEqual(1);
+		expect(content).toMatch(LEGAL_LINE_COMMENT);
+		expect(content.trimEnd()).not.toMatch(LINK_COMMENT);
+		expect(fs.existsSync(legalFile)).toBe(false);
+	});
+}
+
+if (LEGAL_COMMENTS === "eof") {
+	it("should move the legal comments to the end of the file", () => {
+		const content = readContent();
+		// the minified code is in the first line, followed by the legal comments
+		const code = content.slice(0, content.indexOf("\n"));
+		const eof = content.slice(content.indexOf("\n"));
+		expect(countLegalComments(code)).toBe(0);
+		expect(code).not.toMatch(LEGAL_LINE_COMMENT);
+		expect(countLegalComments(eof)).toBe(1);
+		expect(eof).toMatch(LEGAL_LINE_COMMENT);
+		expect(eof).toMatch(LICENSE_COMMENT);
+		expect(content.trimEnd()).not.toMatch(LINK_COMMENT);
+		expect(fs.existsSync(legalFile)).toBe(false);
+	});
+}
+
+if (LEGAL_COMMENTS === "linked") {
+	it("should move the legal comments to the linked file", () => {
+		const legal = readLegal();
+		expect(legal.match(/Legal Comment/g).length).toBe(1);
+		expect(legal).toContain("// @preserve Legal Line Comment");
+		expect(legal).toContain("@license Apache-2.0");
+		expect(legal).not.toContain("Foo");
+
+		const content = readContent();
+		expect(countLegalComments(content)).toBe(0);
+		expect(content.trimEnd()).toMatch(LINK_COMMENT);
+		expect(content).toContain(
+			`/*! For license information please see ${path.basename(legalFile)} */`
+		);
+	});
+}
+
+if (LEGAL_COMMENTS === "external") {
+	it("should move the legal comments to the external file without linking", () => {
+		expect(__STATS__.assets.map(asset => asset.name)).toContain(
+			path.basename(legalFile)
+		);
+
+		const legal = readLegal();
+		expect(legal.match(/Legal Comment/g).length).toBe(1);
+		expect(legal).toContain("// @preserve Legal Line Comment");
+		expect(legal).toContain("@license Apache-2.0");
+		expect(legal).not.toContain("Foo");
+
+		const content = readContent();
+		expect(countLegalComments(content)).toBe(0);
+		expect(content).not.toMatch(LEGAL_LINE_COMMENT);
+		expect(content.trimEnd()).not.toMatch(LINK_COMMENT);
+	});
+}
@@ -0,0 +1,8 @@
+/*! Legal Comment */
+
+/**
+ * Utility functions for the foo package.
+ * @license Apache-2.0
+ */
+
+export default "module";
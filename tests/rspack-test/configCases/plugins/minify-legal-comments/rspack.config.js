@@ -0,0 +1,23 @@
+const { rspack } = require("@rspack/core");
+
+/**
+ * @param {"inline" | "eof" | "linked" | "external"} legalComments
+ * @returns {import("@rspack/core").Configuration}
+ */
+const config = legalComments => ({
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcJsMinimizerRspackPlugin({
+				legalComments
+			})
+		]
+	},
+	plugins: [
+		new rspack.DefinePlugin({
+			LEGAL_COMMENTS: JSON.stringify(legalComments)
+		})
+	]
+});
+
+module.exports = ["inline", "eof", "linked", "external"].map(config);
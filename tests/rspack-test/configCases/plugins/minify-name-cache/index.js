@@ -0,0 +1,14 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should write the mangled properties to the name cache", () => {
+	const obj = { _secret: 42 };
+	expect(obj._secret).toBe(42);
+	const [mangled] = Object.keys(obj);
+	expect(mangled).not.toBe("_secret");
+
+	const nameCache = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "name-cache.json"), "utf-8")
+	);
+	expect(nameCache.props.props.$_secret).toBe(mangled);
+});
@@ -0,0 +1,22 @@
+const path = require("path");
+const { rspack } = require("@rspack/core");
+
+/** @type {function(any, any): import("@rspack/core").Configuration} */
+module.exports = (env, { testPath }) => ({
+	target: "node",
+	optimization: {
+		minimize: true
+	},
+	plugins: [
+		new rspack.SwcJsMinimizerRspackPlugin({
+			minimizerOptions: {
+				mangle: {
+					props: {
+						regex: "^_"
+					}
+				}
+			},
+			nameCache: path.join(testPath, "name-cache.json")
+		})
+	]
+});
@@ -0,0 +1,15 @@
+import source wasmModule from "./wasm.wat";
+
+it("should import the compiled WebAssembly.Module", async function () {
+	expect(wasmModule).toBeInstanceOf(WebAssembly.Module);
+	const instance = await WebAssembly.instantiate(wasmModule);
+	expect(instance.exports.getNumber()).toBe(42);
+	expect(instance.exports.add(1, 2)).toBe(3);
+});
+
+it("should import the compiled WebAssembly.Module dynamically", async function () {
+	const mod = await import.source("./wasm.wat");
+	expect(mod).toBe(wasmModule);
+	const exports = WebAssembly.Module.exports(mod).map(e => e.name);
+	expect(exports).toEqual(["add", "getNumber"]);
+});
@@ -0,0 +1,3 @@
+module.exports = [
+	[/The universal wasm loading doesn't support synchronous WebAssembly modules/]
+];
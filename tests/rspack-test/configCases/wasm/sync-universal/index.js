@@ -0,0 +1,5 @@
+it("should report an error for the sync WebAssembly modules with universal wasm loading", function () {
+	if (Math.random() < 0) {
+		import("./wasm.wat");
+	}
+});
@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: ["web", "node"],
+	module: {
+		rules: [
+			{
+				test: /\.wat$/,
+				loader: "wast-loader",
+				type: "webassembly/sync"
+			}
+		]
+	},
+	experiments: {
+		outputModule: true,
+		syncWebAssembly: true
+	}
+};
@@ -0,0 +1,3 @@
+export function getValue() {
+	return 21;
+}
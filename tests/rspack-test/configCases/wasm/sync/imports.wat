@@ -0,0 +1,6 @@
+(module
+  (import "./env.js" "getValue" (func $getValue (result i32)))
+  (func $getDoubled (export "getDoubled") (result i32)
+    (i32.mul
+      (call $getValue)
+      (i32.const 2))))
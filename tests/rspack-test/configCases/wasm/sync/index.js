@@ -0,0 +1,5 @@
+it("should instantiate the sync WebAssembly module when the chunk is loaded", function () {
+	return import("./module").then(function (module) {
+		expect(module.run()).toEqual(84);
+	});
+});
@@ -0,0 +1,6 @@
+import { add, getNumber } from "./wasm.wat";
+import { getDoubled } from "./imports.wat";
+
+export function run() {
+	return add(getNumber(), getDoubled());
+}
@@ -0,0 +1,17 @@
+import { add, value, memory, missing } from "./wasm.wat";
+
+it("should provide the typed exports of the wasm module", () => {
+	expect(add(1, 2)).toBe(3);
+	expect(value.value).toBe(42);
+	expect(memory).toBeInstanceOf(WebAssembly.Memory);
+	expect(missing).toBe(undefined);
+});
+
+it("should warn when a non-function wasm export is called", () => {
+	expect(() => value()).toThrow();
+});
+
+it("should warn when a wasm function is called with a different number of arguments", () => {
+	expect(add(1)).toBe(1);
+	expect(add(...[1, 2])).toBe(3);
+});
@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	module: {
+		rules: [
+			{
+				test: /\.wat$/,
+				loader: "wast-loader",
+				type: "webassembly/async"
+			}
+		]
+	},
+	output: {
+		webassemblyModuleFilename: "[id].[hash].wasm"
+	},
+	experiments: {
+		asyncWebAssembly: true
+	}
+};
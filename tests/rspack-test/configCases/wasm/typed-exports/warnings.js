@@ -0,0 +1,5 @@
+module.exports = [
+	/export 'missing' \(imported as 'missing'\) was not found in '\.\/wasm\.wat'/,
+	/export 'value' of WebAssembly module '\.\/wasm\.wat' is a global i32, but it's called as a function/,
+	/export 'add' of WebAssembly module '\.\/wasm\.wat' is a function \(i32, i32\) => i32, but it's called with 1 argument\(s\)/
+];
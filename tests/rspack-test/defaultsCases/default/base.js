@@ -28,6 +28,7 @@ module.exports = {
 			    futureDefaults: false,
 			    incremental: Object {
 			      buildChunkGraph: false,
+			      check: false,
 			      chunkIds: true,
 			      chunksHashes: true,
 			      chunksRender: true,
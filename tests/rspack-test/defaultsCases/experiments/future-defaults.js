@@ -74,6 +74,16 @@ module.exports = {
 			+         "type": "css",
 			+       },
 			+       Object {
+			+         "resolve": Object {
+			+           "fullySpecified": true,
+			+           "preferRelative": true,
+			+         },
+			+         "type": "css",
+			+         "with": Object {
+			+           "type": "css",
+			+         },
+			+       },
+			+       Object {
 			@@ ... @@
 			+       "css": Object {
 			+         "esModule": true,
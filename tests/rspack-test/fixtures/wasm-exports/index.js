@@ -0,0 +1,3 @@
+import { add } from "./wasm.wat";
+
+console.log(add(1, 2));
@@ -0,0 +1,9 @@
+(module
+  (memory (export "memory") 1)
+  (global (export "value") i32 (i32.const 42))
+  (func (export "add") (param $a i32) (param $b i32) (result i32)
+    local.get $a
+    local.get $b
+    i32.add
+  )
+)
@@ -0,0 +1,40 @@
+/** @type {import('@rspack/test-tools').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have usedExports and providedExports stats of wasm modules",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				main: "./fixtures/wasm-exports/index"
+			},
+			module: {
+				rules: [
+					{
+						test: /\.wat$/,
+						loader: "wast-loader",
+						type: "webassembly/async"
+					}
+				]
+			},
+			experiments: {
+				asyncWebAssembly: true
+			},
+			optimization: {
+				usedExports: true,
+				providedExports: true
+			}
+		};
+	},
+	async check(stats) {
+		const { modules } = stats?.toJson({
+			all: false,
+			modules: true,
+			usedExports: true,
+			providedExports: true
+		});
+		const wasm = modules.find(module => module.name.endsWith("wasm.wat"));
+		expect([...wasm.providedExports].sort()).toEqual(["add", "memory", "value"]);
+		// the unused exports of the wasm module are not in usedExports
+		expect(wasm.usedExports).toEqual(["add"]);
+	}
+};